@@ -1,10 +1,14 @@
 use crate::api_client::{ApiClient, BoardGetResponse, ColorInfo, PixelNetwork, UserInfos};
-use crate::art::PixelArt;
+use crate::art::{ArtPixel, PixelArt};
+use crate::palette_names::PaletteNames;
 use crate::token_storage::TokenStorage;
-use std::collections::VecDeque;
+use std::collections::{HashMap, HashSet, VecDeque};
 use std::time::Instant;
 use tokio::sync::mpsc;
 
+/// Art editor canvas (width, height) for new arts; arts opened for editing get at least this
+pub const EDITOR_CANVAS_SIZE: (u16, u16) = (30, 20);
+
 #[derive(Debug, PartialEq, Eq, Default)]
 pub enum InputMode {
     #[default]
@@ -25,6 +29,16 @@ pub enum InputMode {
     EnterShareString,       // New mode for entering/parsing share strings
     ShareSelection,         // New mode for selecting from received shares
     ArtDeleteConfirmation,  // New mode for confirming art deletion
+    ShowBoardInfo,          // New mode for displaying board dimensions and palette facts
+    EnterColorSearch,       // New mode for entering the color to search the board for
+    EnterSnapshotDiff,      // New mode for naming the two board snapshots to compare
+    ArtEditorExitConfirm,   // New mode for confirming an editor exit with unsaved changes
+    EnterPaletteFile,       // New mode for entering the palette file to take color names from
+    QuitConfirm,            // New mode for confirming quit while the queue is running
+    ArtOverwriteConfirm,    // New mode for confirming a save over the edited art's file
+    ResumePositioning,      // New mode for resuming the art positioned before a restart
+    LogoutConfirm,          // New mode for confirming a logout that clears the tokens
+    ArtRestoreConfirm,      // New mode for confirming an autosave restore over unsaved edits
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
@@ -36,8 +50,213 @@ pub enum QueueStatus {
     Failed,  // If placement failed
 }
 
+/// How completed queue items are drawn on the board overlay
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum CompletedOverlay {
+    #[default]
+    Full, // Same per-pixel overlay as active items
+    Outline, // Faint bounding-box outline only
+    Hidden,  // Not drawn at all
+}
+
+impl CompletedOverlay {
+    pub fn next(self) -> Self {
+        match self {
+            CompletedOverlay::Full => CompletedOverlay::Outline,
+            CompletedOverlay::Outline => CompletedOverlay::Hidden,
+            CompletedOverlay::Hidden => CompletedOverlay::Full,
+        }
+    }
+
+    pub fn label(self) -> &'static str {
+        match self {
+            CompletedOverlay::Full => "Full",
+            CompletedOverlay::Outline => "Outline",
+            CompletedOverlay::Hidden => "Hidden",
+        }
+    }
+}
+
+/// What the last board/profile fetch said about the server, for the corner indicator
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ConnectionState {
+    #[default]
+    Unknown, // Nothing fetched yet
+    Connected,    // Last fetch succeeded
+    Unauthorized, // Server answered but rejected the tokens
+    Disconnected, // Last fetch failed (network, timeout, server error)
+}
+
+impl ConnectionState {
+    /// Classify a failed fetch by its HTTP status: auth rejections still mean the server is reachable
+    pub fn from_fetch_status(status: Option<u16>) -> Self {
+        match status {
+            Some(401 | 403) => ConnectionState::Unauthorized,
+            _ => ConnectionState::Disconnected,
+        }
+    }
+}
+
+/// Named bundles of the queue's timing knobs, cycled from the queue screen
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum CooldownStrategy {
+    Aggressive, // No safety margin, short floors, minimal pacing
+    #[default]
+    Balanced, // Original timings
+    Stealth,    // Longer jittered waits and slower board refreshes
+}
+
+impl CooldownStrategy {
+    pub fn next(self) -> Self {
+        match self {
+            CooldownStrategy::Aggressive => CooldownStrategy::Balanced,
+            CooldownStrategy::Balanced => CooldownStrategy::Stealth,
+            CooldownStrategy::Stealth => CooldownStrategy::Aggressive,
+        }
+    }
+
+    pub fn label(self) -> &'static str {
+        match self {
+            CooldownStrategy::Aggressive => "Aggressive",
+            CooldownStrategy::Balanced => "Balanced",
+            CooldownStrategy::Stealth => "Stealth",
+        }
+    }
+
+    /// Seconds added after the earliest timer expires
+    pub fn timer_margin_secs(self) -> u64 {
+        match self {
+            CooldownStrategy::Aggressive => 0,
+            CooldownStrategy::Balanced => 2,
+            CooldownStrategy::Stealth => 5,
+        }
+    }
+
+    /// Up to this many random extra seconds on each cooldown wait
+    pub fn cooldown_jitter_secs(self) -> u64 {
+        match self {
+            CooldownStrategy::Aggressive | CooldownStrategy::Balanced => 0,
+            CooldownStrategy::Stealth => 10,
+        }
+    }
+
+    /// Minimum wait when the server gave no usable timers (doubled without any timer data)
+    pub fn fallback_floor_secs(self) -> u64 {
+        match self {
+            CooldownStrategy::Aggressive => 30,
+            CooldownStrategy::Balanced => 60,
+            CooldownStrategy::Stealth => 120,
+        }
+    }
+
+    /// Waits longer than this pause the item and poll the profile instead
+    pub fn pause_threshold_secs(self) -> u64 {
+        match self {
+            CooldownStrategy::Aggressive => 60,
+            CooldownStrategy::Balanced => 120,
+            CooldownStrategy::Stealth => 300,
+        }
+    }
+
+    /// Whether placements stay spaced out while buffer is spare; the others burst then
+    pub fn paces_spare_buffer(self) -> bool {
+        matches!(self, CooldownStrategy::Stealth)
+    }
+
+    /// Base delay between placements
+    pub fn pixel_delay_ms(self) -> u64 {
+        match self {
+            CooldownStrategy::Aggressive => 50,
+            CooldownStrategy::Balanced => 100,
+            CooldownStrategy::Stealth => 400,
+        }
+    }
+
+    /// Up to this many random extra milliseconds between placements
+    pub fn pixel_jitter_ms(self) -> u64 {
+        match self {
+            CooldownStrategy::Aggressive | CooldownStrategy::Balanced => 0,
+            CooldownStrategy::Stealth => 800,
+        }
+    }
+
+    /// Seconds between board refreshes while the queue runs
+    pub fn board_refresh_secs(self) -> u64 {
+        match self {
+            CooldownStrategy::Aggressive | CooldownStrategy::Balanced => 60,
+            CooldownStrategy::Stealth => 180,
+        }
+    }
+}
+
+/// How the queue mixes pixels of its pending items; all share one cooldown governor
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Interleave {
+    #[default]
+    Off, // One item at a time, in queue order
+    RoundRobin, // One pixel per item per round
+    Priority,   // More pixels per round for higher-priority items
+}
+
+impl Interleave {
+    pub fn next(self) -> Self {
+        match self {
+            Interleave::Off => Interleave::RoundRobin,
+            Interleave::RoundRobin => Interleave::Priority,
+            Interleave::Priority => Interleave::Off,
+        }
+    }
+
+    pub fn label(self) -> &'static str {
+        match self {
+            Interleave::Off => "Sequential",
+            Interleave::RoundRobin => "Interleaved",
+            Interleave::Priority => "Interleaved by priority",
+        }
+    }
+
+    /// `FTPLACE_INTERLEAVE` values: "round-robin" (or "1") and "priority"
+    pub fn from_config(value: &str) -> Option<Self> {
+        match value.to_lowercase().as_str() {
+            "0" | "off" | "sequential" => Some(Interleave::Off),
+            "1" | "round-robin" | "roundrobin" => Some(Interleave::RoundRobin),
+            "priority" => Some(Interleave::Priority),
+            _ => None,
+        }
+    }
+}
+
+/// Order in which a queue item's pixels are placed
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, serde::Serialize, serde::Deserialize)]
+pub enum PlacementOrder {
+    #[default]
+    BorderFirst, // Outline first, then top-to-bottom fill
+    ContrastFirst, // Pixels whose neighbors differ most first, so the design reads early
+    CenterOut,     // Middle of the art first, growing outwards
+}
+
+impl PlacementOrder {
+    pub fn next(self) -> Self {
+        match self {
+            PlacementOrder::BorderFirst => PlacementOrder::ContrastFirst,
+            PlacementOrder::ContrastFirst => PlacementOrder::CenterOut,
+            PlacementOrder::CenterOut => PlacementOrder::BorderFirst,
+        }
+    }
+
+    pub fn label(self) -> &'static str {
+        match self {
+            PlacementOrder::BorderFirst => "border-first",
+            PlacementOrder::ContrastFirst => "contrast-first",
+            PlacementOrder::CenterOut => "center-out",
+        }
+    }
+}
+
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
 pub struct ArtQueueItem {
+    #[serde(default)]
+    pub id: u64, // Stable identity across re-sorts and reloads; 0 until assigned
     pub art: PixelArt,
     pub priority: u8, // 1=high, 5=low
     pub status: QueueStatus,
@@ -46,28 +265,219 @@ pub struct ArtQueueItem {
     #[serde(skip, default = "std::time::Instant::now")]
     pub added_time: Instant, // When added to queue
     pub paused: bool,         // Whether this individual item is paused
+    #[serde(default)]
+    pub placement_order: PlacementOrder,
+    #[serde(skip)]
+    pub selected: bool, // Marked with Space for a batch priority change
+}
+
+/// UI preferences saved in `preferences.json`
+#[derive(Debug, Clone, Default, serde::Serialize, serde::Deserialize)]
+#[serde(default)]
+pub struct Preferences {
+    pub ascii_status: bool, // Plain [OK]/[ERR] tags instead of emoji in status text
+    pub placement_bell: bool, // Terminal bell on each pixel placed in single-art mode
+    pub cooldown_bell: bool, // Terminal bell when the cooldown ends
+    pub empty_cells_only: bool, // Queue places only on empty or default-color cells
+}
+
+/// Which palette colors count as background and are never placed, from `skip_rules.json`
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+#[serde(default)]
+pub struct SkipRules {
+    pub color_ids: Vec<i32>,
+    pub name_patterns: Vec<String>, // Case-insensitive name substrings; "=name" matches exactly
+}
+
+impl Default for SkipRules {
+    /// The built-in rules, used when no `skip_rules.json` exists
+    fn default() -> Self {
+        Self {
+            color_ids: Vec::new(),
+            name_patterns: ["transparent", "background", "empty", "=none", "alpha"]
+                .map(String::from)
+                .to_vec(),
+        }
+    }
+}
+
+impl SkipRules {
+    /// Ids of the palette colors these rules skip
+    pub fn background_color_ids(&self, colors: &[ColorInfo]) -> HashSet<i32> {
+        colors
+            .iter()
+            .filter(|color| {
+                let name = color.name.to_lowercase();
+                self.color_ids.contains(&color.id)
+                    || self
+                        .name_patterns
+                        .iter()
+                        .map(|p| p.to_lowercase())
+                        .any(|pattern| match pattern.strip_prefix('=') {
+                            Some(exact) => name == exact,
+                            None => name.contains(&pattern),
+                        })
+            })
+            .map(|color| color.id)
+            .collect()
+    }
+}
+
+/// A board coordinate watched for changes, with the color seen at the last refresh
+#[derive(Debug, Clone, Copy, serde::Serialize, serde::Deserialize)]
+pub struct WatchedPixel {
+    pub x: i32,
+    pub y: i32,
+    pub last_color: Option<i32>, // None = empty cell
+}
+
+/// Placement history of one saved art across sessions, kept in art_stats.json
+#[derive(Debug, Clone, Default, serde::Serialize, serde::Deserialize)]
+pub struct ArtPlacementStats {
+    pub runs: u32, // Queue runs of the art that completed or failed
+    pub pixels_succeeded: u64,
+    pub pixels_failed: u64,
+}
+
+/// One queue run, written to `reports/run_<timestamp>.json` when it ends
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct RunReport {
+    pub started_at: String, // RFC 3339, local time
+    pub ended_at: String,
+    pub outcome: String, // completed, cancelled or failed
+    pub duration_secs: u64,
+    pub items: Vec<RunReportItem>,
+    pub items_completed: usize,
+    pub items_failed: usize,
+    pub items_skipped: usize,
+    pub total_pixels_placed: usize,
+    #[serde(skip)]
+    pub started: Instant,
+}
+
+/// How a queue item ended within a run report
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum RunItemStatus {
+    Completed,
+    Failed,
+    Skipped,
+    Interrupted, // Still open when the run ended (cancel or a failure elsewhere)
+}
+
+/// Final state of one queue item within a run report
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct RunReportItem {
+    pub name: String,
+    pub status: RunItemStatus,
+    pub pixels_placed: usize,
+    pub pixels_total: usize,
+    pub duration_secs: u64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reason: Option<String>, // Why the item failed or was skipped
+    #[serde(skip)]
+    pub queue_index: usize,
+    #[serde(skip)]
+    pub started: Instant,
+}
+
+/// What a board search ('/') looks for
+#[derive(Debug, Clone)]
+pub enum SearchTarget {
+    Color(Option<i32>), // A color id; None = empty cells
+    Template {
+        name: String,
+        pixels: Vec<ArtPixel>, // Loaded art's meaningful pixels, relative to their top-left
+        min_percent: u32,      // Share of pixels that must match (100 = exact)
+    },
+}
+
+/// Active board search, with matches ordered by distance from where it started
+#[derive(Debug, Clone)]
+pub struct ColorSearch {
+    pub target: SearchTarget,
+    pub origin: (i32, i32),       // Viewport center when the search started
+    pub matches: Vec<(i32, i32)>, // Cells, or template top-left corners
+    pub current: usize,           // Index of the match the viewport is centered on
+    pub searching: bool,          // First matches still being computed in the background
+}
+
+/// How a cell differs between two board snapshots
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PixelChange {
+    Added,     // Empty before, colored after
+    Removed,   // Colored before, empty after
+    Recolored, // Different color
+}
+
+/// Snapshot of board facts shown in the board info popup (cached until the next board fetch)
+#[derive(Debug, Clone)]
+pub struct BoardInfo {
+    pub width: usize,
+    pub height: usize,
+    pub non_empty_pixels: usize,
+    pub color_count: usize,
+    pub viewport: (u16, u16, u16, u16), // (x, y, width, height) in board pixels when computed
+    pub viewport_color_count: usize,    // Distinct colors visible in that viewport
+    pub base_url: String,
+}
+
+/// Board shared with the queue task, which keeps it up to date between placements
+pub type SharedBoard = std::sync::Arc<std::sync::RwLock<Vec<Vec<Option<PixelNetwork>>>>>;
+
+/// A second server session parked in the background while the other one is active
+#[derive(Debug)]
+pub struct BoardSession {
+    pub api_client: ApiClient,
+    pub board: Vec<Vec<Option<PixelNetwork>>>,
+    pub colors: Vec<ColorInfo>,
+    pub user_info: Option<UserInfos>,
+    pub board_viewport_x: u16,
+    pub board_viewport_y: u16,
+    pub initial_board_fetched: bool,
+    pub last_board_refresh: Option<Instant>,
+    pub shared_board_state: Option<SharedBoard>,
+    pub watched_pixels: Vec<WatchedPixel>,
 }
 
 #[derive(Debug)]
 pub struct App {
     pub exit: bool,
+    pub force_redraw: bool, // Ctrl+L: clear the terminal before the next frame
     pub api_client: ApiClient,
     pub token_storage: TokenStorage,
+    pub env_credentials: bool, // Tokens came from FTPLACE_* variables and are never saved
     pub input_mode: InputMode,
     pub input_buffer: String, // Generic input buffer (renamed from cookie_input_buffer for clarity)
     pub status_message: String, // To display messages to the user
     pub status_messages: VecDeque<(String, Instant, String)>, // History: (message, instant, utc+2_timestamp)
-    pub cooldown_status: String,                              // Persistent cooldown/timer info
+    pub status_log_capacity: usize,                           // Max entries kept in status_messages
+    pub compress_saved_files: bool, // Gzip the queue file (FTPLACE_COMPRESS)
+    pub cooldown_status: String,    // Persistent cooldown/timer info
     pub board: Vec<Vec<Option<PixelNetwork>>>,
     pub colors: Vec<ColorInfo>,
+    pub palette_names: PaletteNames, // Imported color names for this base URL ('P')
     pub user_info: Option<UserInfos>,
     pub loaded_art: Option<PixelArt>,
-    pub board_viewport_x: u16,       // X offset of the viewport in pixels
-    pub board_viewport_y: u16,       // Y offset of the viewport in pixel rows (top row of the pair)
+    pub snap_grid_size: Option<i32>, // Snap loaded art position to multiples of N (None = off)
+    pub next_placement_order: PlacementOrder, // Order for the art being positioned ('o' flips it)
+    pub fit_region_active: bool, // Next two board clicks pick a region to fit the loaded art into
+    pub fit_region_corner: Option<(i32, i32)>, // First corner clicked for the fit region
+    pub capture_region_active: bool, // Next two board clicks pick a region to copy into an art
+    pub capture_region_corner: Option<(i32, i32)>, // First corner clicked for the capture
+    pub overlap_count_due: Option<Instant>, // Debounced already-correct count (large arts)
+    pub board_viewport_x: u16,   // X offset of the viewport in pixels
+    pub board_viewport_y: u16,   // Y offset of the viewport in pixel rows (top row of the pair)
     pub initial_board_fetched: bool, // New flag
     pub last_board_refresh: Option<Instant>, // For auto-refresh
+    pub last_full_board_fetch: Option<Instant>, // Deltas are requested between full fetches
+    pub board_last_pixel_time: Option<i64>, // Newest pixel timestamp (delta cursor)
+    pub board_delta_supported: Option<bool>, // None until a delta request was answered
+    pub board_fetch_is_delta: bool, // In-flight fetch asked for a delta
+    pub empty_palette_retried: bool, // Already re-fetched once after a board with no colors
+    pub connection_state: ConnectionState, // Drives the connection/auth indicator
     pub should_fetch_board_on_start: bool, // Flag to trigger board fetch when tokens are restored
-    pub board_loading: bool,         // Flag to indicate board is being fetched in background
+    pub board_loading: bool,     // Flag to indicate board is being fetched in background
     pub board_load_start: Option<Instant>, // When background load started
     pub board_fetch_receiver: Option<mpsc::UnboundedReceiver<BoardFetchResult>>, // Channel for receiving board fetch results
     pub placement_receiver: Option<mpsc::UnboundedReceiver<PlacementUpdate>>, // Channel for receiving placement updates
@@ -78,6 +488,11 @@ pub struct App {
     pub queue_control_sender: Option<mpsc::UnboundedSender<QueueControl>>, // Channel for sending pause/resume commands
     pub queue_processing_start: Option<Instant>, // When queue processing started
     pub profile_receiver: Option<mpsc::UnboundedReceiver<ProfileFetchResult>>, // Channel for receiving profile fetch results
+    pub connection_test_receiver: Option<mpsc::UnboundedReceiver<ConnectionTestResult>>, // Result of the config screen's connection test
+    pub color_search_receiver: Option<mpsc::UnboundedReceiver<Vec<(i32, i32)>>>, // Board search matches computed off the UI thread
+    pub secondary_session: Option<BoardSession>, // Inactive session, swapped in with Tab
+    pub secondary_active: bool, // The second session is in front
+    pub background_fetch_receiver: Option<mpsc::UnboundedReceiver<BoardFetchResult>>, // Board refresh of the background session
 
     // State for Base URL selection
     pub base_url_options: Vec<String>,
@@ -95,26 +510,71 @@ pub struct App {
     pub art_editor_viewport_x: i32, // X offset of the art editor viewport - for future scrolling
     #[allow(dead_code)]
     pub art_editor_viewport_y: i32, // Y offset of the art editor viewport - for future scrolling
+    pub editor_dirty: bool,                    // Edits since the art was last saved
+    pub editing_saved_art: bool,               // Opened from the library; first save confirms
+    pub editor_autosave_due: bool,             // Edits not yet written to editor_autosave.json
+    pub editor_last_autosave: Option<Instant>, // Throttles autosave writes
 
     // Pixel Art Selection State
     pub available_pixel_arts: Vec<PixelArt>, // List of available pixel arts (saved + default)
+    pub available_art_sizes: Vec<((i32, i32), usize)>, // Bounding box and meaningful pixels per art
     pub art_selection_index: usize,          // Current selection in art list
     pub art_preview_art: Option<PixelArt>,   // Art being previewed in full-screen mode
 
     // Art Queue System
     pub art_queue: Vec<ArtQueueItem>, // Queue of arts to be placed
     pub queue_selection_index: usize, // Current selection in queue list
+    pub queue_list_offset: usize,     // First visible row of the queue list
     pub queue_processing: bool,       // Whether queue is currently being processed
     pub queue_paused: bool,           // Whether queue processing is paused
-    pub queue_blink_state: bool,      // For blinking preview effect
-    pub last_blink_time: Option<Instant>, // Last time blink state changed
+    pub queue_interleave: Interleave, // How pixels are mixed across items ('i')
+    pub placement_concurrency: usize, // Max placements in flight while buffer allows (1 = serial)
+    pub reserve_buffer: usize,        // Buffered pixels the queue never spends
+    pub polite_rate: Option<u32>,     // Politeness cap in pixels/minute (None = off)
+    pub verify_placements: bool,      // Read placed pixels back and re-queue misses
+    pub integrity_threshold: Option<u32>, // Post-run check: re-queue items below this %
+    pub integrity_check_items: Vec<usize>, // Completed items to check on the next board load
+    pub queue_active_index: Option<usize>, // Item the queue task is currently placing
+    pub queue_active_position: Option<(i32, i32)>, // Board coordinate of the last placement for that item
+    pub queue_cooldown: Option<(Instant, Instant)>, // Start and deadline of the queue's current cooldown wait
+    pub moving_queue_item: Option<u64>, // id of the queue item being repositioned
+    pub duplicate_priority: Option<u8>, // Priority of the queue item being duplicated, kept by its copy
+    pub completed_overlay: CompletedOverlay, // Board overlay style for completed items
+    pub cooldown_strategy: CooldownStrategy, // Timing preset used by queue processing
+    pub board_cursor: Option<(i32, i32)>,   // Last board pixel clicked
+    pub watched_pixels: Vec<WatchedPixel>,  // Pixels to alert on, saved in watch.json
+    pub watch_webhook: Option<String>,      // FTPLACE_WATCH_WEBHOOK, posted on watch alerts
+    pub art_stats: HashMap<String, ArtPlacementStats>, // Placement history by art name
+    pub skip_rules: SkipRules,              // Background colors never placed
+    pub run_report: Option<RunReport>,      // Queue run being recorded
+    pub color_search: Option<ColorSearch>,  // Highlighted board color search ('/')
+    pub snapshot_diff: Option<Vec<(i32, i32, PixelChange)>>, // Snapshot diff overlay ('K')
+    pub spectator_mode: bool,               // Read-only: every placement path is a no-op
+    pub ascii_status: bool,                 // Show status emoji as plain tags ('A')
+    pub placement_bell: bool,               // Bell on each single-art pixel ('a')
+    pub cooldown_bell: bool,                // Bell when the cooldown ends ('T')
+    pub cooldown_bell_armed: bool,          // On cooldown since the last bell
+    pub empty_cells_only: bool,             // Never paint over other players ('E')
+    pub force_placement: bool,              // Place correct pixels too ('X', not saved)
+    pub resume_art: Option<PixelArt>,       // Art offered for resuming at startup
+    pub help_return_mode: InputMode,        // Mode help was opened from ('?')
+    pub loaded_art_saved: Option<(String, i32, i32, usize)>, // What loaded_art.json holds
+    pub placement_flash: Option<((i32, i32), Instant)>, // Cell just placed and when
+    pub queue_sidebar: bool,                // Queue list beside the board ('Q')
+    pub follow_queue: bool,                 // Center on the processing item ('F')
+    pub queue_blink_state: bool,            // For blinking preview effect
+    pub last_blink_time: Option<Instant>,   // Last time blink state changed
 
     // Shared board state for queue processing
-    pub shared_board_state:
-        Option<std::sync::Arc<std::sync::RwLock<Vec<Vec<Option<PixelNetwork>>>>>>,
+    pub shared_board_state: Option<SharedBoard>,
 
     // Mouse support
     pub board_area_bounds: Option<(u16, u16, u16, u16)>, // (x, y, width, height) of the board display area
+    pub last_terminal_size: Option<(u16, u16)>,          // (width, height) at the last draw
+    pub spinner_frame: usize, // Advances every render so busy indicators visibly move
+    pub board_info: Option<BoardInfo>, // Cached board facts for the info popup, cleared on board fetch
+    pub compact_layout: Option<bool>, // Layout override: None = auto by terminal height, Some(true/false) = forced
+    pub api_debug: bool,              // Log full API error details to the status log
 
     // Sharing system
     pub available_shares: Vec<crate::art::ShareablePixelArt>, // List of received shares
@@ -145,7 +605,14 @@ pub enum BoardFetchResult {
         board_response: BoardGetResponse,
         updated_tokens: Option<(Option<String>, Option<String>)>, // (access_token, refresh_token)
     },
-    Error(String),
+    Delta {
+        board_response: BoardGetResponse, // Only pixels changed since the requested time are set
+        updated_tokens: Option<(Option<String>, Option<String>)>,
+    },
+    Error {
+        message: String,
+        status: Option<u16>, // HTTP status of the failed request, when there was a response
+    },
 }
 
 #[derive(Debug, Clone)]
@@ -174,6 +641,9 @@ pub enum PlacementUpdate {
         pixels_placed: usize,
         total_pixels: usize,
     },
+    PixelPlaced {
+        position: (i32, i32),
+    },
     ApiCall {
         message: String,
     },
@@ -195,6 +665,14 @@ pub enum QueueUpdate {
         position: (i32, i32),
         cooldown_remaining: Option<u32>,
     },
+    PixelPlaced {
+        position: (i32, i32), // Only sent when the run places a single item
+    },
+    ItemCheckpoint {
+        item_index: usize,
+        pixels_placed: usize,
+        total_pixels: usize,
+    },
     ItemCompleted {
         item_index: usize,
         art_name: String,
@@ -205,6 +683,7 @@ pub enum QueueUpdate {
         item_index: usize,
         art_name: String,
         error_msg: String,
+        stops_queue: bool, // false when an interleaved run carries on with the other items
     },
     ItemSkipped {
         item_index: usize,
@@ -240,13 +719,23 @@ pub enum QueueUpdate {
     },
 }
 
+/// Outcome of the config screen's connection test
+#[derive(Debug)]
+pub struct ConnectionTestResult {
+    pub profile: ProfileFetchResult,
+    pub updated_tokens: Option<(Option<String>, Option<String>)>, // Stored tokens the server rotated (426) during the test
+}
+
 #[derive(Debug)]
 pub enum ProfileFetchResult {
     Success {
         user_infos: crate::api_client::UserInfos,
         updated_tokens: Option<(Option<String>, Option<String>)>, // (access_token, refresh_token)
     },
-    Error(String),
+    Error {
+        message: String,
+        status: Option<u16>, // HTTP status of the failed request, when there was a response
+    },
 }
 
 #[derive(Debug, Clone)]
@@ -277,3 +766,74 @@ pub enum ValidationUpdate {
 pub enum ValidationControl {
     Stop,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn color(id: i32, name: &str) -> ColorInfo {
+        ColorInfo {
+            id,
+            name: name.to_string(),
+            red: 0,
+            green: 0,
+            blue: 0,
+        }
+    }
+
+    #[test]
+    fn skip_rules_match_names_and_ids() {
+        let colors = [
+            color(1, "Transparent"),
+            color(2, "Dark Background"),
+            color(3, "None"),
+            color(4, "Nonexistent Blue"),
+            color(5, "Red"),
+            color(6, "Green"),
+        ];
+        let rules = SkipRules {
+            color_ids: vec![6],
+            name_patterns: ["TRANSPARENT", "background", "=none"]
+                .map(String::from)
+                .to_vec(),
+        };
+
+        // Case-insensitive substrings, "=" for exact names, plus explicit ids
+        assert_eq!(
+            rules.background_color_ids(&colors),
+            HashSet::from([1, 2, 3, 6])
+        );
+    }
+
+    #[test]
+    fn interleave_parses_config_values() {
+        assert_eq!(Interleave::from_config("off"), Some(Interleave::Off));
+        assert_eq!(Interleave::from_config("0"), Some(Interleave::Off));
+        assert_eq!(Interleave::from_config("1"), Some(Interleave::RoundRobin));
+        assert_eq!(
+            Interleave::from_config("Round-Robin"),
+            Some(Interleave::RoundRobin)
+        );
+        assert_eq!(
+            Interleave::from_config("PRIORITY"),
+            Some(Interleave::Priority)
+        );
+        assert_eq!(Interleave::from_config("fastest"), None);
+    }
+
+    #[test]
+    fn only_auth_statuses_count_as_unauthorized() {
+        for status in [401, 403] {
+            assert_eq!(
+                ConnectionState::from_fetch_status(Some(status)),
+                ConnectionState::Unauthorized
+            );
+        }
+        for status in [Some(500), Some(404), None] {
+            assert_eq!(
+                ConnectionState::from_fetch_status(status),
+                ConnectionState::Disconnected
+            );
+        }
+    }
+}
@@ -1,4 +1,6 @@
+use crate::api_client::ColorInfo;
 use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet};
 use std::fs;
 use std::path::Path;
 
@@ -13,8 +15,9 @@ pub struct ArtPixel {
 #[derive(Deserialize, Serialize, Debug, Clone, Default)]
 pub struct PixelArt {
     pub name: String,
-    pub width: i32,             // Added width field
-    pub height: i32,            // Added height field
+    pub width: i32,  // Added width field
+    pub height: i32, // Added height field
+    #[serde(alias = "pixels")] // Older files still use the pre-rename field name
     pub pattern: Vec<ArtPixel>, // Changed from pixels to pattern
 
     // Optional fields for runtime positioning (not saved to file by default)
@@ -32,6 +35,8 @@ pub struct PixelArt {
     pub created_at: Option<String>, // ISO 8601 timestamp
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub tags: Option<Vec<String>>,
+    #[serde(default, skip_serializing_if = "is_false")]
+    pub default_palette: bool, // Drawn offline with `default_palette()` ids, remapped on load
 }
 
 // Shareable pixel art format with coordinates
@@ -50,6 +55,87 @@ fn is_zero(value: &i32) -> bool {
     *value == 0
 }
 
+fn is_false(value: &bool) -> bool {
+    !*value
+}
+
+/// Built-in palette for drawing before any board (or cached palette) is available.
+/// Ids 1 and 18 match the white and red of the default art.
+pub fn default_palette() -> Vec<ColorInfo> {
+    [
+        ("White", [255, 255, 255]),
+        ("Light Gray", [212, 215, 217]),
+        ("Gray", [137, 141, 144]),
+        ("Dark Gray", [81, 82, 82]),
+        ("Black", [0, 0, 0]),
+        ("Light Pink", [255, 153, 170]),
+        ("Pink", [255, 56, 129]),
+        ("Magenta", [222, 16, 127]),
+        ("Lavender", [228, 171, 255]),
+        ("Purple", [180, 74, 192]),
+        ("Dark Purple", [129, 30, 159]),
+        ("Light Blue", [81, 233, 244]),
+        ("Blue", [54, 144, 234]),
+        ("Dark Blue", [36, 80, 164]),
+        ("Indigo", [73, 58, 193]),
+        ("Teal", [0, 158, 170]),
+        ("Dark Teal", [0, 117, 111]),
+        ("Red", [255, 69, 0]),
+        ("Dark Red", [190, 0, 57]),
+        ("Orange", [255, 168, 0]),
+        ("Yellow", [255, 214, 53]),
+        ("Pale Yellow", [255, 248, 184]),
+        ("Light Green", [126, 237, 86]),
+        ("Green", [0, 204, 120]),
+        ("Dark Green", [0, 163, 104]),
+        ("Beige", [255, 180, 112]),
+        ("Brown", [156, 105, 38]),
+        ("Dark Brown", [109, 72, 47]),
+    ]
+    .into_iter()
+    .enumerate()
+    .map(|(index, (name, [red, green, blue]))| ColorInfo {
+        id: index as i32 + 1,
+        name: name.to_string(),
+        red,
+        green,
+        blue,
+    })
+    .collect()
+}
+
+/// Rewrite an art drawn with `default_palette()` to the nearest colors of `palette`
+pub fn remap_to_palette(art: &mut PixelArt, palette: &[ColorInfo]) {
+    let defaults = default_palette();
+    let distance = |a: &ColorInfo, b: &ColorInfo| {
+        let delta = |x: u8, y: u8| (x as i32 - y as i32).pow(2);
+        delta(a.red, b.red) + delta(a.green, b.green) + delta(a.blue, b.blue)
+    };
+    let nearest = defaults
+        .iter()
+        .filter_map(|default| {
+            palette
+                .iter()
+                .min_by_key(|color| distance(default, color))
+                .map(|color| (default.id, color.id))
+        })
+        .collect::<HashMap<_, _>>();
+
+    for pixel in &mut art.pattern {
+        if let Some(&color) = nearest.get(&pixel.color) {
+            pixel.color = color;
+        }
+    }
+    art.default_palette = false;
+}
+
+/// Remap an art drawn offline once a server palette is known; an empty `palette` keeps it as is
+pub fn adapt_to_palette(art: &mut PixelArt, palette: &[ColorInfo]) {
+    if art.default_palette && !palette.is_empty() {
+        remap_to_palette(art, palette);
+    }
+}
+
 // Function to load a predefined pixel art
 // Swiss flag as the default pixel art
 pub fn load_default_pixel_art() -> PixelArt {
@@ -196,26 +282,51 @@ pub fn load_default_pixel_art() -> PixelArt {
             "swiss".to_string(),
             "flag".to_string(),
         ]),
+        default_palette: false,
     }
 }
 
-/// Load a pixel art from a JSON file
-pub fn load_pixel_art_from_file(file_path: &Path) -> Result<PixelArt, Box<dyn std::error::Error>> {
+/// Load a pixel art from a JSON file, remapping it to `palette` if it was drawn offline
+pub fn load_pixel_art_from_file(
+    file_path: &Path,
+    palette: &[ColorInfo],
+) -> Result<PixelArt, Box<dyn std::error::Error>> {
     let file_content = fs::read_to_string(file_path)?;
-    let pixel_art: PixelArt = serde_json::from_str(&file_content)?;
+    let mut pixel_art: PixelArt = serde_json::from_str(&file_content)?;
 
     // Preserve the saved board position instead of resetting
     // This allows for template positioning and queue automation
+    normalize_art_origin(&mut pixel_art);
+    adapt_to_palette(&mut pixel_art, palette);
 
     Ok(pixel_art)
 }
 
-/// Load a shareable pixel art from a JSON file
+/// Save a pixel art into the patterns directory as `<file_stem>.json`, returning its path
+pub fn save_pixel_art(
+    art: &PixelArt,
+    file_stem: &str,
+) -> Result<std::path::PathBuf, Box<dyn std::error::Error>> {
+    let dir_path = crate::paths::data_path("patterns");
+    std::fs::create_dir_all(&dir_path)?;
+
+    let file_path = dir_path.join(format!("{}.json", file_stem));
+    let json_data = serde_json::to_string_pretty(art)?;
+    std::fs::write(&file_path, json_data)?;
+    Ok(file_path)
+}
+
+/// Load a shareable pixel art from a JSON file, remapping it to `palette` if it was drawn offline
 pub fn load_shareable_pixel_art_from_file(
     file_path: &Path,
+    palette: &[ColorInfo],
 ) -> Result<ShareablePixelArt, Box<dyn std::error::Error>> {
     let file_content = fs::read_to_string(file_path)?;
-    let shareable_art: ShareablePixelArt = serde_json::from_str(&file_content)?;
+    let mut shareable_art: ShareablePixelArt = serde_json::from_str(&file_content)?;
+    adapt_to_palette(&mut shareable_art.art, palette);
+    let (shift_x, shift_y) = normalize_art_origin(&mut shareable_art.art);
+    shareable_art.board_x += shift_x;
+    shareable_art.board_y += shift_y;
     Ok(shareable_art)
 }
 
@@ -285,20 +396,20 @@ pub fn parse_share_string(share_string: &str) -> Option<(String, i32, i32)> {
 }
 
 /// Get all available pixel arts (saved files + default)
-pub fn get_available_pixel_arts() -> Vec<PixelArt> {
+pub fn get_available_pixel_arts(palette: &[ColorInfo]) -> Vec<PixelArt> {
     let mut arts = Vec::new();
 
     // Add default pixel art first
     arts.push(load_default_pixel_art());
 
     // Load saved pixel arts from patterns directory
-    let patterns_dir = Path::new("patterns");
+    let patterns_dir = crate::paths::data_path("patterns");
     if patterns_dir.exists() && patterns_dir.is_dir() {
-        if let Ok(entries) = fs::read_dir(patterns_dir) {
+        if let Ok(entries) = fs::read_dir(&patterns_dir) {
             for entry in entries.flatten() {
                 let path = entry.path();
                 if path.extension().and_then(|s| s.to_str()) == Some("json") {
-                    if let Ok(pixel_art) = load_pixel_art_from_file(&path) {
+                    if let Ok(pixel_art) = load_pixel_art_from_file(&path, palette) {
                         arts.push(pixel_art);
                     }
                 }
@@ -309,25 +420,93 @@ pub fn get_available_pixel_arts() -> Vec<PixelArt> {
     arts
 }
 
-/// Get all available shareable pixel arts from shares directory
-pub fn get_available_shareable_arts() -> Vec<ShareablePixelArt> {
+/// Get all available shareable pixel arts from shares directory, along with
+/// "file: error" for each share that could not be read (those are skipped)
+pub fn get_available_shareable_arts(
+    palette: &[ColorInfo],
+) -> (Vec<ShareablePixelArt>, Vec<String>) {
     let mut arts = Vec::new();
+    let mut errors = Vec::new();
 
-    let shares_dir = Path::new("shares");
+    let shares_dir = crate::paths::data_path("shares");
     if shares_dir.exists() && shares_dir.is_dir() {
-        if let Ok(entries) = fs::read_dir(shares_dir) {
+        if let Ok(entries) = fs::read_dir(&shares_dir) {
             for entry in entries.flatten() {
                 let path = entry.path();
                 if path.extension().and_then(|s| s.to_str()) == Some("json") {
-                    if let Ok(shareable_art) = load_shareable_pixel_art_from_file(&path) {
-                        arts.push(shareable_art);
+                    match load_shareable_pixel_art_from_file(&path, palette) {
+                        Ok(shareable_art) => arts.push(shareable_art),
+                        Err(e) => errors.push(format!(
+                            "{}: {}",
+                            path.file_name().unwrap_or_default().to_string_lossy(),
+                            e
+                        )),
                     }
                 }
             }
         }
     }
 
-    arts
+    (arts, errors)
+}
+
+/// Outcome of rewriting the art library in the current schema
+#[derive(Debug, Default)]
+pub struct MigrationReport {
+    pub migrated: usize,
+    pub up_to_date: usize,
+    pub failed: Vec<String>, // "file: error" for files that could not be read or rewritten
+}
+
+/// Rewrite every art in `patterns/` and `shares/` in the current schema,
+/// keeping the original next to it as `<file>.json.bak`
+pub fn migrate_art_library() -> MigrationReport {
+    let mut report = MigrationReport::default();
+
+    for (dir, shareable) in [("patterns", false), ("shares", true)] {
+        let Ok(entries) = fs::read_dir(crate::paths::data_path(dir)) else {
+            continue;
+        };
+        for entry in entries.flatten() {
+            let path = entry.path();
+            if path.extension().and_then(|s| s.to_str()) != Some("json") {
+                continue;
+            }
+            match migrate_art_file(&path, shareable) {
+                Ok(true) => report.migrated += 1,
+                Ok(false) => report.up_to_date += 1,
+                Err(e) => report.failed.push(format!("{}: {}", path.display(), e)),
+            }
+        }
+    }
+
+    report
+}
+
+/// Returns whether the file needed rewriting
+fn migrate_art_file(path: &Path, shareable: bool) -> Result<bool, Box<dyn std::error::Error>> {
+    let original = fs::read_to_string(path)?;
+    let original_value: serde_json::Value = serde_json::from_str(&original)?;
+    let canonical = if shareable {
+        serde_json::to_value(serde_json::from_value::<ShareablePixelArt>(
+            original_value.clone(),
+        )?)?
+    } else {
+        serde_json::to_value(serde_json::from_value::<PixelArt>(original_value.clone())?)?
+    };
+    if canonical == original_value {
+        return Ok(false);
+    }
+
+    // Never overwrite an earlier backup: it holds the oldest original
+    let mut backup_name = path.as_os_str().to_owned();
+    backup_name.push(".bak");
+    let backup_path = std::path::PathBuf::from(backup_name);
+    if !backup_path.exists() {
+        fs::write(&backup_path, &original)?;
+    }
+    fs::write(path, serde_json::to_string_pretty(&canonical)?)?;
+    Ok(true)
 }
 
 /// Get dimensions of a pixel art (width, height)
@@ -343,3 +522,217 @@ pub fn get_art_dimensions(art: &PixelArt) -> (i32, i32) {
 
     (max_x - min_x + 1, max_y - min_y + 1)
 }
+
+/// Move pixels with negative relative coordinates to a (0, 0) origin, shifting the board
+/// position by the same amount so every pixel still lands on the same board cell.
+/// The editor, previews and bounds checks all assume non-negative coordinates.
+/// Returns the (x, y) shift applied to the board position.
+pub fn normalize_art_origin(art: &mut PixelArt) -> (i32, i32) {
+    let shift_x = art.pattern.iter().map(|p| p.x).min().unwrap_or(0).min(0);
+    let shift_y = art.pattern.iter().map(|p| p.y).min().unwrap_or(0).min(0);
+    if shift_x == 0 && shift_y == 0 {
+        return (0, 0);
+    }
+
+    for pixel in &mut art.pattern {
+        pixel.x -= shift_x;
+        pixel.y -= shift_y;
+    }
+    art.board_x += shift_x;
+    art.board_y += shift_y;
+    (shift_x, shift_y)
+}
+
+/// Number of positions holding several pixels with different colors.
+/// Placement keeps only the first pixel at each position.
+pub fn count_conflicting_pixels(art: &PixelArt) -> usize {
+    let mut first_colors = HashMap::new();
+    let mut conflicts = HashSet::new();
+    for pixel in &art.pattern {
+        let first_color = *first_colors
+            .entry((pixel.x, pixel.y))
+            .or_insert(pixel.color);
+        if first_color != pixel.color {
+            conflicts.insert((pixel.x, pixel.y));
+        }
+    }
+    conflicts.len()
+}
+
+/// Which pixel survives when an art has several at the same position
+#[derive(Debug, Clone, Copy)]
+pub enum DuplicateResolution {
+    KeepFirst, // What placement does implicitly
+    KeepLast,  // The most recently drawn pixel
+}
+
+/// Leave one pixel per position; returns how many pixels were removed
+pub fn resolve_duplicate_pixels(art: &mut PixelArt, resolution: DuplicateResolution) -> usize {
+    let before = art.pattern.len();
+    let mut seen_positions = HashSet::new();
+    match resolution {
+        DuplicateResolution::KeepFirst => {
+            art.pattern
+                .retain(|pixel| seen_positions.insert((pixel.x, pixel.y)));
+        }
+        DuplicateResolution::KeepLast => {
+            art.pattern.reverse();
+            art.pattern
+                .retain(|pixel| seen_positions.insert((pixel.x, pixel.y)));
+            art.pattern.reverse();
+        }
+    }
+    before - art.pattern.len()
+}
+
+/// Scale an art up by an integer factor; each pixel becomes a `factor`x`factor` block
+pub fn scale_art(art: &PixelArt, factor: i32) -> PixelArt {
+    let factor = factor.max(1);
+    let min_x = art.pattern.iter().map(|p| p.x).min().unwrap_or(0);
+    let min_y = art.pattern.iter().map(|p| p.y).min().unwrap_or(0);
+    let (width, height) = get_art_dimensions(art);
+
+    let mut pattern = Vec::with_capacity(art.pattern.len() * (factor * factor) as usize);
+    for pixel in &art.pattern {
+        for dy in 0..factor {
+            for dx in 0..factor {
+                pattern.push(ArtPixel {
+                    x: (pixel.x - min_x) * factor + dx,
+                    y: (pixel.y - min_y) * factor + dy,
+                    color: pixel.color,
+                });
+            }
+        }
+    }
+
+    PixelArt {
+        width: width * factor,
+        height: height * factor,
+        pattern,
+        ..art.clone()
+    }
+}
+
+/// Keep only the top-left `width`x`height` area of an art
+pub fn crop_art(art: &PixelArt, width: i32, height: i32) -> PixelArt {
+    let min_x = art.pattern.iter().map(|p| p.x).min().unwrap_or(0);
+    let min_y = art.pattern.iter().map(|p| p.y).min().unwrap_or(0);
+
+    let pattern: Vec<ArtPixel> = art
+        .pattern
+        .iter()
+        .map(|pixel| ArtPixel {
+            x: pixel.x - min_x,
+            y: pixel.y - min_y,
+            color: pixel.color,
+        })
+        .filter(|pixel| pixel.x < width && pixel.y < height)
+        .collect();
+
+    let mut cropped = PixelArt {
+        pattern,
+        ..art.clone()
+    };
+    let (cropped_width, cropped_height) = get_art_dimensions(&cropped);
+    cropped.width = cropped_width;
+    cropped.height = cropped_height;
+    cropped
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn board_cells(art: &PixelArt) -> Vec<(i32, i32, i32)> {
+        art.pattern
+            .iter()
+            .map(|p| (art.board_x + p.x, art.board_y + p.y, p.color))
+            .collect()
+    }
+
+    #[test]
+    fn normalizing_negative_offsets_keeps_every_pixel_on_its_board_cell() {
+        let mut art = PixelArt {
+            name: "test".to_string(),
+            pattern: vec![
+                ArtPixel {
+                    x: -2,
+                    y: 1,
+                    color: 1,
+                },
+                ArtPixel {
+                    x: 0,
+                    y: -3,
+                    color: 2,
+                },
+                ArtPixel {
+                    x: 4,
+                    y: 0,
+                    color: 3,
+                },
+            ],
+            board_x: 10,
+            board_y: 20,
+            ..Default::default()
+        };
+        let cells = board_cells(&art);
+
+        assert_eq!(normalize_art_origin(&mut art), (-2, -3));
+        assert_eq!(board_cells(&art), cells);
+        assert_eq!((art.board_x, art.board_y), (8, 17));
+        assert!(art.pattern.iter().all(|p| p.x >= 0 && p.y >= 0));
+        assert_eq!(normalize_art_origin(&mut art), (0, 0));
+    }
+
+    #[test]
+    fn shares_drawn_offline_are_remapped_when_loaded() {
+        let color = |id, name: &str, rgb: [u8; 3]| ColorInfo {
+            id,
+            name: name.to_string(),
+            red: rgb[0],
+            green: rgb[1],
+            blue: rgb[2],
+        };
+        let server_palette = vec![
+            color(40, "Snow", [250, 250, 250]),
+            color(41, "Ruby", [200, 0, 0]),
+        ];
+        let art = PixelArt {
+            name: "offline".to_string(),
+            pattern: vec![
+                ArtPixel {
+                    x: 0,
+                    y: 0,
+                    color: 1,
+                },
+                ArtPixel {
+                    x: 1,
+                    y: 0,
+                    color: 18,
+                },
+            ],
+            default_palette: true,
+            ..Default::default()
+        };
+        let path = std::env::temp_dir().join(format!("ftplace_share_{}.json", std::process::id()));
+        save_shareable_pixel_art(&art, 3, 4, None, None, &path).unwrap();
+
+        let untouched = load_shareable_pixel_art_from_file(&path, &[]).unwrap();
+        let remapped = load_shareable_pixel_art_from_file(&path, &server_palette).unwrap();
+        std::fs::remove_file(&path).unwrap();
+
+        assert!(untouched.art.default_palette);
+        let colors = |share: &ShareablePixelArt| {
+            share
+                .art
+                .pattern
+                .iter()
+                .map(|p| p.color)
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(colors(&untouched), vec![1, 18]);
+        assert!(!remapped.art.default_palette);
+        assert_eq!(colors(&remapped), vec![40, 41]);
+        assert_eq!((remapped.board_x, remapped.board_y), (3, 4));
+    }
+}
@@ -1,14 +1,189 @@
 use crate::api_client::{ApiError, UserInfos};
 use crate::app_state::{App, PlacementUpdate};
 use crate::art::{ArtPixel, PixelArt};
+use crate::event_handling::helpers::{pause_between_pixels, ring_bell};
 use std::collections::HashSet;
 use std::time::{Duration, Instant};
 use tokio::sync::mpsc;
 
+/// Arts larger than this only count already-correct pixels once they stop moving
+const OVERLAP_COUNT_DEBOUNCE_PIXELS: usize = 20_000;
+const OVERLAP_COUNT_DEBOUNCE: Duration = Duration::from_millis(300);
+const OVERLAP_COUNT_PENDING: &str = " Counting already-correct pixels...";
+
 impl App {
+    /// Start picking a board region (two corner clicks) to fit the loaded art into
+    pub fn start_fit_region(&mut self) {
+        if self.loaded_art.is_none() {
+            self.status_message = "Load an art with 'l' before fitting it to a region.".to_string();
+            return;
+        }
+        self.fit_region_active = true;
+        self.fit_region_corner = None;
+        self.status_message =
+            "Fit to region: click the first corner on the board (Esc to cancel).".to_string();
+    }
+
+    /// Record a corner click; the second one fits the loaded art into the region
+    pub fn handle_fit_region_click(&mut self, board_x: i32, board_y: i32) {
+        let Some((first_x, first_y)) = self.fit_region_corner else {
+            self.fit_region_corner = Some((board_x, board_y));
+            self.status_message = format!(
+                "Fit to region: first corner ({}, {}). Click the opposite corner.",
+                board_x, board_y
+            );
+            return;
+        };
+
+        self.fit_region_active = false;
+        self.fit_region_corner = None;
+        self.fit_loaded_art_to_region(
+            first_x.min(board_x),
+            first_y.min(board_y),
+            (first_x - board_x).abs() + 1,
+            (first_y - board_y).abs() + 1,
+        );
+    }
+
+    /// Start picking a board region (two corner clicks) to copy into a new art
+    pub fn start_region_capture(&mut self) {
+        if self.board.is_empty() {
+            self.status_message = "Load the board before copying a region.".to_string();
+            return;
+        }
+        self.capture_region_active = true;
+        self.capture_region_corner = None;
+        self.status_message =
+            "Copy region: click the first corner on the board (Esc to cancel).".to_string();
+    }
+
+    /// Record a corner of the capture region; the second corner saves the art
+    pub fn handle_capture_region_click(&mut self, board_x: i32, board_y: i32) {
+        let Some((first_x, first_y)) = self.capture_region_corner else {
+            self.capture_region_corner = Some((board_x, board_y));
+            self.status_message = format!(
+                "Copy region: first corner ({}, {}). Click the opposite corner.",
+                board_x, board_y
+            );
+            return;
+        };
+
+        self.capture_region_active = false;
+        self.capture_region_corner = None;
+        self.capture_board_region(
+            first_x.min(board_x),
+            first_y.min(board_y),
+            (first_x - board_x).abs() + 1,
+            (first_y - board_y).abs() + 1,
+        );
+    }
+
+    /// Copy the board pixels in a region into a new art in patterns/; empty cells are skipped
+    fn capture_board_region(&mut self, x: i32, y: i32, width: i32, height: i32) {
+        let mut pattern = Vec::new();
+        for art_x in 0..width {
+            for art_y in 0..height {
+                let pixel = self
+                    .board
+                    .get((x + art_x) as usize)
+                    .and_then(|column| column.get((y + art_y) as usize))
+                    .and_then(|pixel| pixel.as_ref());
+                if let Some(pixel) = pixel {
+                    pattern.push(ArtPixel {
+                        x: art_x,
+                        y: art_y,
+                        color: pixel.c,
+                    });
+                }
+            }
+        }
+
+        if pattern.is_empty() {
+            self.status_message = format!(
+                "Region {}x{} at ({}, {}) is empty - nothing to copy.",
+                width, height, x, y
+            );
+            return;
+        }
+
+        let name = format!("board_{}_{}_{}x{}", x, y, width, height);
+        let art = PixelArt {
+            name: name.clone(),
+            width,
+            height,
+            pattern,
+            created_at: Some(chrono::Utc::now().to_rfc3339()),
+            ..Default::default()
+        };
+
+        match crate::art::save_pixel_art(&art, &name) {
+            Ok(path) => self.add_status_message(format!(
+                "📋 Copied {} pixels from ({}, {}) into '{}' ({}). Load it with 'l'.",
+                art.pattern.len(),
+                x,
+                y,
+                name,
+                path.display()
+            )),
+            Err(e) => self.add_status_message(format!("❌ Could not save copied region: {}", e)),
+        }
+    }
+
+    /// Scale the loaded art by the largest integer factor that fits the region and center it there;
+    /// arts bigger than the region are cropped instead
+    fn fit_loaded_art_to_region(&mut self, x: i32, y: i32, width: i32, height: i32) {
+        let Some(art) = &self.loaded_art else {
+            return;
+        };
+        let (art_width, art_height) = crate::art::get_art_dimensions(art);
+        if art_width == 0 || art_height == 0 {
+            self.status_message = "Loaded art is empty - nothing to fit.".to_string();
+            return;
+        }
+
+        let scale = (width / art_width).min(height / art_height);
+        let mut fitted = if scale >= 1 {
+            crate::art::scale_art(art, scale)
+        } else {
+            crate::art::crop_art(art, width, height)
+        };
+        let (fitted_width, fitted_height) = crate::art::get_art_dimensions(&fitted);
+        fitted.board_x = x + (width - fitted_width) / 2;
+        fitted.board_y = y + (height - fitted_height) / 2;
+
+        self.status_message = if scale >= 1 {
+            format!(
+                "Fit '{}' into {}x{} region at scale {}x ({}x{}) at ({}, {}). Press Enter to place.",
+                fitted.name,
+                width,
+                height,
+                scale,
+                fitted_width,
+                fitted_height,
+                fitted.board_x,
+                fitted.board_y
+            )
+        } else {
+            format!(
+                "'{}' is larger than the {}x{} region - cropped to {}x{} at ({}, {}). Press Enter to place.",
+                fitted.name, width, height, fitted_width, fitted_height, fitted.board_x, fitted.board_y
+            )
+        };
+        self.loaded_art = Some(fitted);
+    }
+
+    /// Highlight a just-placed pixel on the board, ringing the bell if it's on ('a')
+    pub fn flash_placed_pixel(&mut self, position: (i32, i32)) {
+        self.placement_flash = Some((position, Instant::now()));
+        if self.placement_bell {
+            ring_bell();
+        }
+    }
+
     /// Handle placement updates from background art placement tasks
     pub fn handle_placement_update(&mut self, update: PlacementUpdate) {
         match update {
+            PlacementUpdate::PixelPlaced { position } => self.flash_placed_pixel(position),
             PlacementUpdate::Progress {
                 art_name,
                 pixel_index,
@@ -61,6 +236,9 @@ impl App {
                 pixel_index,
                 total_pixels,
             } => {
+                crate::event_log::log_event(crate::event_log::LogEvent::Error {
+                    message: format!("Placing '{}' failed: {}", art_name, error_msg),
+                });
                 self.add_status_message(format!(
                     "Error placing '{}' at pixel {}/{}: {}. Press 'r' to refresh board.",
                     art_name,
@@ -97,9 +275,54 @@ impl App {
         }
     }
 
+    /// Switch spectator mode, stopping any placement that is running when it turns on
+    pub fn toggle_spectator_mode(&mut self) {
+        self.spectator_mode = !self.spectator_mode;
+        if !self.spectator_mode {
+            self.add_status_message("✏️ Spectator mode off: placement enabled.".to_string());
+            return;
+        }
+
+        if self.queue_processing {
+            self.cancel_queue_processing();
+        }
+        if self.placement_in_progress {
+            self.placement_cancel_requested = true;
+            self.placement_in_progress = false;
+            self.placement_start = None;
+            self.placement_receiver = None;
+        }
+        self.add_status_message(
+            "👁️ Spectator mode on: placement disabled, board stays browsable.".to_string(),
+        );
+    }
+
+    /// Switch force placement: correct-looking pixels are placed anyway, for a stale board
+    pub fn toggle_force_placement(&mut self) {
+        self.force_placement = !self.force_placement;
+        self.add_status_message(if self.force_placement {
+            "⚠️ Force placement on: every art pixel is placed, even ones the board shows as correct. This spends buffer on pixels that may already be right.".to_string()
+        } else {
+            "Force placement off: pixels that are already correct are skipped again.".to_string()
+        });
+    }
+
+    /// In spectator mode, explain why nothing is placed and return true
+    pub fn placement_blocked_by_spectator(&mut self) -> bool {
+        if self.spectator_mode {
+            self.status_message =
+                "👁️ Spectator mode: placement is disabled. Press 'S' to leave it.".to_string();
+        }
+        self.spectator_mode
+    }
+
     /// Trigger non-blocking art placement if one isn't already in progress
     #[allow(dead_code)]
     pub fn trigger_art_placement(&mut self) {
+        if self.placement_blocked_by_spectator() {
+            return;
+        }
+
         if self.placement_in_progress {
             self.status_message =
                 "Art placement already in progress. Press Esc to cancel.".to_string();
@@ -144,7 +367,11 @@ impl App {
         let base_url = self.api_client.get_base_url();
         let access_token = self.api_client.get_access_token_clone();
         let refresh_token = self.api_client.get_refresh_token_clone();
+        let persist_tokens = self.persists_tokens();
         let _colors = self.colors.clone();
+        let known_min_px = self.user_info.as_ref().and_then(|info| info.min_px);
+        let reserve_buffer = self.reserve_buffer;
+        let api_debug = self.api_debug;
 
         self.status_message = format!(
             "Starting to place art '{}' ({} meaningful pixels out of {} total)...",
@@ -159,7 +386,8 @@ impl App {
                 crate::api_client::ApiClient::new(Some(base_url), access_token, refresh_token);
 
             // Set up callback to save refreshed tokens to storage
-            if let Ok(callback) = crate::api_client::create_token_refresh_callback(None) {
+            if let Ok(callback) = crate::api_client::session_token_refresh_callback(persist_tokens)
+            {
                 api_client.set_token_refresh_callback(callback);
             }
             // Note: We don't fail the placement if callback setup fails
@@ -171,6 +399,23 @@ impl App {
                 let abs_x = art_to_place.board_x + art_pixel.x;
                 let abs_y = art_to_place.board_y + art_pixel.y;
 
+                // Skip pixels the server would reject because of the min_px restriction
+                let min_px = user_info
+                    .as_ref()
+                    .and_then(|info| info.min_px)
+                    .or(known_min_px);
+                if !crate::api_client::min_px_allows_coordinate(min_px, abs_x, abs_y) {
+                    let _ = tx.send(PlacementUpdate::ApiCall {
+                        message: format!(
+                            "⏭️ Skipping pixel at ({},{}): below min_px {}",
+                            abs_x,
+                            abs_y,
+                            min_px.unwrap_or(0)
+                        ),
+                    });
+                    continue;
+                }
+
                 // Check for cooldown before placing pixel
                 if let Some(ref info) = user_info {
                     if info.pixel_buffer <= 0 && info.pixel_timer > 0 {
@@ -222,36 +467,45 @@ impl App {
                         });
                         pixels_placed += 1;
                         user_info = Some(response.user_infos);
+                        let _ = tx.send(PlacementUpdate::PixelPlaced {
+                            position: (abs_x, abs_y),
+                        });
                     }
                     Err(e) => {
                         // Send error log with status
-                        let status_text = match &e {
-                            crate::api_client::ApiError::ErrorResponse { status, .. } => {
-                                let status_emoji = match status.as_u16() {
+                        let status_text = match e.status_code() {
+                            Some(426) => "🔄426".to_string(),
+                            Some(status) => {
+                                let status_emoji = match status {
                                     400..=499 => "❌",
                                     500..=599 => "💥",
                                     _ => "❓",
                                 };
-                                format!("{}{}", status_emoji, status.as_u16())
+                                format!("{}{}", status_emoji, status)
                             }
-                            crate::api_client::ApiError::Unauthorized => "❌401".to_string(),
-                            crate::api_client::ApiError::TokenRefreshedPleaseRetry => {
-                                "🔄426".to_string()
-                            }
-                            _ => "💥ERR".to_string(),
+                            None => "💥ERR".to_string(),
                         };
 
                         let _ = tx.send(PlacementUpdate::ApiCall {
                             message: format!("🎨 POST /api/set → {}", status_text),
                         });
+                        if api_debug {
+                            let _ = tx.send(PlacementUpdate::ApiCall {
+                                message: format!(
+                                    "🐞 POST /api/set ({}, {}): {:?}",
+                                    abs_x, abs_y, e
+                                ),
+                            });
+                        } else if let Some(raw_body) = e.raw_body_preview(200) {
+                            let _ = tx.send(PlacementUpdate::ApiCall {
+                                message: format!("🐞 Raw response: {}", raw_body),
+                            });
+                        }
 
                         // Send error update
-                        let error_msg = match e {
-                            crate::api_client::ApiError::ErrorResponse {
-                                status: _,
-                                error_response,
-                            } => error_response.message,
-                            _ => format!("{:?}", e),
+                        let error_msg = match e.error_response() {
+                            Some(error_response) => error_response.message.clone(),
+                            None => e.summary(),
                         };
                         let _ = tx.send(PlacementUpdate::Error {
                             art_name: art_to_place.name.clone(),
@@ -263,8 +517,8 @@ impl App {
                     }
                 }
 
-                // Small delay between pixels
-                tokio::time::sleep(Duration::from_millis(100)).await;
+                // Small delay between pixels once the buffer is spent
+                pause_between_pixels(user_info.as_ref(), reserve_buffer).await;
             }
 
             // Send completion update
@@ -279,6 +533,9 @@ impl App {
     /// Legacy art placement method for synchronous placement
     #[allow(dead_code)]
     pub async fn place_loaded_art(&mut self) {
+        if self.placement_blocked_by_spectator() {
+            return;
+        }
         if self.loaded_art.is_none() {
             self.status_message = "No art loaded to place.".to_string();
             return;
@@ -315,7 +572,8 @@ impl App {
             let abs_y = art_to_place.board_y + art_pixel.y;
 
             // Check if pixel is already the correct color to avoid unnecessary placement
-            if self.is_pixel_already_correct(abs_x, abs_y, art_pixel.color) {
+            if !self.force_placement && self.is_pixel_already_correct(abs_x, abs_y, art_pixel.color)
+            {
                 self.status_message = format!(
                     "Pixel {}/{} at ({},{}) already correct color, skipping...",
                     index + 1,
@@ -326,6 +584,21 @@ impl App {
                 continue;
             }
 
+            // Skip pixels the server would reject because of the min_px restriction
+            if let Some(u_info) = &self.user_info {
+                if !u_info.allows_coordinate(abs_x, abs_y) {
+                    self.add_status_message(format!(
+                        "⏭️ Pixel {}/{} at ({},{}) is below min_px {}, skipping...",
+                        index + 1,
+                        total_pixels,
+                        abs_x,
+                        abs_y,
+                        u_info.min_px.unwrap_or(0)
+                    ));
+                    continue;
+                }
+            }
+
             self.status_message = format!(
                 "Placing pixel {}/{} ('{}') at ({},{}) with color_id {}...",
                 index + 1,
@@ -373,19 +646,8 @@ impl App {
                     self.user_info = Some(response.user_infos);
                 }
                 Err(e) => {
-                    // Log API error with status code
-                    match &e {
-                        ApiError::ErrorResponse { status, .. } => {
-                            self.log_api_call("POST", "/api/set", Some(status.as_u16()));
-                        }
-                        ApiError::Unauthorized => {
-                            self.log_api_call("POST", "/api/set", Some(401));
-                        }
-                        _ => {
-                            // For network errors or other issues, log without status
-                            self.log_api_call("POST", "/api/set", None);
-                        }
-                    }
+                    // Log API error with status code (None for network errors)
+                    self.log_api_call("POST", "/api/set", e.status_code());
 
                     // Use enhanced error display for API errors
                     let base_message = format!(
@@ -398,7 +660,7 @@ impl App {
 
                     // Check if it's unauthorized first for token clearing
                     match &e {
-                        ApiError::Unauthorized
+                        ApiError::Unauthorized { .. }
                         | ApiError::ErrorResponse {
                             status: reqwest::StatusCode::UNAUTHORIZED,
                             ..
@@ -413,23 +675,39 @@ impl App {
                         _ => {}
                     }
 
-                    // For rate limiting errors, don't halt placement - let enhanced display handle it
-                    if let ApiError::ErrorResponse { status, .. } = &e {
-                        if status == &reqwest::StatusCode::TOO_MANY_REQUESTS
-                            || status.as_u16() == 425
-                            || status.as_u16() == 420
-                        {
-                            // Enhanced display already updated user timers, just refresh board
+                    match &e {
+                        ApiError::RateLimited { .. } => {
+                            // Pick up the new timers, then refresh to show what landed
+                            self.handle_api_error_with_enhanced_display(&base_message, &e)
+                                .await;
                             self.trigger_board_fetch();
                         }
+                        ApiError::EventNotStarted { starts_in, .. } => {
+                            self.status_message = format!(
+                                "{}: the event has not started yet{}.",
+                                base_message,
+                                starts_in.map_or(String::new(), |secs| format!(
+                                    " (starts in {}s)",
+                                    secs
+                                ))
+                            );
+                        }
+                        ApiError::EventClosed { interval, .. } => {
+                            self.status_message = format!(
+                                "{}: the event ended {} seconds ago.",
+                                base_message,
+                                interval.abs()
+                            );
+                        }
+                        _ => {}
                     }
 
                     return;
                 }
             }
 
-            // Small delay between pixels
-            tokio::time::sleep(Duration::from_millis(100)).await;
+            // Small delay between pixels once the buffer is spent
+            pause_between_pixels(self.user_info.as_ref(), self.reserve_buffer).await;
         }
 
         self.status_message = format!(
@@ -439,6 +717,30 @@ impl App {
         self.trigger_board_fetch();
     }
 
+    /// Reload the art selection list along with each art's size, so rendering stays cheap
+    pub fn refresh_available_pixel_arts(&mut self) {
+        self.available_pixel_arts = crate::art::get_available_pixel_arts(self.art_palette());
+
+        let background_color_ids = self.get_background_color_ids();
+        self.available_art_sizes = self
+            .available_pixel_arts
+            .iter()
+            .map(|art| {
+                // Same rule as filter_meaningful_pixels: first pixel per position wins
+                let mut seen_positions = HashSet::new();
+                let meaningful_pixels = art
+                    .pattern
+                    .iter()
+                    .filter(|pixel| {
+                        seen_positions.insert((pixel.x, pixel.y))
+                            && !background_color_ids.contains(&pixel.color)
+                    })
+                    .count();
+                (crate::art::get_art_dimensions(art), meaningful_pixels)
+            })
+            .collect();
+    }
+
     /// Filter out background/transparent pixels and remove duplicates
     pub fn filter_meaningful_pixels(&self, art: &PixelArt) -> Vec<ArtPixel> {
         let mut meaningful_pixels = Vec::new();
@@ -464,36 +766,107 @@ impl App {
             seen_positions.insert(position);
         }
 
+        // Pixels positioned off the board are skipped rather than sent to the API
+        let meaningful_pixels =
+            super::queue_management::retain_on_board_pixels(meaningful_pixels, art, &self.board);
+
         // Apply border-first ordering
         super::queue_management::order_pixels_border_first(meaningful_pixels)
     }
 
-    /// Get color IDs that should be considered background/transparent
-    pub fn get_background_color_ids(&self) -> HashSet<i32> {
-        let mut background_ids = HashSet::new();
-
-        // Only filter colors explicitly marked as transparent/background
-        for color in &self.colors {
-            let name_lower = color.name.to_lowercase();
-            if name_lower.contains("transparent") 
-				|| name_lower.contains("background")
-				|| name_lower.contains("empty")
-				|| name_lower == "none"
-				// Only filter if explicitly alpha/transparent in name
-				|| name_lower.contains("alpha")
-            {
-                background_ids.insert(color.id);
-            }
+    /// Append how many of the loaded art's pixels already match the board where it sits
+    pub fn note_positioning_overlap(&mut self) {
+        let Some(art) = &self.loaded_art else {
+            return;
+        };
+
+        if art.pattern.len() > OVERLAP_COUNT_DEBOUNCE_PIXELS {
+            self.overlap_count_due = Some(Instant::now() + OVERLAP_COUNT_DEBOUNCE);
+            self.status_message.push_str(OVERLAP_COUNT_PENDING);
+        } else {
+            self.overlap_count_due = None;
+            let note = self.positioning_overlap_note();
+            self.status_message.push_str(&note);
         }
+    }
 
-        // Don't filter any colors by default - let users place any color they want
-        // Including white (color_id 1) which is a valid placeable color
+    /// Finish a debounced already-correct count once the art has stayed put
+    pub fn update_positioning_overlap(&mut self) {
+        if self
+            .overlap_count_due
+            .is_none_or(|due| Instant::now() < due)
+        {
+            return;
+        }
+        self.overlap_count_due = None;
 
-        background_ids
+        // Only fill in the placeholder if nothing has replaced the message since
+        if let Some(message) = self.status_message.strip_suffix(OVERLAP_COUNT_PENDING) {
+            self.status_message = format!("{}{}", message, self.positioning_overlap_note());
+        }
+    }
+
+    /// " N/M pixels already correct." for the loaded art at its current position
+    fn positioning_overlap_note(&self) -> String {
+        let Some(art) = &self.loaded_art else {
+            return String::new();
+        };
+        if self.board.is_empty() {
+            return String::new();
+        }
+
+        let meaningful_pixels = self.filter_meaningful_pixels(art);
+        let already_correct = meaningful_pixels
+            .iter()
+            .filter(|pixel| {
+                self.is_pixel_already_correct(
+                    art.board_x + pixel.x,
+                    art.board_y + pixel.y,
+                    pixel.color,
+                )
+            })
+            .count();
+
+        format!(
+            " {}/{} pixels already correct.",
+            already_correct,
+            meaningful_pixels.len()
+        )
+    }
+
+    /// " - N pixels off-board (will be skipped)" for an art partly outside the board, else empty
+    pub fn off_board_note(
+        board: &[Vec<Option<crate::api_client::PixelNetwork>>],
+        art: &PixelArt,
+    ) -> String {
+        if board.is_empty() {
+            return String::new();
+        }
+
+        let board_width = board.len() as i32;
+        let board_height = board[0].len() as i32;
+        let off_board = art
+            .pattern
+            .iter()
+            .map(|pixel| (art.board_x + pixel.x, art.board_y + pixel.y))
+            .filter(|(x, y)| !(0..board_width).contains(x) || !(0..board_height).contains(y))
+            .collect::<HashSet<_>>()
+            .len();
+
+        if off_board == 0 {
+            String::new()
+        } else {
+            format!(" - {} pixels off-board (will be skipped)", off_board)
+        }
+    }
+
+    /// Get color IDs that should be considered background/transparent
+    pub fn get_background_color_ids(&self) -> HashSet<i32> {
+        // Only colors the skip rules match; white (color_id 1) is a valid placeable color
+        self.skip_rules.background_color_ids(&self.colors)
     }
 
     /// Check if a pixel at the given position already has the correct color
-    #[allow(dead_code)]
     pub fn is_pixel_already_correct(&self, x: i32, y: i32, expected_color_id: i32) -> bool {
         // Convert to usize for array indexing
         let x_idx = x as usize;
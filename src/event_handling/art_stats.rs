@@ -0,0 +1,44 @@
+use crate::app_state::App;
+
+impl App {
+    pub fn load_art_stats(&mut self) -> Result<(), Box<dyn std::error::Error>> {
+        let stats_path = crate::paths::data_path("art_stats.json");
+        if stats_path.exists() {
+            let stats_data = std::fs::read_to_string(&stats_path)?;
+            self.art_stats = serde_json::from_str(&stats_data)?;
+        }
+        Ok(())
+    }
+
+    fn save_art_stats(&self) -> Result<(), Box<dyn std::error::Error>> {
+        let stats_data = serde_json::to_string_pretty(&self.art_stats)?;
+        std::fs::write(crate::paths::data_path("art_stats.json"), stats_data)?;
+        Ok(())
+    }
+
+    /// Add a finished (completed or failed) queue run of an art to its history
+    pub fn record_art_run(&mut self, art_name: &str, pixels_placed: usize, total_pixels: usize) {
+        let stats = self.art_stats.entry(art_name.to_string()).or_default();
+        stats.runs += 1;
+        stats.pixels_succeeded += pixels_placed as u64;
+        stats.pixels_failed += total_pixels.saturating_sub(pixels_placed) as u64;
+        let _ = self.save_art_stats();
+    }
+
+    /// "placed 4 times, 97% pixel success" for the art preview, once the art has a history
+    pub fn art_stats_summary(&self, art_name: &str) -> Option<String> {
+        let stats = self.art_stats.get(art_name)?;
+        let attempted = stats.pixels_succeeded + stats.pixels_failed;
+        let success = if attempted == 0 {
+            100.0
+        } else {
+            stats.pixels_succeeded as f64 * 100.0 / attempted as f64
+        };
+        Some(format!(
+            "placed {} time{}, {:.0}% pixel success",
+            stats.runs,
+            if stats.runs == 1 { "" } else { "s" },
+            success
+        ))
+    }
+}
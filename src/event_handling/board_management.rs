@@ -1,8 +1,12 @@
-use crate::api_client::ApiError;
-use crate::app_state::{App, BoardFetchResult};
-use std::time::Instant;
+use crate::api_client::{ApiError, BoardGetResponse, ColorInfo};
+use crate::app_state::{App, BoardFetchResult, BoardInfo, ConnectionState, InputMode};
+use std::collections::HashSet;
+use std::time::{Duration, Instant};
 use tokio::sync::mpsc;
 
+/// How often a full board is fetched even when the server supports deltas
+const FULL_BOARD_RESYNC_INTERVAL: Duration = Duration::from_secs(300);
+
 impl App {
     /// Trigger a non-blocking board fetch if one isn't already in progress
     pub fn trigger_board_fetch(&mut self) {
@@ -24,10 +28,15 @@ impl App {
         let (tx, rx) = mpsc::unbounded_channel();
         self.board_fetch_receiver = Some(rx);
 
+        // Ask only for recent changes when we have a board to patch
+        let delta_since = self.delta_fetch_since();
+        self.board_fetch_is_delta = delta_since.is_some();
+
         // Clone API client data needed for the fetch
         let base_url = self.api_client.get_base_url();
         let access_token = self.api_client.get_access_token_clone();
         let refresh_token = self.api_client.get_refresh_token_clone();
+        let persist_tokens = self.persists_tokens();
         let _colors = self.colors.clone();
 
         // Add API call log to status messages
@@ -39,34 +48,53 @@ impl App {
                 crate::api_client::ApiClient::new(Some(base_url), access_token, refresh_token);
 
             // Set up callback to save refreshed tokens to storage
-            if let Ok(callback) = crate::api_client::create_token_refresh_callback(None) {
+            if let Ok(callback) = crate::api_client::session_token_refresh_callback(persist_tokens)
+            {
                 api_client.set_token_refresh_callback(callback);
             } else {
-                let _ = tx.send(BoardFetchResult::Error(
-                    "Failed to initialize token storage".to_string(),
-                ));
+                let _ = tx.send(BoardFetchResult::Error {
+                    message: "Failed to initialize token storage".to_string(),
+                    status: None,
+                });
                 return;
             }
 
             // Store initial tokens for comparison
             let initial_tokens = api_client.get_tokens();
 
-            let result = match api_client.get_board().await {
+            let response = match delta_since {
+                Some(since) => api_client.get_board_delta(since).await,
+                None => api_client.get_board().await,
+            };
+
+            let result = match response {
                 Ok(board_response) => {
                     // Check if tokens were updated during the request
                     let current_tokens = api_client.get_tokens();
                     let tokens_changed = initial_tokens != current_tokens;
-
-                    BoardFetchResult::Success {
-                        board_response,
-                        updated_tokens: if tokens_changed {
-                            Some(current_tokens)
-                        } else {
-                            None
-                        },
+                    let updated_tokens = if tokens_changed {
+                        Some(current_tokens)
+                    } else {
+                        None
+                    };
+
+                    // A server that ignored min_time sent the full board instead
+                    if delta_since.is_some() && board_response.min_time == delta_since {
+                        BoardFetchResult::Delta {
+                            board_response,
+                            updated_tokens,
+                        }
+                    } else {
+                        BoardFetchResult::Success {
+                            board_response,
+                            updated_tokens,
+                        }
                     }
                 }
-                Err(e) => BoardFetchResult::Error(format!("{:?}", e)),
+                Err(e) => BoardFetchResult::Error {
+                    message: e.summary(),
+                    status: e.status_code(),
+                },
             };
 
             // Send result back - if this fails, the main app has been dropped
@@ -81,21 +109,56 @@ impl App {
             .map(|start| start.elapsed().as_millis())
             .unwrap_or(0);
 
-        match result {
+        // Patch deltas into the current board, then handle them like a full fetch
+        let mut delta_changes = None;
+        let mut retry_for_palette = false;
+        let result = match result {
+            BoardFetchResult::Delta {
+                board_response,
+                updated_tokens,
+            } => match self.apply_board_delta(board_response) {
+                Ok((board_response, changed)) => {
+                    self.board_delta_supported = Some(true);
+                    delta_changes = Some(changed);
+                    Ok((board_response, updated_tokens))
+                }
+                Err(message) => Err((message, None)),
+            },
             BoardFetchResult::Success {
                 board_response,
                 updated_tokens,
             } => {
+                if self.board_fetch_is_delta && self.board_delta_supported.is_none() {
+                    self.board_delta_supported = Some(false);
+                    self.add_status_message(
+                        "📡 Server has no delta board updates - using full fetches.".to_string(),
+                    );
+                }
+                self.last_full_board_fetch = Some(Instant::now());
+                Ok((board_response, updated_tokens))
+            }
+            BoardFetchResult::Error { message, status } => Err((message, status)),
+        };
+
+        match result {
+            Ok((board_response, updated_tokens)) => {
                 // Log successful API call
                 self.log_api_call("GET", "/api/get", Some(200));
+                self.connection_state = ConnectionState::Connected;
 
                 // Update main API client tokens if they were refreshed
                 if let Some((access_token, refresh_token)) = updated_tokens {
                     self.api_client.set_tokens(access_token, refresh_token);
                 }
 
+                retry_for_palette = self.apply_board_colors(board_response.colors);
                 self.board = board_response.board;
-                self.colors = board_response.colors;
+                self.board_last_pixel_time = self
+                    .board
+                    .iter()
+                    .flatten()
+                    .filter_map(|pixel| pixel.as_ref().map(|p| p.t))
+                    .max();
 
                 // Update shared board state if it exists (for queue processing)
                 if let Some(shared_board) = &self.shared_board_state {
@@ -104,10 +167,19 @@ impl App {
                     }
                 }
 
+                // Board stats are stale now; keep them live if the popup is open
+                self.board_info = None;
+                if self.input_mode == InputMode::ShowBoardInfo {
+                    self.ensure_board_info();
+                }
+
                 // Set status message directly without adding to history to avoid overriding other logs
                 self.status_message = format!(
-                    "Board data loaded in {}ms. {} colors. Board size: {}x{}. Arrows to scroll.",
+                    "Board data loaded in {}ms{}. {} colors. Board size: {}x{}. Arrows to scroll.",
                     load_time,
+                    delta_changes
+                        .map(|changed| format!(" (delta: {} changed pixels)", changed))
+                        .unwrap_or_default(),
                     self.colors.len(),
                     self.board.len(),
                     if self.board.is_empty() {
@@ -120,13 +192,19 @@ impl App {
                 self.last_board_refresh = Some(Instant::now());
                 if !self.initial_board_fetched {
                     self.initial_board_fetched = true;
+                    self.restore_saved_viewport();
 
                     // Automatically fetch profile on initial board load
                     self.trigger_profile_fetch();
                 }
 
+                // Alert on watched pixels that changed since the last refresh
+                self.check_watched_pixels();
+                self.refresh_color_search();
+
                 // Recalculate queue totals now that we have updated board data
                 self.recalculate_queue_totals();
+                self.run_integrity_check();
 
                 // Check if queue should auto-resume (after app restart)
                 self.check_auto_resume_queue();
@@ -134,24 +212,14 @@ impl App {
                 // Save tokens in case they were refreshed during the API call
                 self.save_tokens();
             }
-            BoardFetchResult::Error(error_msg) => {
-                // Try to extract status code from error message for logging
-                let status_code = if error_msg.contains("401") || error_msg.contains("Unauthorized")
-                {
-                    Some(401)
-                } else if error_msg.contains("403") || error_msg.contains("Forbidden") {
-                    Some(403)
-                } else if error_msg.contains("404") {
-                    Some(404)
-                } else if error_msg.contains("500") {
-                    Some(500)
-                } else {
-                    None
-                };
-
+            Err((error_msg, status_code)) => {
                 if let Some(code) = status_code {
                     self.log_api_call("GET", "/api/get", Some(code));
                 }
+                self.connection_state = ConnectionState::from_fetch_status(status_code);
+                if self.api_debug {
+                    self.add_status_message(format!("🐞 GET /api/get: {}", error_msg));
+                }
 
                 // Set status message directly without adding to history to avoid overriding other logs
                 self.status_message = format!(
@@ -166,6 +234,94 @@ impl App {
         self.board_loading = false;
         self.board_load_start = None;
         self.board_fetch_receiver = None;
+
+        if retry_for_palette {
+            self.trigger_board_fetch();
+        }
+    }
+
+    /// Take the palette from a board response. An empty one is almost always a transient
+    /// or malformed response, so the current (or cached) palette is kept instead.
+    /// Returns true when the board should be re-requested once.
+    fn apply_board_colors(&mut self, colors: Vec<ColorInfo>) -> bool {
+        if !colors.is_empty() {
+            self.empty_palette_retried = false;
+            if self.colors != colors {
+                self.colors = colors;
+                self.save_cached_palette();
+                self.remap_offline_queue_arts();
+            }
+            return false;
+        }
+
+        if self.colors.is_empty() || self.using_default_palette() {
+            if let Some(cached) = self
+                .token_storage
+                .load_palette(&self.api_client.get_base_url())
+            {
+                self.colors = cached;
+                self.remap_offline_queue_arts();
+            }
+        }
+        let palette_note = if self.colors.is_empty() {
+            "no palette available yet".to_string()
+        } else {
+            format!("keeping the previous {} colors", self.colors.len())
+        };
+
+        let retry = !self.empty_palette_retried;
+        self.empty_palette_retried = true;
+        self.add_status_message(format!(
+            "⚠️ Board response had no colors - {}{}",
+            palette_note,
+            if retry { ", retrying once" } else { "" }
+        ));
+        retry
+    }
+
+    /// Timestamp to request a delta from, or None when a full fetch is due
+    fn delta_fetch_since(&self) -> Option<i64> {
+        if self.board.is_empty() || self.board_delta_supported == Some(false) {
+            return None;
+        }
+        if self
+            .last_full_board_fetch
+            .is_none_or(|last| last.elapsed() >= FULL_BOARD_RESYNC_INTERVAL)
+        {
+            return None;
+        }
+        self.board_last_pixel_time
+    }
+
+    /// Merge the pixels of a delta response into the current board
+    fn apply_board_delta(
+        &mut self,
+        mut delta: BoardGetResponse,
+    ) -> Result<(BoardGetResponse, usize), String> {
+        let same_size = delta.board.len() == self.board.len()
+            && delta
+                .board
+                .iter()
+                .zip(self.board.iter())
+                .all(|(new, old)| new.len() == old.len());
+        if !same_size {
+            // Board was resized; force the next fetch to be a full one
+            self.last_full_board_fetch = None;
+            return Err("Delta board size doesn't match the current board".to_string());
+        }
+
+        let mut board = std::mem::take(&mut self.board);
+        let mut changed = 0;
+        for (x, column) in delta.board.into_iter().enumerate() {
+            for (y, pixel) in column.into_iter().enumerate() {
+                if pixel.is_some() {
+                    board[x][y] = pixel;
+                    changed += 1;
+                }
+            }
+        }
+        delta.board = board;
+        Ok((delta, changed))
     }
 
     /// Legacy board fetch method for compatibility
@@ -184,10 +340,11 @@ impl App {
             Ok(board_response) => {
                 // Log successful API call
                 self.log_api_call("GET", "/api/get", Some(200));
+                self.connection_state = ConnectionState::Connected;
 
                 // Tokens are already updated in the main API client via the retry mechanism
                 self.board = board_response.board;
-                self.colors = board_response.colors;
+                self.apply_board_colors(board_response.colors);
 
                 // Update shared board state if it exists (for queue processing)
                 if let Some(shared_board) = &self.shared_board_state {
@@ -216,6 +373,7 @@ impl App {
                 self.last_board_refresh = Some(Instant::now());
                 if !self.initial_board_fetched {
                     self.initial_board_fetched = true;
+                    self.restore_saved_viewport();
 
                     // Automatically fetch profile on initial board load
                     self.trigger_profile_fetch();
@@ -238,7 +396,7 @@ impl App {
                     ApiError::ErrorResponse { status, .. } => {
                         self.log_api_call("GET", "/api/get", Some(status.as_u16()));
                     }
-                    ApiError::Unauthorized => {
+                    ApiError::Unauthorized { .. } => {
                         self.log_api_call("GET", "/api/get", Some(401));
                     }
                     _ => {
@@ -246,13 +404,19 @@ impl App {
                         self.log_api_call("GET", "/api/get", None);
                     }
                 }
+                self.connection_state = ConnectionState::from_fetch_status(e.status_code());
 
                 match e {
-                    ApiError::Unauthorized => {
+                    ApiError::Unauthorized { login_page } => {
                         self.status_message = format!(
-							"Session expired after {}ms. Auto-refresh paused. Enter new tokens or restart.", 
-							load_time
-						);
+                            "{} after {}ms. Auto-refresh paused. Enter new tokens or restart.",
+                            if login_page {
+                                "Login page instead of board data (tokens likely invalid)"
+                            } else {
+                                "Session expired"
+                            },
+                            load_time
+                        );
                         self.api_client.clear_tokens();
                         // Clear saved tokens when session expires
                         self.clear_saved_tokens();
@@ -273,4 +437,59 @@ impl App {
         self.board_loading = false;
         self.board_load_start = None;
     }
+
+    /// Compute board dimensions, fill and palette facts for the info popup
+    pub fn compute_board_info(&self) -> BoardInfo {
+        let non_empty_pixels = self
+            .board
+            .iter()
+            .map(|column| column.iter().filter(|pixel| pixel.is_some()).count())
+            .sum();
+
+        let viewport = self.current_viewport();
+        let (viewport_x, viewport_y, viewport_width, viewport_height) = viewport;
+        let viewport_colors: HashSet<i32> = self
+            .board
+            .iter()
+            .skip(viewport_x as usize)
+            .take(viewport_width as usize)
+            .flat_map(|column| {
+                column
+                    .iter()
+                    .skip(viewport_y as usize)
+                    .take(viewport_height as usize)
+            })
+            .filter_map(|pixel| pixel.as_ref().map(|p| p.c))
+            .collect();
+
+        BoardInfo {
+            width: self.board.len(),
+            height: self.board.first().map_or(0, |column| column.len()),
+            non_empty_pixels,
+            color_count: self.colors.len(),
+            viewport,
+            viewport_color_count: viewport_colors.len(),
+            base_url: self.api_client.get_base_url(),
+        }
+    }
+
+    /// Reuse the cached board info unless it's missing or the viewport moved
+    pub fn ensure_board_info(&mut self) {
+        let viewport = self.current_viewport();
+        if self
+            .board_info
+            .as_ref()
+            .is_none_or(|info| info.viewport != viewport)
+        {
+            self.board_info = Some(self.compute_board_info());
+        }
+    }
+
+    /// Visible board region in board pixels: (x, y, width, height)
+    fn current_viewport(&self) -> (u16, u16, u16, u16) {
+        let (width, height) = self
+            .board_area_bounds
+            .map_or((0, 0), |(_, _, width, height)| (width, height * 2));
+        (self.board_viewport_x, self.board_viewport_y, width, height)
+    }
 }
@@ -0,0 +1,160 @@
+use crate::app_state::{App, InputMode, PixelChange};
+use std::path::PathBuf;
+
+/// Board colors by column, as stored in `snapshots/<timestamp>.json`
+type SnapshotGrid = Vec<Vec<Option<i32>>>;
+
+impl App {
+    /// Save the current board colors to `snapshots/` for later diffing
+    pub fn save_board_snapshot(&mut self) {
+        if self.board.is_empty() {
+            self.status_message = "Board not loaded yet - nothing to snapshot.".to_string();
+            return;
+        }
+
+        let name = chrono::Local::now().format("%Y%m%d-%H%M%S").to_string();
+        match self.write_board_snapshot(&name) {
+            Ok(()) => self.add_status_message(format!("📸 Board snapshot saved as '{}'.", name)),
+            Err(e) => self.add_status_message(format!("❌ Could not save board snapshot: {}", e)),
+        }
+    }
+
+    /// Ask which two snapshots to compare
+    pub fn start_snapshot_diff(&mut self) {
+        let names = list_snapshots();
+        if names.len() < 2 {
+            self.status_message = format!(
+                "Need two board snapshots to diff ({} saved). Press 'k' to save one.",
+                names.len()
+            );
+            return;
+        }
+
+        let recent = names.iter().rev().take(5).cloned().collect::<Vec<_>>();
+        self.input_mode = InputMode::EnterSnapshotDiff;
+        self.input_buffer.clear();
+        self.status_message = format!(
+            "Diff 'OLD NEW' (Enter for the latest two). Recent: {}",
+            recent.join(", ")
+        );
+    }
+
+    /// Load the two snapshots named in `query` (or the latest two) and overlay their differences
+    pub fn apply_snapshot_diff(&mut self, query: &str) {
+        self.input_mode = InputMode::None;
+
+        let names = list_snapshots();
+        let requested = query.split_whitespace().collect::<Vec<_>>();
+        let (from, to) = match requested.as_slice() {
+            [] if names.len() >= 2 => (
+                names[names.len() - 2].clone(),
+                names[names.len() - 1].clone(),
+            ),
+            [from, to] => (from.to_string(), to.to_string()),
+            _ => {
+                self.status_message =
+                    "Give two snapshot names ('OLD NEW') or leave empty for the latest two."
+                        .to_string();
+                return;
+            }
+        };
+
+        let (old_grid, new_grid) = match (load_snapshot(&from), load_snapshot(&to)) {
+            (Ok(old_grid), Ok(new_grid)) => (old_grid, new_grid),
+            (Err(e), _) | (_, Err(e)) => {
+                self.status_message = format!("❌ Could not load snapshot: {}", e);
+                return;
+            }
+        };
+
+        let width = old_grid.len().max(new_grid.len());
+        let mut changes = Vec::new();
+        for x in 0..width {
+            let old_column = old_grid.get(x).map_or(&[][..], |column| column.as_slice());
+            let new_column = new_grid.get(x).map_or(&[][..], |column| column.as_slice());
+            for y in 0..old_column.len().max(new_column.len()) {
+                let old_color = old_column.get(y).copied().flatten();
+                let new_color = new_column.get(y).copied().flatten();
+                let change = match (old_color, new_color) {
+                    (None, Some(_)) => PixelChange::Added,
+                    (Some(_), None) => PixelChange::Removed,
+                    (Some(old), Some(new)) if old != new => PixelChange::Recolored,
+                    _ => continue,
+                };
+                changes.push((x as i32, y as i32, change));
+            }
+        }
+
+        let count = |kind: PixelChange| changes.iter().filter(|(_, _, c)| *c == kind).count();
+        let message = format!(
+            "🗂️ {} → {}: {} pixels changed ({} added, {} removed, {} recolored). Esc: clear",
+            from,
+            to,
+            changes.len(),
+            count(PixelChange::Added),
+            count(PixelChange::Removed),
+            count(PixelChange::Recolored)
+        );
+        self.add_status_message(message.clone());
+        self.status_message = message;
+        self.snapshot_diff = Some(changes);
+    }
+
+    /// Stop drawing the snapshot diff overlay
+    pub fn clear_snapshot_diff(&mut self) {
+        self.snapshot_diff = None;
+        self.status_message = "Snapshot diff cleared.".to_string();
+    }
+
+    fn write_board_snapshot(&self, name: &str) -> Result<(), Box<dyn std::error::Error>> {
+        let grid: SnapshotGrid = self
+            .board
+            .iter()
+            .map(|column| {
+                column
+                    .iter()
+                    .map(|pixel| pixel.as_ref().map(|p| p.c))
+                    .collect()
+            })
+            .collect();
+        std::fs::create_dir_all(snapshot_dir())?;
+        std::fs::write(snapshot_path(name), serde_json::to_string(&grid)?)?;
+        Ok(())
+    }
+}
+
+fn snapshot_dir() -> PathBuf {
+    crate::paths::data_path("snapshots")
+}
+
+fn snapshot_path(name: &str) -> PathBuf {
+    snapshot_dir().join(format!("{}.json", name))
+}
+
+/// Saved snapshot names, oldest first (timestamps sort chronologically)
+fn list_snapshots() -> Vec<String> {
+    let mut names = std::fs::read_dir(snapshot_dir())
+        .map(|entries| {
+            entries
+                .filter_map(|entry| entry.ok())
+                .filter_map(|entry| {
+                    let path = entry.path();
+                    if path.extension().is_some_and(|ext| ext == "json") {
+                        path.file_stem()
+                            .map(|stem| stem.to_string_lossy().into_owned())
+                    } else {
+                        None
+                    }
+                })
+                .collect::<Vec<_>>()
+        })
+        .unwrap_or_default();
+    names.sort();
+    names
+}
+
+fn load_snapshot(name: &str) -> Result<SnapshotGrid, Box<dyn std::error::Error>> {
+    let data =
+        std::fs::read_to_string(snapshot_path(name)).map_err(|e| format!("'{}': {}", name, e))?;
+    Ok(serde_json::from_str(&data)?)
+}
@@ -0,0 +1,307 @@
+use crate::api_client::PixelNetwork;
+use crate::app_state::{App, ColorSearch, InputMode, SearchTarget};
+use std::collections::HashSet;
+use tokio::sync::mpsc;
+
+impl App {
+    /// Ask for the color (or loaded art template) to look for on the board
+    pub fn start_color_search(&mut self) {
+        if self.board.is_empty() {
+            self.status_message = "Board not loaded yet - nothing to search.".to_string();
+            return;
+        }
+        self.input_mode = InputMode::EnterColorSearch;
+        self.input_buffer.clear();
+        self.status_message =
+            "Search color (id, name or 'empty'), or 'art [percent]' for the loaded art:"
+                .to_string();
+    }
+
+    /// Find every match for `query` and jump to the one nearest the viewport center
+    pub fn apply_color_search(&mut self, query: &str) {
+        self.input_mode = InputMode::None;
+
+        let query = query.trim();
+        let mut words = query.split_whitespace();
+        let target = if words
+            .next()
+            .is_some_and(|word| word.eq_ignore_ascii_case("art"))
+        {
+            let Some(art) = &self.loaded_art else {
+                self.status_message =
+                    "Load an art first ('l') to search the board for it.".to_string();
+                return;
+            };
+            let min_percent = match words.next().map(|word| word.trim_end_matches('%').parse()) {
+                None => 100,
+                Some(Ok(percent @ 1..=100)) => percent,
+                Some(_) => {
+                    self.status_message =
+                        "Template match percent must be between 1 and 100.".to_string();
+                    return;
+                }
+            };
+
+            // Same pixels placement would use: no background colors, one per position
+            let background_color_ids = self.get_background_color_ids();
+            let mut seen_positions = HashSet::new();
+            let mut pixels = art
+                .pattern
+                .iter()
+                .filter(|pixel| !background_color_ids.contains(&pixel.color))
+                .filter(|pixel| seen_positions.insert((pixel.x, pixel.y)))
+                .cloned()
+                .collect::<Vec<_>>();
+            if pixels.is_empty() {
+                self.status_message = format!("'{}' has no pixels to search for.", art.name);
+                return;
+            }
+            // Matches are reported at the pixels' top-left, wherever the art's origin is
+            let min_x = pixels.iter().map(|p| p.x).min().unwrap_or(0);
+            let min_y = pixels.iter().map(|p| p.y).min().unwrap_or(0);
+            for pixel in &mut pixels {
+                pixel.x -= min_x;
+                pixel.y -= min_y;
+            }
+
+            SearchTarget::Template {
+                name: art.name.clone(),
+                pixels,
+                min_percent,
+            }
+        } else if query.eq_ignore_ascii_case("empty") {
+            SearchTarget::Color(None)
+        } else if let Some(color) = self.colors.iter().find(|color| {
+            query.parse::<i32>().is_ok_and(|id| id == color.id)
+                || color.name.eq_ignore_ascii_case(query)
+                || self.color_display_name(color).eq_ignore_ascii_case(query)
+        }) {
+            SearchTarget::Color(Some(color.id))
+        } else {
+            self.status_message = format!(
+                "No color '{}' in the palette. Use a color id, a name, 'empty' or 'art'.",
+                query
+            );
+            return;
+        };
+
+        self.color_search = Some(ColorSearch {
+            target,
+            origin: self.viewport_center(),
+            matches: Vec::new(),
+            current: 0,
+            searching: true,
+        });
+        self.status_message = "🔍 Searching the board...".to_string();
+        self.refresh_color_search();
+    }
+
+    /// Recompute matches against the current board, e.g. after a refresh. A template
+    /// scan costs board size × art size, so it runs off the UI thread; starting another
+    /// one drops the receiver of any still running.
+    pub fn refresh_color_search(&mut self) {
+        let Some(search) = &self.color_search else {
+            return;
+        };
+
+        let board = self.board.clone();
+        let target = search.target.clone();
+        let origin = search.origin;
+        let (tx, rx) = mpsc::unbounded_channel();
+        self.color_search_receiver = Some(rx);
+        tokio::task::spawn_blocking(move || {
+            let _ = tx.send(find_search_matches(&board, &target, origin));
+        });
+    }
+
+    /// Store freshly computed matches; the first ones of a search move the viewport
+    pub fn handle_color_search_result(&mut self, matches: Vec<(i32, i32)>) {
+        self.color_search_receiver = None;
+        let Some(search) = &mut self.color_search else {
+            return;
+        };
+        search.current = search.current.min(matches.len().saturating_sub(1));
+        search.matches = matches;
+        if std::mem::take(&mut search.searching) {
+            self.focus_color_search_match();
+        }
+    }
+
+    /// Move to the next (or previous) match and center the viewport on it
+    pub fn cycle_color_search(&mut self, forward: bool) {
+        let Some(search) = &mut self.color_search else {
+            self.status_message = "No color search active. Press '/' to search.".to_string();
+            return;
+        };
+        if search.searching {
+            self.status_message = "🔍 Still searching the board...".to_string();
+            return;
+        }
+        if search.matches.is_empty() {
+            self.focus_color_search_match();
+            return;
+        }
+
+        let count = search.matches.len();
+        search.current = if forward {
+            (search.current + 1) % count
+        } else {
+            (search.current + count - 1) % count
+        };
+        self.focus_color_search_match();
+    }
+
+    /// Stop highlighting search matches
+    pub fn clear_color_search(&mut self) {
+        self.color_search = None;
+        self.status_message = "Color search cleared.".to_string();
+    }
+
+    /// Board pixel at the middle of the board view (rows show two pixels each)
+    fn viewport_center(&self) -> (i32, i32) {
+        let (width, height) = self.board_view_size();
+        (
+            self.board_viewport_x as i32 + width / 2,
+            self.board_viewport_y as i32 + height,
+        )
+    }
+
+    fn focus_color_search_match(&mut self) {
+        let Some(search) = &self.color_search else {
+            return;
+        };
+        let (label, template_size) = match &search.target {
+            SearchTarget::Color(color_id) => {
+                (format!("{} cells", self.color_label(*color_id)), None)
+            }
+            SearchTarget::Template {
+                name,
+                pixels,
+                min_percent,
+            } => {
+                let width = pixels.iter().map(|p| p.x).max().unwrap_or(0) + 1;
+                let height = pixels.iter().map(|p| p.y).max().unwrap_or(0) + 1;
+                let label = if *min_percent < 100 {
+                    format!("'{}' matches (≥{}%)", name, min_percent)
+                } else {
+                    format!("'{}' matches", name)
+                };
+                (label, Some((width, height)))
+            }
+        };
+        let Some(&(x, y)) = search.matches.get(search.current) else {
+            self.status_message = format!("🔍 No {} on the board.", label);
+            return;
+        };
+        let (current, count) = (search.current, search.matches.len());
+
+        // Center on the middle of a template match, not its corner
+        self.center_viewport_on(x, y, template_size.unwrap_or((1, 1)));
+
+        self.status_message = format!(
+            "🔍 {} {} - match {}/{} at ({}, {}). n/N: next/previous, Esc: clear",
+            count,
+            label,
+            current + 1,
+            count,
+            x,
+            y
+        );
+    }
+}
+
+/// Every cell (or template top-left corner) matching `target`, nearest to `origin` first
+fn find_search_matches(
+    board: &[Vec<Option<PixelNetwork>>],
+    target: &SearchTarget,
+    origin: (i32, i32),
+) -> Vec<(i32, i32)> {
+    let board_width = board.len() as i32;
+    let board_height = board.first().map_or(0, |column| column.len()) as i32;
+    let cell_color = |x: i32, y: i32| {
+        board
+            .get(x as usize)
+            .and_then(|column| column.get(y as usize))
+            .and_then(|pixel| pixel.as_ref())
+            .map(|p| p.c)
+    };
+
+    let mut matches = Vec::new();
+    match target {
+        SearchTarget::Color(color_id) => {
+            for x in 0..board_width {
+                for y in 0..board_height {
+                    if cell_color(x, y) == *color_id {
+                        matches.push((x, y));
+                    }
+                }
+            }
+        }
+        SearchTarget::Template {
+            pixels,
+            min_percent,
+            ..
+        } => {
+            // Sliding window: stop checking a position once too many pixels differ
+            let max_x = pixels.iter().map(|p| p.x).max().unwrap_or(0);
+            let max_y = pixels.iter().map(|p| p.y).max().unwrap_or(0);
+            let needed = (pixels.len() * *min_percent as usize).div_ceil(100);
+            let allowed_misses = pixels.len() - needed;
+            for x in 0..board_width - max_x {
+                for y in 0..board_height - max_y {
+                    let mut misses = 0;
+                    for pixel in pixels {
+                        if cell_color(x + pixel.x, y + pixel.y) != Some(pixel.color) {
+                            misses += 1;
+                            if misses > allowed_misses {
+                                break;
+                            }
+                        }
+                    }
+                    if misses <= allowed_misses {
+                        matches.push((x, y));
+                    }
+                }
+            }
+        }
+    }
+
+    // Nearest first, so 'n' walks outwards from where the search started
+    let (origin_x, origin_y) = origin;
+    matches
+        .sort_by_key(|&(x, y)| ((x - origin_x) as i64).pow(2) + ((y - origin_y) as i64).pow(2));
+    matches
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::api_client::PixelNetwork;
+    use crate::app_state::App;
+    use crate::art::{ArtPixel, PixelArt};
+
+    #[tokio::test]
+    async fn template_matches_are_reported_at_the_pixels_top_left() {
+        let mut app = App::for_tests();
+        let cell = |c| Some(PixelNetwork { c, u: String::new(), t: 0 });
+        app.board = vec![vec![None; 4]; 4];
+        app.board[1][1] = cell(5);
+        app.board[2][1] = cell(6);
+        // Pixels start at (3, 2) relative to the art's origin
+        app.loaded_art = Some(PixelArt {
+            name: "offset".to_string(),
+            pattern: vec![
+                ArtPixel { x: 3, y: 2, color: 5 },
+                ArtPixel { x: 4, y: 2, color: 6 },
+            ],
+            ..Default::default()
+        });
+
+        app.apply_color_search("art");
+        let matches = app.color_search_receiver.as_mut().unwrap().recv().await.unwrap();
+        app.handle_color_search_result(matches);
+
+        let search = app.color_search.as_ref().unwrap();
+        assert!(!search.searching);
+        assert_eq!(search.matches, [(1, 1)]);
+    }
+}
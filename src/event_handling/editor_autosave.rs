@@ -0,0 +1,84 @@
+use crate::app_state::App;
+use crate::art::PixelArt;
+use std::time::{Duration, Instant};
+
+/// Minimum time between two autosave writes while drawing
+const AUTOSAVE_INTERVAL: Duration = Duration::from_secs(3);
+
+impl App {
+    /// Note an editor change; it reaches `editor_autosave.json` on the next throttled flush
+    pub fn mark_editor_edited(&mut self) {
+        self.editor_dirty = true;
+        self.editor_autosave_due = true;
+        self.flush_editor_autosave(false);
+    }
+
+    /// Write pending editor changes to the autosave, at most once per interval unless forced
+    pub fn flush_editor_autosave(&mut self, force: bool) {
+        if !self.editor_autosave_due {
+            return;
+        }
+        if !force
+            && self
+                .editor_last_autosave
+                .is_some_and(|last| last.elapsed() < AUTOSAVE_INTERVAL)
+        {
+            return;
+        }
+        let Some(art) = &self.current_editing_art else {
+            return;
+        };
+
+        let result = serde_json::to_string_pretty(art)
+            .map_err(|e| e.to_string())
+            .and_then(|data| std::fs::write(autosave_path(), data).map_err(|e| e.to_string()));
+        self.editor_autosave_due = false;
+        self.editor_last_autosave = Some(Instant::now());
+        if let Err(e) = result {
+            self.add_status_message(format!("❌ Could not autosave the editor art: {}", e));
+        }
+    }
+
+    /// Name of the art waiting in the autosave, if any
+    pub fn editor_autosave_name(&self) -> Option<String> {
+        load_autosave().map(|art| art.name)
+    }
+
+    /// Replace the art in the editor with the autosaved one, after writing out any
+    /// throttled edits so the restored copy is never older than what's on screen
+    pub fn restore_editor_autosave(&mut self) {
+        self.flush_editor_autosave(true);
+        let Some(art) = load_autosave() else {
+            self.status_message = "No autosaved art to restore.".to_string();
+            return;
+        };
+
+        self.status_message = format!(
+            "Restored autosaved art '{}' ({} pixels). Press 's' to save it.",
+            art.name,
+            art.pattern.len()
+        );
+        self.current_editing_art = Some(art);
+        self.art_editor_cursor_x = 0;
+        self.art_editor_cursor_y = 0;
+        self.editor_dirty = true;
+        self.editor_autosave_due = false;
+    }
+
+    /// Drop the autosave once its work has been saved or deliberately discarded
+    pub fn discard_editor_autosave(&mut self) {
+        self.editor_autosave_due = false;
+        let _ = std::fs::remove_file(autosave_path());
+    }
+}
+
+/// Kept beside the other app files rather than among the arts: every `.json` in
+/// `patterns/` is listed as a saved art
+fn autosave_path() -> std::path::PathBuf {
+    crate::paths::data_path("editor_autosave.json")
+}
+
+fn load_autosave() -> Option<PixelArt> {
+    let data = std::fs::read_to_string(autosave_path()).ok()?;
+    serde_json::from_str(&data).ok()
+}
@@ -1,8 +1,8 @@
-use crate::app_state::App;
+use crate::app_state::{App, InputMode, Preferences, EDITOR_CANVAS_SIZE};
 use crate::art::PixelArt;
+use crate::palette_names::PaletteNames;
 use std::fs::File;
 use std::io::Write;
-use std::path::Path;
 
 impl App {
     /// Save current art in editor to file
@@ -31,14 +31,15 @@ impl App {
                 author: art.author.clone(),
                 created_at: art.created_at.clone(),
                 tags: art.tags.clone(),
+                default_palette: art.default_palette,
             };
             match serde_json::to_string_pretty(&art_with_name) {
                 Ok(json_data) => {
-                    let dir_path = Path::new("patterns");
+                    let dir_path = crate::paths::data_path("patterns");
                     if !dir_path.exists() {
-                        if let Err(e) = std::fs::create_dir_all(dir_path) {
+                        if let Err(e) = std::fs::create_dir_all(&dir_path) {
                             self.status_message =
-                                format!("Error creating directory patterns: {}", e);
+                                format!("Error creating directory {}: {}", dir_path.display(), e);
                             return;
                         }
                     }
@@ -58,6 +59,8 @@ impl App {
                                     art_with_name.name,
                                     file_path.display()
                                 );
+                                self.editor_dirty = false;
+                                self.discard_editor_autosave();
                             }
                         }
                         Err(e) => {
@@ -75,8 +78,109 @@ impl App {
         }
     }
 
+    /// Open a saved art in the editor, with the canvas sized to fit it
+    pub fn open_art_in_editor(&mut self, mut art: PixelArt) {
+        // The editor draws from (0, 0), so shift the pattern to its top-left corner
+        let min_x = art.pattern.iter().map(|p| p.x).min().unwrap_or(0);
+        let min_y = art.pattern.iter().map(|p| p.y).min().unwrap_or(0);
+        for pixel in &mut art.pattern {
+            pixel.x -= min_x;
+            pixel.y -= min_y;
+        }
+        art.board_x = 0;
+        art.board_y = 0;
+
+        let (width, height) = crate::art::get_art_dimensions(&art);
+        self.art_editor_canvas_width = (width as u16).max(EDITOR_CANVAS_SIZE.0);
+        self.art_editor_canvas_height = (height as u16).max(EDITOR_CANVAS_SIZE.1);
+
+        if self.colors.is_empty() {
+            self.colors = crate::art::default_palette();
+        }
+        self.art_editor_selected_color_id = art
+            .pattern
+            .first()
+            .map_or(self.art_editor_selected_color_id, |p| p.color);
+        self.art_editor_color_palette_index = self
+            .colors
+            .iter()
+            .position(|c| c.id == self.art_editor_selected_color_id)
+            .unwrap_or(0);
+
+        self.status_message = format!(
+            "Editing '{}' ({}x{}, {} pixels). Arrows to move, Space to draw, Tab to change colors, s to save over it.",
+            art.name,
+            width,
+            height,
+            art.pattern.len()
+        );
+        self.current_editing_art = Some(art);
+        self.art_editor_cursor_x = 0;
+        self.art_editor_cursor_y = 0;
+        self.editor_dirty = false;
+        self.editing_saved_art = true;
+        self.input_mode = InputMode::ArtEditor;
+    }
+
+    /// Copy the selected art to `<name>_copy` as a starting point for a variation
+    pub fn duplicate_selected_art(&mut self) {
+        let Some(mut copy) = self
+            .available_pixel_arts
+            .get(self.art_selection_index)
+            .cloned()
+        else {
+            return;
+        };
+
+        // Never clobber an existing art: _copy, then _copy2, _copy3...
+        let base_name = format!("{}_copy", copy.name);
+        let name = (1..)
+            .map(|n| match n {
+                1 => base_name.clone(),
+                n => format!("{}{}", base_name, n),
+            })
+            .find(|name| {
+                !crate::paths::data_path("patterns")
+                    .join(format!("{}.json", name))
+                    .exists()
+            })
+            .unwrap();
+        copy.name = name.clone();
+        copy.created_at = Some(chrono::Utc::now().to_rfc3339());
+        copy.board_x = 0;
+        copy.board_y = 0;
+
+        match crate::art::save_pixel_art(&copy, &name) {
+            Ok(_) => {
+                self.refresh_available_pixel_arts();
+                if let Some(index) = self
+                    .available_pixel_arts
+                    .iter()
+                    .position(|art| art.name == name)
+                {
+                    self.art_selection_index = index;
+                }
+                self.status_message =
+                    format!("Duplicated as '{}'. Press 'e' to edit the copy.", name);
+            }
+            Err(e) => {
+                self.status_message = format!("Could not duplicate the art: {}", e);
+            }
+        }
+    }
+
+    /// Whether the active session's tokens belong in the token file: the second
+    /// session is kept in memory so it never replaces the first one's tokens, and
+    /// credentials from environment variables must not end up on disk
+    pub fn persists_tokens(&self) -> bool {
+        !self.secondary_active && !self.env_credentials
+    }
+
     /// Save current tokens and base URL to persistent storage
     pub fn save_tokens(&mut self) {
+        if !self.persists_tokens() {
+            return;
+        }
         let token_data = crate::token_storage::TokenData {
             access_token: self.api_client.get_access_token_clone(),
             refresh_token: self.api_client.get_refresh_token_clone(),
@@ -88,10 +192,271 @@ impl App {
         }
     }
 
+    /// Use the cached palette for the current base URL until a board has been fetched,
+    /// along with any color names imported for it
+    pub fn load_cached_palette(&mut self) {
+        self.load_palette_names();
+        if !self.board.is_empty() {
+            return;
+        }
+        if let Some(colors) = self
+            .token_storage
+            .load_palette(&self.api_client.get_base_url())
+        {
+            self.colors = colors;
+        }
+    }
+
+    /// Whether the colors are the built-in offline palette rather than a server's
+    pub fn using_default_palette(&self) -> bool {
+        !self.colors.is_empty() && self.colors == crate::art::default_palette()
+    }
+
+    /// Palette that arts drawn offline get remapped to on load; empty until a server's is known
+    pub fn art_palette(&self) -> &[crate::api_client::ColorInfo] {
+        if self.using_default_palette() {
+            &[]
+        } else {
+            &self.colors
+        }
+    }
+
+    /// Pick up the color names imported for the current base URL
+    pub fn load_palette_names(&mut self) {
+        self.palette_names = self
+            .token_storage
+            .load_palette_names(&self.api_client.get_base_url())
+            .unwrap_or_default();
+    }
+
+    /// Read color names from a palette file for the current base URL; "none" forgets them
+    pub fn import_palette_names(&mut self, path: &str) {
+        self.input_mode = InputMode::None;
+        let base_url = self.api_client.get_base_url();
+
+        if path.eq_ignore_ascii_case("none") {
+            self.palette_names = PaletteNames::default();
+            let _ = self.token_storage.save_palette_names(&base_url, None);
+            self.status_message = "Imported color names removed.".to_string();
+            return;
+        }
+
+        let names = match std::fs::read_to_string(path) {
+            Ok(text) => PaletteNames::parse(&text),
+            Err(e) => {
+                self.status_message = format!("❌ Could not read palette file {}: {}", path, e);
+                return;
+            }
+        };
+        if names.entry_count() == 0 {
+            self.status_message = format!(
+                "No colors found in {} (expected 'R G B Name' or 'ID Name' lines).",
+                path
+            );
+            return;
+        }
+
+        if let Err(e) = self
+            .token_storage
+            .save_palette_names(&base_url, Some(&names))
+        {
+            self.add_status_message(format!("❌ Could not save color names: {}", e));
+        }
+        self.palette_names = names;
+        let named = self
+            .colors
+            .iter()
+            .filter(|color| self.palette_names.name_for(color).is_some())
+            .count();
+        self.status_message = format!(
+            "🎨 Imported {} color names; {} of {} palette colors renamed.",
+            self.palette_names.entry_count(),
+            named,
+            self.colors.len()
+        );
+    }
+
+    /// Cache the current palette so the editor works before the next board load
+    pub fn save_cached_palette(&mut self) {
+        if self.colors.is_empty() {
+            return;
+        }
+        if let Err(e) = self
+            .token_storage
+            .save_palette(&self.api_client.get_base_url(), &self.colors)
+        {
+            self.add_status_message(format!("⚠️ Could not save palette cache: {}", e));
+        }
+    }
+
+    /// Scroll back to the viewport last used on this instance, kept inside the board
+    pub fn restore_saved_viewport(&mut self) {
+        let Some((x, y)) = self
+            .token_storage
+            .load_viewport(&self.api_client.get_base_url())
+        else {
+            return;
+        };
+
+        let board_width = self.board.len();
+        let board_height = self.board.first().map_or(0, |column| column.len());
+        self.board_viewport_x = x.min(board_width.saturating_sub(1) as u16);
+        self.board_viewport_y = y.min(board_height.saturating_sub(1) as u16);
+    }
+
+    /// Remember the viewport for the current base URL so the next launch starts there
+    pub fn save_viewport(&mut self) {
+        if !self.initial_board_fetched {
+            return;
+        }
+        if let Err(e) = self.token_storage.save_viewport(
+            &self.api_client.get_base_url(),
+            (self.board_viewport_x, self.board_viewport_y),
+        ) {
+            self.add_status_message(format!("⚠️ Could not save viewport: {}", e));
+        }
+    }
+
+    /// Load the background color rules from `skip_rules.json`, keeping the built-in
+    /// ones when the file is missing or unreadable
+    pub fn load_skip_rules(&mut self) {
+        let rules_path = crate::paths::data_path("skip_rules.json");
+        let rules_data = match std::fs::read_to_string(&rules_path) {
+            Ok(rules_data) => rules_data,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => return,
+            Err(e) => {
+                self.add_status_message(format!(
+                    "⚠️ Could not read {}: {} - using the built-in background colors",
+                    rules_path.display(),
+                    e
+                ));
+                return;
+            }
+        };
+        match serde_json::from_str(&rules_data) {
+            Ok(rules) => self.skip_rules = rules,
+            Err(e) => self.add_status_message(format!(
+                "⚠️ Ignoring {}: {} - using the built-in background colors",
+                rules_path.display(),
+                e
+            )),
+        }
+    }
+
+    /// Load UI preferences; on first run, use ascii status tags on terminals without UTF-8
+    pub fn load_preferences(&mut self) {
+        let preferences = std::fs::read_to_string(crate::paths::data_path("preferences.json"))
+            .ok()
+            .and_then(|data| serde_json::from_str::<Preferences>(&data).ok())
+            .unwrap_or_else(|| Preferences {
+                ascii_status: !terminal_supports_unicode(),
+                ..Default::default()
+            });
+        self.ascii_status = preferences.ascii_status;
+        self.placement_bell = preferences.placement_bell;
+        self.cooldown_bell = preferences.cooldown_bell;
+        self.empty_cells_only = preferences.empty_cells_only;
+    }
+
+    /// Save UI preferences to `preferences.json` in the data directory
+    pub fn save_preferences(&self) -> Result<(), Box<dyn std::error::Error>> {
+        let preferences = Preferences {
+            ascii_status: self.ascii_status,
+            placement_bell: self.placement_bell,
+            cooldown_bell: self.cooldown_bell,
+            empty_cells_only: self.empty_cells_only,
+        };
+        std::fs::write(
+            crate::paths::data_path("preferences.json"),
+            serde_json::to_string_pretty(&preferences)?,
+        )?;
+        Ok(())
+    }
+
+    /// Switch between emoji and plain-tag status prefixes, remembering the choice
+    pub fn toggle_ascii_status(&mut self) {
+        self.ascii_status = !self.ascii_status;
+        self.status_message = format!(
+            "Status prefixes: {}",
+            if self.ascii_status {
+                "ascii tags"
+            } else {
+                "emoji"
+            }
+        );
+        if let Err(e) = self.save_preferences() {
+            self.add_status_message(format!("❌ Could not save preferences: {}", e));
+        }
+    }
+
+    /// Turn the per-pixel bell of single-art placement on or off, remembering the choice
+    pub fn toggle_placement_bell(&mut self) {
+        self.placement_bell = !self.placement_bell;
+        self.status_message = format!(
+            "Bell on each placed pixel: {}",
+            if self.placement_bell { "on" } else { "off" }
+        );
+        if let Err(e) = self.save_preferences() {
+            self.add_status_message(format!("❌ Could not save preferences: {}", e));
+        }
+    }
+
+    /// Turn the cooldown-over bell on or off, remembering the choice
+    pub fn toggle_cooldown_bell(&mut self) {
+        self.cooldown_bell = !self.cooldown_bell;
+        self.status_message = format!(
+            "Bell when the cooldown ends: {}",
+            if self.cooldown_bell { "on" } else { "off" }
+        );
+        if let Err(e) = self.save_preferences() {
+            self.add_status_message(format!("❌ Could not save preferences: {}", e));
+        }
+    }
+
+    /// Turn peaceful placement on or off: only empty or default-color cells get painted
+    pub fn toggle_empty_cells_only(&mut self) {
+        self.empty_cells_only = !self.empty_cells_only;
+        self.status_message = if self.empty_cells_only {
+            let default_color =
+                match crate::event_handling::queue_management::detect_default_color_id(&self.board)
+                {
+                    Some(id) => self.color_label(Some(id)),
+                    None if self.board.is_empty() => "unknown until the board loads".to_string(),
+                    None => "none, only unpainted cells are free".to_string(),
+                };
+            format!(
+                "🕊️ Empty cells only: on - the queue skips cells other players painted (default color: {})",
+                default_color
+            )
+        } else {
+            "Empty cells only: off - the queue paints over any cell".to_string()
+        };
+        if let Err(e) = self.save_preferences() {
+            self.add_status_message(format!("❌ Could not save preferences: {}", e));
+        }
+    }
+
     /// Clear saved tokens from persistent storage
     pub fn clear_saved_tokens(&mut self) {
+        if !self.persists_tokens() {
+            return;
+        }
         if let Err(e) = self.token_storage.clear() {
             eprintln!("Warning: Could not clear saved tokens: {}", e);
         }
     }
 }
+
+/// Best guess from the environment: the Linux console and non-UTF-8 locales can't show emoji
+fn terminal_supports_unicode() -> bool {
+    if std::env::var("TERM").is_ok_and(|term| term == "linux") {
+        return false;
+    }
+    let locale = ["LC_ALL", "LC_CTYPE", "LANG"]
+        .iter()
+        .find_map(|name| std::env::var(name).ok().filter(|value| !value.is_empty()));
+    locale.is_none_or(|locale| {
+        let locale = locale.to_lowercase();
+        locale.contains("utf-8") || locale.contains("utf8")
+    })
+}
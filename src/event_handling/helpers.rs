@@ -1,8 +1,19 @@
-use crate::api_client::UserInfos;
+use crate::api_client::{ColorInfo, UserInfos};
 use crate::app_state::App;
 use std::time::{Duration, Instant};
 
 impl App {
+    /// Name shown for a palette color: imported name, then the server's, then "Color N"
+    pub fn color_display_name(&self, color: &ColorInfo) -> String {
+        if let Some(name) = self.palette_names.name_for(color) {
+            name.to_string()
+        } else if color.name.trim().is_empty() {
+            format!("Color {}", color.id)
+        } else {
+            color.name.clone()
+        }
+    }
+
     /// Enhanced error message formatting that utilizes timers and interval from ErrorResponse
     pub fn format_enhanced_error_message(
         &self,
@@ -60,15 +71,17 @@ impl App {
             crate::api_client::ApiError::ErrorResponse {
                 status,
                 error_response,
+            }
+            | crate::api_client::ApiError::RateLimited {
+                status,
+                error_response,
+                ..
             } => {
                 let enhanced_message =
                     self.format_enhanced_error_message(base_message, status, error_response);
 
                 // For rate limiting errors, update user info with new timers if available
-                if *status == reqwest::StatusCode::TOO_MANY_REQUESTS
-                    || status.as_u16() == 425
-                    || status.as_u16() == 420
-                {
+                if error.is_rate_limited() {
                     if let Some(timers) = &error_response.timers {
                         if let Some(ref mut info) = self.user_info {
                             info.timers = Some(timers.clone());
@@ -94,19 +107,93 @@ impl App {
 
                 self.status_message = enhanced_message;
             }
-            crate::api_client::ApiError::Unauthorized => {
-                self.status_message = format!(
-                    "{}: Unauthorized access. Please check your tokens.",
-                    base_message
-                );
+            crate::api_client::ApiError::Unauthorized { login_page } => {
+                self.status_message = if *login_page {
+                    format!(
+                        "{}: {}. Please check your tokens.",
+                        base_message,
+                        crate::api_client::LOGIN_PAGE_HINT
+                    )
+                } else {
+                    format!(
+                        "{}: Unauthorized access. Please check your tokens.",
+                        base_message
+                    )
+                };
                 self.api_client.clear_tokens();
             }
             _ => {
-                self.status_message = format!("{}: {:?}", base_message, error);
+                self.status_message = format!("{}: {}", base_message, error.summary());
             }
         }
     }
 
+    /// Map a board-area screen row to the board rows it displays.
+    ///
+    /// Each terminal cell shows two board rows with '▀' (top = fg, bottom = bg).
+    /// Terminals only report mouse positions per cell, so a click can't tell the
+    /// halves apart and always resolves to the top row. On boards with an odd
+    /// height the last cell row has no bottom half, which is returned as `None`.
+    pub fn board_rows_for_screen_cell(&self, screen_cell_y: u16) -> (i32, Option<i32>) {
+        let board_height = self.board.first().map_or(0, |column| column.len()) as i32;
+        let top_row = self.board_viewport_y as i32 + screen_cell_y as i32 * 2;
+        let bottom_row = top_row + 1;
+
+        (top_row, (bottom_row < board_height).then_some(bottom_row))
+    }
+
+    /// Board pixel shown at an absolute screen position, or None outside the drawn board.
+    ///
+    /// Uses the board area bounds from the last frame, so it agrees with what is on
+    /// screen. The row is the top one of the cell (see `board_rows_for_screen_cell`).
+    pub fn screen_to_board(&self, column: u16, row: u16) -> Option<(i32, i32)> {
+        let (area_x, area_y, width, height) = self.board_area_bounds?;
+        if column < area_x || row < area_y || column >= area_x + width || row >= area_y + height {
+            return None;
+        }
+
+        let (top_row, _) = self.board_rows_for_screen_cell(row - area_y);
+        Some((
+            self.board_viewport_x as i32 + (column - area_x) as i32,
+            top_row,
+        ))
+    }
+
+    /// Absolute screen cell showing a board pixel, or None while it is scrolled out of view.
+    ///
+    /// Both rows of a pair share the cell: even `y - board_viewport_y` is the top half.
+    pub fn board_to_screen(&self, x: i32, y: i32) -> Option<(u16, u16)> {
+        let (area_x, area_y, width, height) = self.board_area_bounds?;
+        let cell_x = x - self.board_viewport_x as i32;
+        let cell_y = (y - self.board_viewport_y as i32).div_euclid(2);
+        if cell_x < 0 || cell_y < 0 || cell_x >= width as i32 || cell_y >= height as i32 {
+            return None;
+        }
+
+        Some((area_x + cell_x as u16, area_y + cell_y as u16))
+    }
+
+    /// Board view size in terminal cells: the area drawn last frame, or an estimate from the
+    /// last terminal size while no board is drawn (e.g. behind the share list)
+    pub fn board_view_size(&self) -> (i32, i32) {
+        if let Some((_, _, width, height)) = self.board_area_bounds {
+            return (width as i32, height as i32);
+        }
+        // Standard layout: 5 header rows, 6 status rows and the board's border
+        let (columns, rows) = self.last_terminal_size.unwrap_or((80, 24));
+        ((columns as i32 - 2).max(1), (rows as i32 - 13).max(1))
+    }
+
+    /// Scroll so an area of `art_dims` at (board_x, board_y) sits in the middle of the board view
+    pub fn center_viewport_on(&mut self, board_x: i32, board_y: i32, art_dims: (i32, i32)) {
+        let (view_width, view_height) = self.board_view_size();
+        let center_x = board_x + art_dims.0 / 2;
+        let center_y = board_y + art_dims.1 / 2;
+        // Viewport y counts pixels while each terminal row shows two
+        self.board_viewport_x = (center_x - view_width / 2).max(0) as u16;
+        self.board_viewport_y = (center_y - view_height).max(0) as u16;
+    }
+
     /// Update blink state for queue preview effects
     pub fn update_blink_state(&mut self) {
         let now = Instant::now();
@@ -130,8 +217,8 @@ impl App {
         self.status_messages
             .push_back((message.clone(), Instant::now(), timestamp_utc2));
 
-        // Keep only last 100 messages (increased from 5)
-        while self.status_messages.len() > 100 {
+        // Drop the oldest entries once the configured cap is reached
+        while self.status_messages.len() > self.status_log_capacity {
             self.status_messages.pop_front();
         }
 
@@ -227,6 +314,30 @@ impl App {
         }
     }
 
+    /// Ring once when a pixel becomes available again after a cooldown
+    pub fn check_cooldown_bell(&mut self) {
+        let Some(user_info) = &self.user_info else {
+            return;
+        };
+        let Some(timers) = &user_info.timers else {
+            return; // No timer data: can't tell when the cooldown ends
+        };
+
+        // Same rule as update_cooldown_status: spare buffer, or every timer expired
+        let now_ms = chrono::Utc::now().timestamp_millis();
+        let ready = user_info.pixel_buffer - timers.len() as i32 > 0
+            || timers.iter().all(|&timer_ms| timer_ms <= now_ms);
+        if !ready {
+            self.cooldown_bell_armed = true;
+        } else if self.cooldown_bell_armed {
+            self.cooldown_bell_armed = false;
+            if self.cooldown_bell {
+                ring_bell();
+                self.add_status_message("🔔 Cooldown over - you can place a pixel.".to_string());
+            }
+        }
+    }
+
     /// Get formatted timer status for display in headers
     pub fn get_formatted_timer_status(&self) -> String {
         if let Some(user_info) = &self.user_info {
@@ -369,7 +480,7 @@ impl App {
     pub fn cleanup_old_status_messages(&mut self) {
         let cutoff = Instant::now() - Duration::from_secs(600); // 10 minutes instead of 30 seconds
         while let Some((_, timestamp, _)) = self.status_messages.front() {
-            if *timestamp < cutoff {
+            if *timestamp < cutoff || self.status_messages.len() > self.status_log_capacity {
                 self.status_messages.pop_front();
             } else {
                 break;
@@ -436,9 +547,15 @@ impl App {
             timestamp_utc: String, // Store as UTC+2 formatted string
         }
 
+        // Only the most recent entries are persisted so the file can't grow across sessions
+        let skip = self
+            .status_messages
+            .len()
+            .saturating_sub(STATUS_LOG_PERSIST_LIMIT);
         let persistent_messages: Vec<PersistentStatusMessage> = self
             .status_messages
             .iter()
+            .skip(skip)
             .map(
                 |(message, _instant, utc2_timestamp)| PersistentStatusMessage {
                     message: message.clone(),
@@ -448,10 +565,13 @@ impl App {
             .collect();
 
         // Create logs directory if it doesn't exist
-        std::fs::create_dir_all("logs")?;
+        std::fs::create_dir_all(crate::paths::data_path("logs"))?;
 
         let json_data = serde_json::to_string_pretty(&persistent_messages)?;
-        std::fs::write("logs/status_messages.json", json_data)?;
+        std::fs::write(
+            crate::paths::data_path("logs/status_messages.json"),
+            json_data,
+        )?;
         Ok(())
     }
 
@@ -465,17 +585,21 @@ impl App {
             timestamp_utc: String,
         }
 
-        if !std::path::Path::new("logs/status_messages.json").exists() {
+        let log_path = crate::paths::data_path("logs/status_messages.json");
+        if !log_path.exists() {
             return Ok(());
         }
 
-        let json_data = std::fs::read_to_string("logs/status_messages.json")?;
+        let json_data = std::fs::read_to_string(&log_path)?;
         let persistent_messages: Vec<PersistentStatusMessage> = serde_json::from_str(&json_data)?;
 
         // Convert back to runtime format with current Instant (for cleanup purposes)
         // We'll use the stored UTC+2 timestamp for display
         let now = Instant::now();
-        for persistent_msg in persistent_messages {
+        let skip = persistent_messages
+            .len()
+            .saturating_sub(STATUS_LOG_PERSIST_LIMIT.min(self.status_log_capacity));
+        for persistent_msg in persistent_messages.into_iter().skip(skip) {
             self.status_messages.push_back((
                 persistent_msg.message,
                 now,
@@ -486,3 +610,166 @@ impl App {
         Ok(())
     }
 }
+
+/// Default cap on in-memory status log entries (override with FTPLACE_STATUS_LOG_CAPACITY)
+pub const DEFAULT_STATUS_LOG_CAPACITY: usize = 5000;
+
+/// Status log entries written to logs/status_messages.json on exit
+const STATUS_LOG_PERSIST_LIMIT: usize = 500;
+
+/// Gzip streams start with these two bytes
+const GZIP_MAGIC: [u8; 2] = [0x1f, 0x8b];
+
+/// Read a JSON file that may have been written gzip-compressed (detected by its header)
+pub fn read_json_file(path: &std::path::Path) -> Result<String, Box<dyn std::error::Error>> {
+    use std::io::Read;
+
+    let bytes = std::fs::read(path)?;
+    if bytes.starts_with(&GZIP_MAGIC) {
+        let mut json = String::new();
+        flate2::read::GzDecoder::new(bytes.as_slice()).read_to_string(&mut json)?;
+        Ok(json)
+    } else {
+        Ok(String::from_utf8(bytes)?)
+    }
+}
+
+/// Write JSON to `path`, gzip-compressed when `compress` is set
+pub fn write_json_file(
+    path: &std::path::Path,
+    json: &str,
+    compress: bool,
+) -> Result<(), Box<dyn std::error::Error>> {
+    use std::io::Write;
+
+    if compress {
+        let mut encoder = flate2::write::GzEncoder::new(Vec::new(), flate2::Compression::default());
+        encoder.write_all(json.as_bytes())?;
+        std::fs::write(path, encoder.finish()?)?;
+    } else {
+        std::fs::write(path, json)?;
+    }
+    Ok(())
+}
+
+/// Rename an unreadable save file to `<stem>.corrupt.<timestamp>.json` next to it
+pub fn backup_corrupt_file(path: &std::path::Path) -> std::io::Result<std::path::PathBuf> {
+    let stem = path
+        .file_stem()
+        .map(|stem| stem.to_string_lossy().into_owned())
+        .unwrap_or_default();
+    let timestamp = chrono::Local::now().format("%Y%m%d_%H%M%S");
+    let backup_path = path.with_file_name(format!("{}.corrupt.{}.json", stem, timestamp));
+    std::fs::rename(path, &backup_path)?;
+    Ok(backup_path)
+}
+
+/// Pixels that can be placed right now without hitting a cooldown
+pub fn available_pixel_buffer(user_info: &UserInfos) -> usize {
+    let active_timers = user_info.timers.as_ref().map_or(0, |timers| timers.len());
+    (user_info.pixel_buffer.max(0) as usize).saturating_sub(active_timers)
+}
+
+/// Whether the last response still showed free pixels above the reserve
+pub fn has_spare_buffer(user_info: Option<&UserInfos>, reserve: usize) -> bool {
+    user_info.is_some_and(|info| available_pixel_buffer(info) > reserve)
+}
+
+/// Pause after a placed pixel, skipped while the buffer above the reserve isn't spent
+pub async fn pause_between_pixels(user_info: Option<&UserInfos>, reserve: usize) {
+    if has_spare_buffer(user_info, reserve) {
+        return;
+    }
+    tokio::time::sleep(Duration::from_millis(100)).await;
+}
+
+/// Sound the terminal bell; BEL passes through the alternate screen untouched
+pub fn ring_bell() {
+    use std::io::Write;
+    let mut stdout = std::io::stdout();
+    let _ = stdout.write_all(b"\x07");
+    let _ = stdout.flush();
+}
+
+/// Grid sizes cycled through with 'g' while positioning art (None = snapping off)
+pub const SNAP_GRID_SIZES: [Option<i32>; 5] = [None, Some(4), Some(8), Some(16), Some(32)];
+
+/// Round a board coordinate to the nearest multiple of the snap size
+pub fn snap_to_grid(value: i32, snap: Option<i32>) -> i32 {
+    match snap {
+        Some(size) if size > 1 => (value as f64 / size as f64).round() as i32 * size,
+        _ => value,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn clicks_before_the_first_render_hit_no_pixel() {
+        let app = App::for_tests();
+        assert_eq!(app.board_area_bounds, None);
+        assert_eq!(app.screen_to_board(10, 10), None);
+        assert_eq!(app.board_to_screen(0, 0), None);
+    }
+
+    #[test]
+    fn last_cell_row_of_an_odd_height_board_has_no_bottom_half() {
+        let mut app = App::for_tests();
+        app.board = vec![vec![None; 5]; 2];
+
+        assert_eq!(app.board_rows_for_screen_cell(0), (0, Some(1)));
+        assert_eq!(app.board_rows_for_screen_cell(1), (2, Some(3)));
+        assert_eq!(app.board_rows_for_screen_cell(2), (4, None));
+
+        app.board_viewport_y = 1;
+        assert_eq!(app.board_rows_for_screen_cell(1), (3, Some(4)));
+        assert_eq!(app.board_rows_for_screen_cell(2), (5, None));
+    }
+
+    /// A 4x7 board drawn in a 4x4 cell area at screen (2, 3)
+    fn app_with_drawn_board(viewport_y: u16) -> App {
+        let mut app = App::for_tests();
+        app.board = vec![vec![None; 7]; 4];
+        app.board_area_bounds = Some((2, 3, 4, 4));
+        app.board_viewport_y = viewport_y;
+        app
+    }
+
+    #[test]
+    fn screen_and_board_positions_round_trip_with_an_odd_viewport() {
+        let app = app_with_drawn_board(1);
+        for x in 0..4 {
+            for y in 1..7 {
+                let (column, row) = app.board_to_screen(x, y).unwrap();
+                // Clicks resolve to the top row of the cell
+                let top_row = y - (y - 1) % 2;
+                assert_eq!(app.screen_to_board(column, row), Some((x, top_row)));
+            }
+        }
+        assert_eq!(app.board_to_screen(1, 1), Some((3, 3)));
+        assert_eq!(app.board_to_screen(1, 2), Some((3, 3)));
+        // Row 0 is scrolled out above the view
+        assert_eq!(app.board_to_screen(0, 0), None);
+    }
+
+    #[test]
+    fn last_row_of_an_odd_height_board_round_trips() {
+        let app = app_with_drawn_board(0);
+        assert_eq!(app.board_to_screen(3, 6), Some((5, 6)));
+        assert_eq!(app.screen_to_board(5, 6), Some((3, 6)));
+        assert_eq!(app.board_rows_for_screen_cell(3), (6, None));
+    }
+
+    #[test]
+    fn clicks_outside_the_board_area_hit_no_pixel() {
+        let app = app_with_drawn_board(0);
+        assert_eq!(app.screen_to_board(1, 3), None); // left of the area
+        assert_eq!(app.screen_to_board(6, 3), None); // right of the area
+        assert_eq!(app.screen_to_board(2, 2), None); // above
+        assert_eq!(app.screen_to_board(2, 7), None); // below
+        assert_eq!(app.screen_to_board(2, 3), Some((0, 0)));
+        assert_eq!(app.board_to_screen(4, 0), None); // right of the view
+    }
+}
@@ -1,7 +1,8 @@
-use crate::app_state::{App, InputMode};
-use crate::art::{get_available_pixel_arts, ArtPixel, PixelArt};
+use crate::app_state::{App, InputMode, Interleave, PlacementOrder};
+use crate::art::{ArtPixel, PixelArt};
+use crate::event_handling::helpers::{snap_to_grid, SNAP_GRID_SIZES};
 use crossterm::event::{
-    self, Event, KeyCode, KeyEventKind, MouseButton, MouseEvent, MouseEventKind,
+    self, Event, KeyCode, KeyEventKind, KeyModifiers, MouseButton, MouseEvent, MouseEventKind,
 };
 use std::io;
 use std::time::Duration;
@@ -19,6 +20,7 @@ impl App {
 
         // Update cooldown status with current timer info
         self.update_cooldown_status();
+        self.check_cooldown_bell();
 
         // Update event timer status if waiting for event
         self.update_event_timer_status();
@@ -26,6 +28,15 @@ impl App {
         // Clean up old status messages
         self.cleanup_old_status_messages();
 
+        // Count already-correct pixels for a large art once it stops moving
+        self.update_positioning_overlap();
+
+        // Write throttled editor changes that are still waiting
+        self.flush_editor_autosave(false);
+
+        // Remember the art being positioned across restarts
+        self.sync_loaded_art_file();
+
         // Check for completed board fetches
         if let Some(receiver) = &mut self.board_fetch_receiver {
             if let Ok(result) = receiver.try_recv() {
@@ -54,6 +65,20 @@ impl App {
             }
         }
 
+        // Check for a finished connection test from the config screen
+        if let Some(receiver) = &mut self.connection_test_receiver {
+            if let Ok(result) = receiver.try_recv() {
+                self.handle_connection_test_result(result);
+            }
+        }
+
+        // Check for board search matches computed in the background
+        if let Some(receiver) = &mut self.color_search_receiver {
+            if let Ok(matches) = receiver.try_recv() {
+                self.handle_color_search_result(matches);
+            }
+        }
+
         // Check for validation updates
         if let Some(receiver) = &mut self.validation_receiver {
             if let Ok(update) = receiver.try_recv() {
@@ -61,6 +86,13 @@ impl App {
             }
         }
 
+        // Check for the background session's board refresh
+        if let Some(receiver) = &mut self.background_fetch_receiver {
+            if let Ok(result) = receiver.try_recv() {
+                self.handle_background_fetch_result(result);
+            }
+        }
+
         let mut should_refresh_board = false;
         if (self.input_mode == InputMode::None || self.input_mode == InputMode::ShowStatusLog)
             && self.initial_board_fetched
@@ -78,6 +110,7 @@ impl App {
         if should_refresh_board {
             self.trigger_board_fetch();
         }
+        self.refresh_background_session();
 
         // Check for user input first - only process board loading if no input is pending
         // Use shorter timeout when status log is open for more responsive updates
@@ -97,6 +130,12 @@ impl App {
                 Event::Key(key_event) => {
                     if key_event.kind == KeyEventKind::Press {
                         match key_event.code {
+                            KeyCode::Char('l')
+                                if key_event.modifiers.contains(KeyModifiers::CONTROL) =>
+                            {
+                                self.force_redraw = true;
+                                return Ok(());
+                            }
                             KeyCode::Char(c)
                                 if matches!(
                                     self.input_mode,
@@ -138,7 +177,13 @@ impl App {
             match event::read()? {
                 Event::Key(key_event) => {
                     if key_event.kind == KeyEventKind::Press {
-                        self.handle_key_input(key_event.code).await?;
+                        if key_event.code == KeyCode::Char('l')
+                            && key_event.modifiers.contains(KeyModifiers::CONTROL)
+                        {
+                            self.force_redraw = true;
+                        } else {
+                            self.handle_key_input(key_event.code).await?;
+                        }
                     }
                 }
                 Event::Mouse(mouse_event) => {
@@ -162,67 +207,92 @@ impl App {
 
         match mouse_event.kind {
             MouseEventKind::Down(MouseButton::Left) => {
-                if let Some((board_x, board_y, board_width, board_height)) = self.board_area_bounds
+                // Bounds are rewritten every frame; None means no board has been drawn yet
+                let Some((_, board_y, _, _)) = self.board_area_bounds else {
+                    self.status_message =
+                        "Board not displayed yet - wait for it to load before clicking."
+                            .to_string();
+                    return Ok(());
+                };
+
+                // Convert screen coordinates to board pixel coordinates (None outside the board)
+                if let Some((board_pixel_x, board_pixel_y)) =
+                    self.screen_to_board(mouse_event.column, mouse_event.row)
                 {
-                    let mouse_x = mouse_event.column;
-                    let mouse_y = mouse_event.row;
-
-                    // Check if click is within board area
-                    if mouse_x >= board_x
-                        && mouse_x < board_x + board_width
-                        && mouse_y >= board_y
-                        && mouse_y < board_y + board_height
-                    {
-                        // Convert screen coordinates to board pixel coordinates
-                        let screen_cell_x = mouse_x - board_x;
-                        let screen_cell_y = mouse_y - board_y;
-
-                        // Each screen cell represents 2 vertical pixels (due to half-block rendering)
-                        let board_pixel_x = self.board_viewport_x as i32 + screen_cell_x as i32;
-                        let board_pixel_y =
-                            self.board_viewport_y as i32 + (screen_cell_y as i32 * 2);
-
-                        if let Some(art) = &mut self.loaded_art {
-                            // Get art dimensions to center it under the mouse cursor
-                            let art_dimensions = crate::art::get_art_dimensions(art);
-                            let art_center_offset_x = art_dimensions.0 / 2;
-                            let art_center_offset_y = art_dimensions.1 / 2;
-
-                            // Position art so its center is under the mouse cursor
-                            let proposed_x = board_pixel_x - art_center_offset_x;
-                            let proposed_y = board_pixel_y - art_center_offset_y;
-
-                            // Get board dimensions for bounds checking
-                            let board_width = self.board.len() as i32;
-                            let board_height = if board_width > 0 {
-                                self.board[0].len() as i32
-                            } else {
-                                0
-                            };
-
-                            // Clamp art position to stay within board bounds
-                            art.board_x = proposed_x
-                                .max(0) // Don't go left of board
-                                .min(board_width - art_dimensions.0); // Don't go right of board
-                            art.board_y = proposed_y
-                                .max(0) // Don't go above board
-                                .min(board_height - art_dimensions.1); // Don't go below board
-
-                            self.status_message = format!(
-                                "Art '{}' positioned at ({}, {}) via mouse. Press Enter to place.",
-                                art.name, art.board_x, art.board_y
-                            );
+                    // Each screen cell represents 2 vertical pixels (due to half-block rendering);
+                    // clicks can only resolve the cell, so they always select its top pixel
+                    let (_, bottom_pixel_y) =
+                        self.board_rows_for_screen_cell(mouse_event.row - board_y);
+
+                    if self.fit_region_active {
+                        self.handle_fit_region_click(board_pixel_x, board_pixel_y);
+                    } else if self.capture_region_active {
+                        self.handle_capture_region_click(board_pixel_x, board_pixel_y);
+                    } else if let Some(art) = &mut self.loaded_art {
+                        // Get art dimensions to center it under the mouse cursor
+                        let art_dimensions = crate::art::get_art_dimensions(art);
+                        let art_center_offset_x = art_dimensions.0 / 2;
+                        let art_center_offset_y = art_dimensions.1 / 2;
+
+                        // Position art so its center is under the mouse cursor
+                        let proposed_x = board_pixel_x - art_center_offset_x;
+                        let proposed_y = board_pixel_y - art_center_offset_y;
+
+                        // Get board dimensions for bounds checking
+                        let board_width = self.board.len() as i32;
+                        let board_height = if board_width > 0 {
+                            self.board[0].len() as i32
                         } else {
-                            // No art loaded - show coordinates for reference
-                            self.status_message = format!(
-                                "Clicked at board position ({}, {}). Load art with 'l' to place here.",
-                                board_pixel_x, board_pixel_y
-                            );
-                        }
+                            0
+                        };
+
+                        // Snap to the grid if enabled
+                        let proposed_x = snap_to_grid(proposed_x, self.snap_grid_size);
+                        let proposed_y = snap_to_grid(proposed_y, self.snap_grid_size);
+
+                        // Art may hang off the edges, but keep at least one pixel on the board
+                        art.board_x = proposed_x.max(1 - art_dimensions.0).min(board_width - 1);
+                        art.board_y = proposed_y.max(1 - art_dimensions.1).min(board_height - 1);
+
+                        self.status_message = format!(
+                            "Art '{}' positioned at ({}, {}) via mouse{}{}. Press Enter to place {}.",
+                            art.name,
+                            art.board_x,
+                            art.board_y,
+                            self.snap_grid_size
+                                .map(|size| format!(" [snap {}]", size))
+                                .unwrap_or_default(),
+                            Self::off_board_note(&self.board, art),
+                            self.next_placement_order.label()
+                        );
+                        self.note_positioning_overlap();
+                    } else {
+                        // No art loaded - show coordinates for reference, including the
+                        // row sharing this cell (absent on the last row of odd-height boards)
+                        let rows = match bottom_pixel_y {
+                            Some(bottom_y) => {
+                                format!("cell covers rows {}-{}", board_pixel_y, bottom_y)
+                            }
+                            None => "last board row".to_string(),
+                        };
+                        self.board_cursor = Some((board_pixel_x, board_pixel_y));
+                        self.status_message = format!(
+                            "Clicked at board position ({}, {}) ({}). Load art with 'l' to place here, W to watch.",
+                            board_pixel_x, board_pixel_y, rows
+                        );
                     }
                 }
             }
+            MouseEventKind::ScrollUp | MouseEventKind::ScrollDown
+                if mouse_event.modifiers.contains(KeyModifiers::CONTROL) =>
+            {
+                // Ctrl+wheel is reserved for zooming around the cursor; the board
+                // renders at a fixed 1 pixel per half-cell, so there is nothing to zoom yet
+                self.status_message =
+                    "Zoom is not supported yet - use the wheel without Ctrl to scroll.".to_string();
+            }
             MouseEventKind::ScrollUp => {
+                self.stop_following_queue();
                 // Scroll up - move viewport up
                 self.board_viewport_y = self.board_viewport_y.saturating_sub(15);
                 self.status_message = format!(
@@ -231,6 +301,7 @@ impl App {
                 );
             }
             MouseEventKind::ScrollDown => {
+                self.stop_following_queue();
                 // Scroll down - move viewport down
                 self.board_viewport_y = self.board_viewport_y.saturating_add(15);
                 self.status_message = format!(
@@ -239,6 +310,7 @@ impl App {
                 );
             }
             MouseEventKind::ScrollLeft => {
+                self.stop_following_queue();
                 // Scroll left - move viewport right (natural scrolling)
                 self.board_viewport_x = self.board_viewport_x.saturating_add(15);
                 self.status_message = format!(
@@ -247,6 +319,7 @@ impl App {
                 );
             }
             MouseEventKind::ScrollRight => {
+                self.stop_following_queue();
                 // Scroll right - move viewport left (natural scrolling)
                 self.board_viewport_x = self.board_viewport_x.saturating_sub(15);
                 self.status_message = format!(
@@ -279,6 +352,15 @@ impl App {
             InputMode::ArtEditor => {
                 self.handle_art_editor_input(key_code).await?;
             }
+            InputMode::ArtEditorExitConfirm => {
+                self.handle_editor_exit_confirm_input(key_code).await;
+            }
+            InputMode::ArtOverwriteConfirm => {
+                self.handle_art_overwrite_confirm_input(key_code).await;
+            }
+            InputMode::ArtRestoreConfirm => {
+                self.handle_art_restore_confirm_input(key_code);
+            }
             InputMode::ShowHelp => {
                 self.handle_help_input(key_code);
             }
@@ -295,6 +377,34 @@ impl App {
             InputMode::EnterShareString => {
                 self.handle_share_string_input(key_code);
             }
+            InputMode::EnterColorSearch => {
+                self.handle_color_search_input(key_code);
+            }
+            InputMode::EnterSnapshotDiff => {
+                self.handle_snapshot_diff_input(key_code);
+            }
+            InputMode::EnterPaletteFile => {
+                self.handle_palette_file_input(key_code);
+            }
+            InputMode::LogoutConfirm => match key_code {
+                KeyCode::Char('y') | KeyCode::Char('Y') => self.logout(),
+                KeyCode::Char('n') | KeyCode::Char('N') | KeyCode::Esc => {
+                    self.input_mode = InputMode::None;
+                    self.status_message = "Logout cancelled.".to_string();
+                }
+                _ => {}
+            },
+            InputMode::ResumePositioning => {
+                self.handle_resume_positioning_input(key_code);
+            }
+            InputMode::QuitConfirm => match key_code {
+                KeyCode::Char('y') | KeyCode::Char('Y') => self.cancel_queue_and_quit(),
+                KeyCode::Char('n') | KeyCode::Char('N') | KeyCode::Esc => {
+                    self.input_mode = InputMode::None;
+                    self.status_message = "Quit cancelled; the queue keeps running.".to_string();
+                }
+                _ => {}
+            },
             InputMode::ShareSelection => {
                 self.handle_share_selection_input(key_code);
             }
@@ -313,6 +423,9 @@ impl App {
             InputMode::ArtDeleteConfirmation => {
                 self.handle_delete_confirmation_input(key_code);
             }
+            InputMode::ShowBoardInfo => {
+                self.handle_board_info_input(key_code);
+            }
         }
         Ok(())
     }
@@ -336,16 +449,19 @@ impl App {
                     self.status_message = "Enter Custom API Base URL:".to_string();
                     self.input_buffer.clear();
                 } else {
-                    self.api_client.set_base_url(selected_option.clone());
+                    let selected_option = selected_option.clone();
+                    self.save_viewport();
+                    self.api_client.set_base_url(selected_option);
                     self.input_mode = InputMode::EnterAccessToken;
                     self.status_message =
                         "Base URL set. Enter Access Token (or Enter to skip):".to_string();
                     self.input_buffer.clear();
                     // Save the base URL immediately
                     self.save_tokens();
+                    self.load_cached_palette();
                 }
             }
-            KeyCode::Char('q') => self.exit = true,
+            KeyCode::Char('q') => self.request_quit(),
             KeyCode::Esc => {
                 // Cancel configuration and return to main mode without changing existing config
                 self.input_mode = InputMode::None;
@@ -354,6 +470,7 @@ impl App {
                 );
                 self.input_buffer.clear();
             }
+            KeyCode::Tab => self.start_connection_test(),
             _ => {}
         }
         Ok(())
@@ -367,6 +484,7 @@ impl App {
                     self.add_status_message("Invalid URL. Must start with http:// or https://. Please re-enter Custom Base URL.".to_string());
                     self.input_buffer.clear();
                 } else {
+                    self.save_viewport();
                     self.api_client.set_base_url(url);
                     self.input_mode = InputMode::EnterAccessToken;
                     self.status_message =
@@ -374,6 +492,7 @@ impl App {
                     self.input_buffer.clear();
                     // Save the base URL immediately
                     self.save_tokens();
+                    self.load_cached_palette();
                 }
             }
             KeyCode::Esc => {
@@ -386,6 +505,7 @@ impl App {
             KeyCode::Backspace => {
                 self.input_buffer.pop();
             }
+            KeyCode::Tab => self.start_connection_test(),
             _ => {}
         }
         Ok(())
@@ -419,6 +539,7 @@ impl App {
             KeyCode::Backspace => {
                 self.input_buffer.pop();
             }
+            KeyCode::Tab => self.start_connection_test(),
             _ => {}
         }
         Ok(())
@@ -432,18 +553,22 @@ impl App {
                 if !refresh.is_empty() {
                     self.api_client.set_tokens(current_access, Some(refresh));
                     self.status_message =
-                        "Refresh Token set. Configuration complete. Fetching initial board..."
-                            .to_string();
+                        "Refresh Token set. Configuration complete. Fetching board...".to_string();
                 } else {
                     self.api_client.set_tokens(current_access, None);
                     self.status_message =
-                        "Refresh Token skipped. Configuration complete. Fetching initial board..."
+                        "Refresh Token skipped. Configuration complete. Fetching board..."
                             .to_string();
                 }
                 self.input_mode = InputMode::ShowHelp;
                 self.input_buffer.clear();
                 if !self.initial_board_fetched {
                     self.fetch_board_data().await;
+                } else if self.api_client.get_auth_cookie_preview().is_some() {
+                    // Mid-session reconfigure: the periodic refresh doesn't run while
+                    // the help screen is up, so fetch with the new tokens right away
+                    self.trigger_board_fetch();
+                    self.trigger_profile_fetch();
                 }
                 // Save tokens after setting refresh token (final step)
                 self.save_tokens();
@@ -458,6 +583,7 @@ impl App {
             KeyCode::Backspace => {
                 self.input_buffer.pop();
             }
+            KeyCode::Tab => self.start_connection_test(),
             _ => {}
         }
         Ok(())
@@ -474,12 +600,17 @@ impl App {
                 0
             };
 
+            // Move by one grid cell when snapping is enabled
+            let snap = self.snap_grid_size;
+            let step = snap.unwrap_or(1);
+
             match key_code {
                 KeyCode::Up => {
                     if let Some(art) = &mut self.loaded_art {
-                        let new_y = art.board_y.saturating_sub(1);
-                        // Only move if the art won't go above the board
-                        if new_y >= 0 {
+                        let art_dimensions = crate::art::get_art_dimensions(art);
+                        let new_y = snap_to_grid(art.board_y.saturating_sub(step), snap);
+                        // Art may hang off the top, but keep at least one row on the board
+                        if new_y + art_dimensions.1 > 0 {
                             art.board_y = new_y;
                             art_moved = true;
                         }
@@ -487,10 +618,9 @@ impl App {
                 }
                 KeyCode::Down => {
                     if let Some(art) = &mut self.loaded_art {
-                        let art_dimensions = crate::art::get_art_dimensions(art);
-                        let new_y = art.board_y.saturating_add(1);
-                        // Only move if the art won't go below the board
-                        if new_y + art_dimensions.1 <= board_height {
+                        let new_y = snap_to_grid(art.board_y.saturating_add(step), snap);
+                        // Art may hang off the bottom, but keep at least one row on the board
+                        if new_y < board_height {
                             art.board_y = new_y;
                             art_moved = true;
                         }
@@ -498,9 +628,10 @@ impl App {
                 }
                 KeyCode::Left => {
                     if let Some(art) = &mut self.loaded_art {
-                        let new_x = art.board_x.saturating_sub(1);
-                        // Only move if the art won't go left of the board
-                        if new_x >= 0 {
+                        let art_dimensions = crate::art::get_art_dimensions(art);
+                        let new_x = snap_to_grid(art.board_x.saturating_sub(step), snap);
+                        // Art may hang off the left edge, but keep at least one column on the board
+                        if new_x + art_dimensions.0 > 0 {
                             art.board_x = new_x;
                             art_moved = true;
                         }
@@ -508,26 +639,37 @@ impl App {
                 }
                 KeyCode::Right => {
                     if let Some(art) = &mut self.loaded_art {
-                        let art_dimensions = crate::art::get_art_dimensions(art);
-                        let new_x = art.board_x.saturating_add(1);
-                        // Only move if the art won't go right of the board
-                        if new_x + art_dimensions.0 <= board_width {
+                        let new_x = snap_to_grid(art.board_x.saturating_add(step), snap);
+                        // Art may hang off the right edge, but keep at least one column on the board
+                        if new_x < board_width {
                             art.board_x = new_x;
                             art_moved = true;
                         }
                     }
                 }
                 KeyCode::Enter => {
+                    self.fit_region_active = false;
+                    self.fit_region_corner = None;
+
+                    // Repositioning an existing queue item updates it in place
+                    if self.moving_queue_item.is_some() {
+                        self.finish_queue_item_move();
+                        return Ok(());
+                    }
+
                     // Add loaded art to queue and start processing
                     if let Some(art) = &self.loaded_art {
                         let art_name = art.name.clone();
                         let art_position = (art.board_x, art.board_y);
 
-                        // Add art to queue at current position
-                        self.add_art_to_queue(art.clone()).await;
+                        // Add art to queue at current position; stay positioning if it was rejected
+                        if !self.add_art_to_queue(art.clone()).await {
+                            return Ok(());
+                        }
 
-                        // Clear loaded art so user exits positioning mode
+                        // Clear loaded art so user exits positioning mode; the order flip was for this art only
                         self.loaded_art = None;
+                        self.next_placement_order = PlacementOrder::default();
 
                         // Start queue processing immediately
                         if !self.queue_processing {
@@ -542,6 +684,26 @@ impl App {
                         self.add_status_message("No art loaded to place.".to_string());
                     }
                 }
+                KeyCode::Char('f') => self.start_fit_region(),
+                KeyCode::Char('o') => {
+                    // Flip between the default border-first order and center-out
+                    self.next_placement_order =
+                        if self.next_placement_order == PlacementOrder::CenterOut {
+                            PlacementOrder::BorderFirst
+                        } else {
+                            PlacementOrder::CenterOut
+                        };
+                    self.status_message = format!(
+                        "Next placement order: {}. Press Enter to place, 'o' to flip.",
+                        self.next_placement_order.label()
+                    );
+                }
+                KeyCode::Esc if self.fit_region_active => {
+                    self.fit_region_active = false;
+                    self.fit_region_corner = None;
+                    self.status_message = "Fit to region cancelled.".to_string();
+                    return Ok(());
+                }
                 KeyCode::Esc => {
                     if self.placement_in_progress {
                         // Cancel ongoing placement
@@ -553,6 +715,8 @@ impl App {
                     } else {
                         // Cancel loaded art
                         self.loaded_art = None;
+                        self.moving_queue_item = None;
+                        self.duplicate_priority = None;
                         self.status_message =
                             "Loaded art cancelled. Board scroll re-enabled.".to_string();
                     }
@@ -562,24 +726,57 @@ impl App {
             if art_moved {
                 let art = self.loaded_art.as_ref().unwrap();
                 self.status_message = format!(
-                    "Art '{}' at ({}, {}). Press Enter to place.",
-                    art.name, art.board_x, art.board_y
+                    "Art '{}' at ({}, {}){}{}. Press Enter to place {}.",
+                    art.name,
+                    art.board_x,
+                    art.board_y,
+                    self.snap_grid_size
+                        .map(|size| format!(" [snap {}]", size))
+                        .unwrap_or_default(),
+                    Self::off_board_note(&self.board, art),
+                    self.next_placement_order.label()
                 );
+                self.note_positioning_overlap();
             }
         }
 
         if !art_moved {
+            if matches!(
+                key_code,
+                KeyCode::Up | KeyCode::Down | KeyCode::Left | KeyCode::Right
+            ) {
+                self.stop_following_queue();
+            }
             match key_code {
                 KeyCode::Up => self.board_viewport_y = self.board_viewport_y.saturating_sub(25),
                 KeyCode::Down => self.board_viewport_y = self.board_viewport_y.saturating_add(25),
                 KeyCode::Left => self.board_viewport_x = self.board_viewport_x.saturating_sub(15),
                 KeyCode::Right => self.board_viewport_x = self.board_viewport_x.saturating_add(15),
+                KeyCode::Esc if self.capture_region_active => {
+                    self.capture_region_active = false;
+                    self.capture_region_corner = None;
+                    self.status_message = "Copy region cancelled.".to_string();
+                }
+                KeyCode::Esc if self.color_search.is_some() => self.clear_color_search(),
+                KeyCode::Esc if self.snapshot_diff.is_some() => self.clear_snapshot_diff(),
                 KeyCode::Esc => {
                     if self.queue_processing {
                         self.cancel_queue_processing();
                     }
                 }
-                KeyCode::Char('q') => self.exit = true,
+                KeyCode::Char('q') => self.request_quit(),
+                KeyCode::Char('/') => self.start_color_search(),
+                KeyCode::Char('n') => self.cycle_color_search(true),
+                KeyCode::Char('N') => self.cycle_color_search(false),
+                KeyCode::Char('k') => self.save_board_snapshot(),
+                KeyCode::Char('K') => self.start_snapshot_diff(),
+                KeyCode::Char('P') => {
+                    self.input_mode = InputMode::EnterPaletteFile;
+                    self.input_buffer.clear();
+                    self.status_message =
+                        "Palette file with color names (GIMP .gpl or 'ID Name' lines), or 'none':"
+                            .to_string();
+                }
                 KeyCode::Char('c') => {
                     self.input_mode = InputMode::EnterBaseUrl;
                     self.status_message = "Select API Base URL or choose Custom:".to_string();
@@ -591,11 +788,51 @@ impl App {
                     self.status_message = "Select API Base URL or choose Custom:".to_string();
                     self.base_url_selection_index = 0; // Reset selection to first option
                 }
+                KeyCode::Char('B') => self.toggle_secondary_session(),
+                KeyCode::Char('C') => self.request_logout(),
+                KeyCode::Char('W') => self.toggle_watch_at_cursor(),
+                KeyCode::Char('R') => self.start_region_capture(),
+                KeyCode::Char('S') => self.toggle_spectator_mode(),
+                KeyCode::Char('A') => self.toggle_ascii_status(),
+                KeyCode::Char('a') => self.toggle_placement_bell(),
+                KeyCode::Char('T') => self.toggle_cooldown_bell(),
+                KeyCode::Char('E') => self.toggle_empty_cells_only(),
+                KeyCode::Char('X') => self.toggle_force_placement(),
+                KeyCode::Char('Q') => {
+                    self.queue_sidebar = !self.queue_sidebar;
+                    self.status_message = if self.queue_sidebar {
+                        "Queue sidebar shown. [ / ]: select an item and center on it.".to_string()
+                    } else {
+                        "Queue sidebar hidden.".to_string()
+                    };
+                }
+                KeyCode::Char('[') | KeyCode::Char(']')
+                    if self.queue_sidebar && !self.art_queue.is_empty() =>
+                {
+                    let count = self.art_queue.len();
+                    self.queue_selection_index = if key_code == KeyCode::Char(']') {
+                        (self.queue_selection_index + 1) % count
+                    } else {
+                        (self.queue_selection_index + count - 1) % count
+                    };
+                    self.center_viewport_on_selected_queue_item();
+                }
+                KeyCode::Char('G') => self.recenter_on_most_griefed_art(),
+                KeyCode::Char('F') => self.toggle_follow_queue(),
+                KeyCode::Char('D') => {
+                    self.api_debug = !self.api_debug;
+                    self.add_status_message(if self.api_debug {
+                        "🐞 API debug on: full error details will be logged.".to_string()
+                    } else {
+                        "🐞 API debug off.".to_string()
+                    });
+                }
+                KeyCode::Tab => self.switch_session(),
                 KeyCode::Char('r') => self.trigger_board_fetch(),
                 KeyCode::Char('p') => self.trigger_profile_fetch(),
                 KeyCode::Char('l') => {
                     // Open art selection to add more arts
-                    self.available_pixel_arts = get_available_pixel_arts();
+                    self.refresh_available_pixel_arts();
                     if !self.available_pixel_arts.is_empty() {
                         self.input_mode = InputMode::ArtSelection;
                         self.art_selection_index = 0;
@@ -614,15 +851,18 @@ impl App {
                     self.input_buffer.clear();
                     self.status_message = "Enter name for new pixel art:".to_string();
                 }
-                KeyCode::Char('?') => {
-                    self.input_mode = InputMode::ShowHelp;
-                    self.status_message = "Showing help. Press Esc or q to close.".to_string();
-                }
+                KeyCode::Char('?') => self.open_help(),
                 KeyCode::Char('i') => {
                     self.input_mode = InputMode::ShowProfile;
                     self.status_message =
                         "Showing user profile. Press Esc, q, or i to close.".to_string();
                 }
+                KeyCode::Char('I') => {
+                    self.ensure_board_info();
+                    self.input_mode = InputMode::ShowBoardInfo;
+                    self.status_message =
+                        "Showing board info. Press Esc, q, or I to close.".to_string();
+                }
                 KeyCode::Char('h') => {
                     self.input_mode = InputMode::ShowStatusLog;
                     self.status_message =
@@ -662,6 +902,31 @@ impl App {
                     // Toggle periodic validation of completed queue items
                     self.toggle_validation();
                 }
+                KeyCode::Char('g') => {
+                    // Cycle snap-to-grid size for positioning loaded art
+                    let current = SNAP_GRID_SIZES
+                        .iter()
+                        .position(|size| *size == self.snap_grid_size)
+                        .unwrap_or(0);
+                    self.snap_grid_size = SNAP_GRID_SIZES[(current + 1) % SNAP_GRID_SIZES.len()];
+                    self.status_message = match self.snap_grid_size {
+                        Some(size) => format!("Snap to grid: {} pixels.", size),
+                        None => "Snap to grid: off.".to_string(),
+                    };
+                }
+                KeyCode::Char('m') => {
+                    // Cycle layout: auto -> compact -> standard -> auto
+                    self.compact_layout = match self.compact_layout {
+                        None => Some(true),
+                        Some(true) => Some(false),
+                        Some(false) => None,
+                    };
+                    self.status_message = match self.compact_layout {
+                        None => "Layout: auto (compact on short terminals).".to_string(),
+                        Some(true) => "Layout: compact.".to_string(),
+                        Some(false) => "Layout: standard.".to_string(),
+                    };
+                }
                 KeyCode::Char('z') => {
                     // Enter share string for quick coordinate sharing
                     self.input_mode = InputMode::EnterShareString;
@@ -677,9 +942,26 @@ impl App {
 
     async fn handle_art_editor_input(&mut self, key_code: KeyCode) -> io::Result<()> {
         match key_code {
+            KeyCode::Esc
+                if self.editor_dirty
+                    && self
+                        .current_editing_art
+                        .as_ref()
+                        .is_some_and(|art| !art.pattern.is_empty()) =>
+            {
+                self.input_mode = InputMode::ArtEditorExitConfirm;
+                self.status_message =
+                    "Discard unsaved changes? y: discard, s: save and exit, n/Esc: keep editing"
+                        .to_string();
+            }
             KeyCode::Esc => {
                 self.input_mode = InputMode::None;
-                self.status_message = "Exited Pixel Art Editor. Changes not saved.".to_string();
+                self.flush_editor_autosave(true);
+                self.status_message = if self.editor_autosave_name().is_some() {
+                    "Exited Pixel Art Editor. Unsaved work kept in the autosave ('r' in the editor restores it).".to_string()
+                } else {
+                    "Exited Pixel Art Editor. Changes not saved.".to_string()
+                };
             }
             KeyCode::Up => {
                 self.art_editor_cursor_y = self.art_editor_cursor_y.saturating_sub(1).max(0);
@@ -715,8 +997,23 @@ impl App {
                         self.art_editor_cursor_y,
                         self.art_editor_selected_color_id
                     );
+                    self.mark_editor_edited();
                 }
             }
+            KeyCode::Char('r') if self.editor_dirty => {
+                self.input_mode = InputMode::ArtRestoreConfirm;
+                self.status_message =
+                    "Replace the editor art with the autosave? y: restore, n/Esc: keep editing"
+                        .to_string();
+            }
+            KeyCode::Char('r') => self.restore_editor_autosave(),
+            KeyCode::Char('?') => self.open_help(),
+            KeyCode::Char('s') if self.editing_saved_art && self.current_editing_art.is_some() => {
+                self.input_mode = InputMode::ArtOverwriteConfirm;
+                self.status_message =
+                    "Overwrite the saved art with your changes? y: overwrite, n/Esc: keep editing"
+                        .to_string();
+            }
             KeyCode::Char('s') => {
                 if self.current_editing_art.is_some() {
                     // Auto-save with the art's name instead of prompting for filename
@@ -737,12 +1034,8 @@ impl App {
                     // Update selected color to match palette index
                     if let Some(color) = self.colors.get(self.art_editor_color_palette_index) {
                         self.art_editor_selected_color_id = color.id;
-                        let color_name = if color.name.trim().is_empty() {
-                            format!("Color {}", color.id)
-                        } else {
-                            color.name.clone()
-                        };
-                        self.status_message = format!("Selected color: {}", color_name);
+                        self.status_message =
+                            format!("Selected color: {}", self.color_display_name(color));
                     }
                 }
             }
@@ -759,12 +1052,31 @@ impl App {
                     // Update selected color to match palette index
                     if let Some(color) = self.colors.get(self.art_editor_color_palette_index) {
                         self.art_editor_selected_color_id = color.id;
-                        let color_name = if color.name.trim().is_empty() {
-                            format!("Color {}", color.id)
-                        } else {
-                            color.name.clone()
-                        };
-                        self.status_message = format!("Selected color: {}", color_name);
+                        self.status_message =
+                            format!("Selected color: {}", self.color_display_name(color));
+                    }
+                }
+            }
+            KeyCode::Char('d') | KeyCode::Char('D') => {
+                // Resolve duplicate positions: 'd' keeps the last drawn pixel, 'D' the first
+                if let Some(art) = &mut self.current_editing_art {
+                    let (resolution, kept) = if key_code == KeyCode::Char('d') {
+                        (crate::art::DuplicateResolution::KeepLast, "last")
+                    } else {
+                        (crate::art::DuplicateResolution::KeepFirst, "first")
+                    };
+                    let conflicts = crate::art::count_conflicting_pixels(art);
+                    let removed = crate::art::resolve_duplicate_pixels(art, resolution);
+                    self.status_message = if removed == 0 {
+                        "No duplicate pixels to resolve.".to_string()
+                    } else {
+                        format!(
+                            "Removed {} duplicate pixels ({} color conflicts), kept the {} at each position.",
+                            removed, conflicts, kept
+                        )
+                    };
+                    if removed > 0 {
+                        self.mark_editor_edited();
                     }
                 }
             }
@@ -776,10 +1088,16 @@ impl App {
         Ok(())
     }
 
+    /// Show help with the keys for the current screen first; closing it comes back here
+    fn open_help(&mut self) {
+        self.help_return_mode = std::mem::replace(&mut self.input_mode, InputMode::ShowHelp);
+        self.status_message = "Showing help. Press Esc or q to close.".to_string();
+    }
+
     fn handle_help_input(&mut self, key_code: KeyCode) {
         match key_code {
             KeyCode::Esc | KeyCode::Char('q') | KeyCode::Char('?') => {
-                self.input_mode = InputMode::None; // Or store and revert to previous mode
+                self.input_mode = std::mem::take(&mut self.help_return_mode);
                 self.status_message = "Help closed.".to_string();
             }
             _ => {}
@@ -796,6 +1114,20 @@ impl App {
         }
     }
 
+    fn handle_board_info_input(&mut self, key_code: KeyCode) {
+        match key_code {
+            KeyCode::Esc | KeyCode::Char('q') | KeyCode::Char('I') => {
+                self.input_mode = InputMode::None;
+                self.status_message = "Board info closed.".to_string();
+            }
+            KeyCode::Char('r') => {
+                // Recompute from the current board data
+                self.board_info = Some(self.compute_board_info());
+            }
+            _ => {}
+        }
+    }
+
     fn handle_status_log_input(&mut self, key_code: KeyCode) {
         match key_code {
             KeyCode::Esc | KeyCode::Char('q') | KeyCode::Char('h') => {
@@ -814,11 +1146,80 @@ impl App {
         }
     }
 
+    async fn handle_editor_exit_confirm_input(&mut self, key_code: KeyCode) {
+        match key_code {
+            KeyCode::Char('y') | KeyCode::Char('Y') => {
+                self.discard_editor_autosave();
+                self.editor_dirty = false;
+                self.input_mode = InputMode::None;
+                self.status_message =
+                    "Exited Pixel Art Editor. Unsaved changes discarded.".to_string();
+            }
+            KeyCode::Char('s') | KeyCode::Char('S') => {
+                if let Some(art) = &self.current_editing_art {
+                    let filename = format!("{}.json", art.name);
+                    self.save_current_art_to_file(filename).await;
+                }
+                // A failed save keeps the editor open with the error showing
+                self.input_mode = if self.editor_dirty {
+                    InputMode::ArtEditor
+                } else {
+                    InputMode::None
+                };
+            }
+            KeyCode::Char('n') | KeyCode::Char('N') | KeyCode::Esc => {
+                self.input_mode = InputMode::ArtEditor;
+                self.status_message = "Back to the editor.".to_string();
+            }
+            _ => {}
+        }
+    }
+
+    async fn handle_art_overwrite_confirm_input(&mut self, key_code: KeyCode) {
+        match key_code {
+            KeyCode::Char('y') | KeyCode::Char('Y') => {
+                if let Some(art) = &self.current_editing_art {
+                    let filename = format!("{}.json", art.name);
+                    self.save_current_art_to_file(filename).await;
+                }
+                // Later saves in this session overwrite without asking again
+                if !self.editor_dirty {
+                    self.editing_saved_art = false;
+                }
+                self.input_mode = InputMode::ArtEditor;
+            }
+            KeyCode::Char('n') | KeyCode::Char('N') | KeyCode::Esc => {
+                self.input_mode = InputMode::ArtEditor;
+                self.status_message = "Not saved. Back to the editor.".to_string();
+            }
+            _ => {}
+        }
+    }
+
+    fn handle_art_restore_confirm_input(&mut self, key_code: KeyCode) {
+        match key_code {
+            KeyCode::Char('y') | KeyCode::Char('Y') => {
+                self.input_mode = InputMode::ArtEditor;
+                self.restore_editor_autosave();
+            }
+            KeyCode::Char('n') | KeyCode::Char('N') | KeyCode::Esc => {
+                self.input_mode = InputMode::ArtEditor;
+                self.status_message = "Not restored. Back to the editor.".to_string();
+            }
+            _ => {}
+        }
+    }
+
     fn handle_new_art_name_input(&mut self, key_code: KeyCode) {
         match key_code {
             KeyCode::Enter => {
                 let name = self.input_buffer.trim().to_string();
                 if !name.is_empty() {
+                    // Without a board or cached palette, draw with the built-in one
+                    let offline = self.colors.is_empty() || self.using_default_palette();
+                    if self.colors.is_empty() {
+                        self.colors = crate::art::default_palette();
+                    }
                     self.current_editing_art = Some(PixelArt {
                         name,
                         width: 0,
@@ -830,14 +1231,24 @@ impl App {
                         author: None,
                         created_at: Some(chrono::Utc::now().to_rfc3339()),
                         tags: None,
+                        default_palette: offline,
                     });
+                    self.editing_saved_art = false;
+                    (self.art_editor_canvas_width, self.art_editor_canvas_height) =
+                        crate::app_state::EDITOR_CANVAS_SIZE;
                     self.input_mode = InputMode::ArtEditor;
                     self.status_message = format!(
 						"Entered Pixel Art Editor. Canvas: {}x{}. Arrows to move, Space to draw, Tab to change colors, s to save.",
 						self.art_editor_canvas_width, self.art_editor_canvas_height
 					);
+                    if offline {
+                        self.status_message.push_str(
+                            " Offline: using the built-in palette, matched to the board's colors when loaded.",
+                        );
+                    }
 
                     // Initialize editor state
+                    self.editor_dirty = false;
                     self.art_editor_cursor_x = 0;
                     self.art_editor_cursor_y = 0;
                     self.art_editor_selected_color_id = 1;
@@ -855,6 +1266,13 @@ impl App {
                             self.art_editor_selected_color_id = first_color.id;
                         }
                     }
+
+                    if let Some(autosaved) = self.editor_autosave_name() {
+                        self.status_message.push_str(&format!(
+                            " Unsaved work from '{}' found - press 'r' to restore it.",
+                            autosaved
+                        ));
+                    }
                 } else {
                     self.status_message = "Name cannot be empty. Please enter a name.".to_string();
                 }
@@ -873,6 +1291,7 @@ impl App {
 
     fn handle_art_selection_input(&mut self, key_code: KeyCode) {
         match key_code {
+            KeyCode::Char('?') => self.open_help(),
             KeyCode::Up => {
                 if self.art_selection_index > 0 {
                     self.art_selection_index -= 1;
@@ -947,6 +1366,8 @@ impl App {
 
                     // Load art for positioning
                     self.loaded_art = Some(art_to_load.clone());
+                    self.moving_queue_item = None;
+                    self.duplicate_priority = None;
                     self.input_mode = InputMode::None;
                     self.status_message = format!(
                         "Loaded art: '{}' at ({}, {}). Use arrows to position, Enter to add to queue.",
@@ -958,6 +1379,29 @@ impl App {
                 self.input_mode = InputMode::None;
                 self.status_message = "Art selection cancelled.".to_string();
             }
+            KeyCode::Char('e') => {
+                if let Some(art) = self
+                    .available_pixel_arts
+                    .get(self.art_selection_index)
+                    .cloned()
+                {
+                    self.open_art_in_editor(art);
+                }
+            }
+            KeyCode::Char('y') => self.duplicate_selected_art(),
+            KeyCode::Char('v') => {
+                if let Some(art) = self
+                    .available_pixel_arts
+                    .get(self.art_selection_index)
+                    .cloned()
+                {
+                    self.art_preview_art = Some(art);
+                    self.input_mode = InputMode::ArtPreview;
+                    self.status_message =
+                        "Full preview. ←/→ (or k/j) to browse, Enter to load, Esc to return."
+                            .to_string();
+                }
+            }
             KeyCode::Char('d') => {
                 // Delete selected art with confirmation
                 if !self.available_pixel_arts.is_empty()
@@ -973,7 +1417,7 @@ impl App {
                     );
                 }
             }
-            KeyCode::Char('q') => self.exit = true,
+            KeyCode::Char('q') => self.request_quit(),
             _ => {}
         }
     }
@@ -985,6 +1429,8 @@ impl App {
                 self.input_mode = InputMode::ArtSelection;
                 self.status_message = "Returned to art selection.".to_string();
             }
+            KeyCode::Left | KeyCode::Char('k') => self.step_art_preview(false),
+            KeyCode::Right | KeyCode::Char('j') => self.step_art_preview(true),
             KeyCode::Enter => {
                 // Load the previewed art for positioning
                 if let Some(art) = &self.art_preview_art {
@@ -1046,6 +1492,8 @@ impl App {
 
                     // Load art for positioning
                     self.loaded_art = Some(art_to_load.clone());
+                    self.moving_queue_item = None;
+                    self.duplicate_priority = None;
                     self.art_preview_art = None;
                     self.input_mode = InputMode::None;
                     self.status_message = format!(
@@ -1058,8 +1506,34 @@ impl App {
         }
     }
 
+    /// Show the next (or previous) art in the full preview, wrapping around the list
+    fn step_art_preview(&mut self, forward: bool) {
+        let count = self.available_pixel_arts.len();
+        if count == 0 {
+            return;
+        }
+        self.art_selection_index = if forward {
+            (self.art_selection_index + 1) % count
+        } else {
+            (self.art_selection_index + count - 1) % count
+        };
+        self.art_preview_art = self
+            .available_pixel_arts
+            .get(self.art_selection_index)
+            .cloned();
+        if let Some(art) = &self.art_preview_art {
+            self.status_message = format!(
+                "Previewing '{}' ({}/{}).",
+                art.name,
+                self.art_selection_index + 1,
+                count
+            );
+        }
+    }
+
     async fn handle_queue_input(&mut self, key_code: KeyCode) -> io::Result<()> {
         match key_code {
+            KeyCode::Char('?') => self.open_help(),
             KeyCode::Up => {
                 if self.queue_selection_index > 0 {
                     self.queue_selection_index -= 1;
@@ -1167,25 +1641,16 @@ impl App {
                     }
                 }
             }
-            KeyCode::Char('1'..='5') => {
-                // Set priority for selected item
-                if !self.art_queue.is_empty() && self.queue_selection_index < self.art_queue.len() {
-                    let priority = match key_code {
-                        KeyCode::Char('1') => 1,
-                        KeyCode::Char('2') => 2,
-                        KeyCode::Char('3') => 3,
-                        KeyCode::Char('4') => 4,
-                        KeyCode::Char('5') => 5,
-                        _ => 3, // Default priority
-                    };
-                    self.art_queue[self.queue_selection_index].priority = priority;
-                    self.sort_queue_by_priority();
-                    let _ = self.save_queue(); // Auto-save after priority change
-                    self.status_message = format!(
-                        "Set priority {} for '{}'",
-                        priority, self.art_queue[self.queue_selection_index].art.name
-                    );
+            KeyCode::Char(' ') => self.toggle_queue_item_selection(),
+            KeyCode::Char(digit @ '1'..='5') => {
+                // Set priority for the marked items, or the selected one
+                self.set_queue_priority(digit as u8 - b'0');
+            }
+            KeyCode::Esc if self.art_queue.iter().any(|item| item.selected) => {
+                for item in &mut self.art_queue {
+                    item.selected = false;
                 }
+                self.status_message = "Queue marks cleared.".to_string();
             }
             KeyCode::Esc => {
                 self.input_mode = InputMode::None;
@@ -1193,7 +1658,7 @@ impl App {
             }
             KeyCode::Char('l') => {
                 // Open art selection to add more arts
-                self.available_pixel_arts = get_available_pixel_arts();
+                self.refresh_available_pixel_arts();
                 if !self.available_pixel_arts.is_empty() {
                     self.input_mode = InputMode::ArtSelection;
                     self.art_selection_index = 0;
@@ -1210,6 +1675,47 @@ impl App {
                 // Toggle pause/resume for selected queue item
                 self.toggle_selected_queue_item_pause();
             }
+            KeyCode::Char('i') => {
+                // Cycle interleaved processing (takes effect on the next queue start)
+                self.queue_interleave = self.queue_interleave.next();
+                self.status_message = match self.queue_interleave {
+                    Interleave::Off => "Queue mode: sequential - one art at a time.",
+                    Interleave::RoundRobin => {
+                        "Queue mode: interleaved - one pixel per pending art per round."
+                    }
+                    Interleave::Priority => {
+                        "Queue mode: interleaved by priority - priority 1 arts get the most pixels per round."
+                    }
+                }
+                .to_string();
+            }
+            KeyCode::Char('G') => self.recenter_on_most_griefed_art(),
+            KeyCode::Char('F') => self.retry_failed_queue_items(),
+            KeyCode::Char('x') => self.clear_finished_queue_items(),
+            KeyCode::Char('y') => self.duplicate_selected_queue_item(),
+            KeyCode::Char('m') => self.move_selected_queue_item(),
+            KeyCode::Char('p') => self.cycle_selected_item_placement_order(),
+            KeyCode::Char('t') => {
+                // Cycle cooldown presets (takes effect on the next queue start)
+                self.cooldown_strategy = self.cooldown_strategy.next();
+                self.status_message =
+                    format!("Cooldown strategy: {}", self.cooldown_strategy.label());
+            }
+            KeyCode::Char('v') => {
+                // Read-back verification (takes effect on the next queue start)
+                self.verify_placements = !self.verify_placements;
+                self.status_message = format!(
+                    "Placement verification: {}",
+                    if self.verify_placements { "on" } else { "off" }
+                );
+            }
+            KeyCode::Char('o') => {
+                self.completed_overlay = self.completed_overlay.next();
+                self.status_message = format!(
+                    "Completed items on board: {}",
+                    self.completed_overlay.label()
+                );
+            }
             _ => {}
         }
         Ok(())
@@ -1266,6 +1772,76 @@ impl App {
         }
     }
 
+    fn handle_color_search_input(&mut self, key_code: KeyCode) {
+        match key_code {
+            KeyCode::Enter => {
+                let query = self.input_buffer.trim().to_string();
+                if !query.is_empty() {
+                    self.apply_color_search(&query);
+                } else {
+                    self.status_message = "Empty color search.".to_string();
+                    self.input_mode = InputMode::None;
+                }
+            }
+            KeyCode::Esc => {
+                self.input_mode = InputMode::None;
+                self.status_message = "Color search cancelled.".to_string();
+            }
+            KeyCode::Backspace => {
+                self.input_buffer.pop();
+            }
+            KeyCode::Char(c) => {
+                self.input_buffer.push(c);
+            }
+            _ => {}
+        }
+    }
+
+    fn handle_palette_file_input(&mut self, key_code: KeyCode) {
+        match key_code {
+            KeyCode::Enter => {
+                let path = self.input_buffer.trim().to_string();
+                if !path.is_empty() {
+                    self.import_palette_names(&path);
+                } else {
+                    self.status_message = "No palette file given.".to_string();
+                    self.input_mode = InputMode::None;
+                }
+            }
+            KeyCode::Esc => {
+                self.input_mode = InputMode::None;
+                self.status_message = "Palette import cancelled.".to_string();
+            }
+            KeyCode::Backspace => {
+                self.input_buffer.pop();
+            }
+            KeyCode::Char(c) => {
+                self.input_buffer.push(c);
+            }
+            _ => {}
+        }
+    }
+
+    fn handle_snapshot_diff_input(&mut self, key_code: KeyCode) {
+        match key_code {
+            KeyCode::Enter => {
+                let query = self.input_buffer.trim().to_string();
+                self.apply_snapshot_diff(&query);
+            }
+            KeyCode::Esc => {
+                self.input_mode = InputMode::None;
+                self.status_message = "Snapshot diff cancelled.".to_string();
+            }
+            KeyCode::Backspace => {
+                self.input_buffer.pop();
+            }
+            KeyCode::Char(c) => {
+                self.input_buffer.push(c);
+            }
+            _ => {}
+        }
+    }
+
     fn handle_share_selection_input(&mut self, key_code: KeyCode) {
         match key_code {
             KeyCode::Up => {
@@ -1311,13 +1887,17 @@ impl App {
                         let art_name = self.available_pixel_arts[index].name.clone();
 
                         // Delete the actual file
-                        let filename = format!("patterns/{}.json", art_name);
+                        let filename = crate::paths::data_path("patterns")
+                            .join(format!("{}.json", art_name))
+                            .display()
+                            .to_string();
                         if let Err(e) = std::fs::remove_file(&filename) {
                             self.status_message =
                                 format!("Failed to delete file '{}': {}", filename, e);
                         } else {
                             // Remove from the list
                             self.available_pixel_arts.remove(index);
+                            self.available_art_sizes.remove(index);
 
                             // Adjust selection index if needed
                             if self.art_selection_index >= self.available_pixel_arts.len()
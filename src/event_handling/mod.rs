@@ -1,9 +1,17 @@
 // Event handling modules
 pub mod art_placement;
+pub mod art_stats;
 pub mod board_management;
+pub mod board_snapshots;
+pub mod color_search;
+pub mod editor_autosave;
 pub mod file_operations;
 pub mod helpers;
 pub mod input_handling;
+pub mod positioning_resume;
 pub mod profile_management;
 pub mod queue_management;
+pub mod run_reports;
+pub mod session_management;
 pub mod validation;
+pub mod watch_list;
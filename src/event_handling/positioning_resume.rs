@@ -0,0 +1,90 @@
+use crate::app_state::{App, InputMode};
+use crate::art::PixelArt;
+use crossterm::event::KeyCode;
+
+impl App {
+    /// Keep `loaded_art.json` in step with the art being positioned, so a restart can resume it
+    pub fn sync_loaded_art_file(&mut self) {
+        // A queue item being moved is already saved in the queue
+        let art = self
+            .loaded_art
+            .as_ref()
+            .filter(|_| self.moving_queue_item.is_none());
+        let key = art.map(|art| {
+            (
+                art.name.clone(),
+                art.board_x,
+                art.board_y,
+                art.pattern.len(),
+            )
+        });
+        if key == self.loaded_art_saved {
+            return;
+        }
+
+        let result = match art {
+            Some(art) => serde_json::to_string_pretty(art)
+                .map_err(|e| e.to_string())
+                .and_then(|data| {
+                    std::fs::write(loaded_art_path(), data).map_err(|e| e.to_string())
+                }),
+            None => match std::fs::remove_file(loaded_art_path()) {
+                Err(e) if e.kind() != std::io::ErrorKind::NotFound => Err(e.to_string()),
+                _ => Ok(()),
+            },
+        };
+        self.loaded_art_saved = key;
+        if let Err(e) = result {
+            self.add_status_message(format!("❌ Could not save the loaded art: {}", e));
+        }
+    }
+
+    /// At startup, offer to pick up the art that was being positioned when the app closed
+    pub fn offer_positioning_resume(&mut self) {
+        // Setup prompts come first; the file stays for the next start
+        if self.input_mode != InputMode::None {
+            return;
+        }
+        let Some(art) = std::fs::read_to_string(loaded_art_path())
+            .ok()
+            .and_then(|data| serde_json::from_str::<PixelArt>(&data).ok())
+        else {
+            return;
+        };
+
+        self.status_message = format!(
+            "Resume positioning '{}' at ({}, {})? (y/n)",
+            art.name, art.board_x, art.board_y
+        );
+        self.resume_art = Some(art);
+        self.input_mode = InputMode::ResumePositioning;
+    }
+
+    pub fn handle_resume_positioning_input(&mut self, key_code: KeyCode) {
+        match key_code {
+            KeyCode::Char('y') | KeyCode::Char('Y') => {
+                self.input_mode = InputMode::None;
+                let Some(art) = self.resume_art.take() else {
+                    return;
+                };
+                self.status_message = format!(
+                    "Positioning '{}' at ({}, {}). Move it with arrows/mouse, Enter to queue, Esc to cancel.",
+                    art.name, art.board_x, art.board_y
+                );
+                self.loaded_art = Some(art);
+            }
+            KeyCode::Char('n') | KeyCode::Char('N') | KeyCode::Esc => {
+                self.input_mode = InputMode::None;
+                if let Some(art) = self.resume_art.take() {
+                    let _ = std::fs::remove_file(loaded_art_path());
+                    self.status_message = format!("Discarded the positioning of '{}'.", art.name);
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+fn loaded_art_path() -> std::path::PathBuf {
+    crate::paths::data_path("loaded_art.json")
+}
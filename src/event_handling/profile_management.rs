@@ -1,5 +1,5 @@
 use crate::api_client::ApiError;
-use crate::app_state::{App, InputMode, ProfileFetchResult};
+use crate::app_state::{App, ConnectionState, ConnectionTestResult, InputMode, ProfileFetchResult};
 use tokio::sync::mpsc;
 
 impl App {
@@ -12,6 +12,7 @@ impl App {
             } => {
                 // Log successful API call
                 self.log_api_call("GET", "/api/profile", Some(200));
+                self.connection_state = ConnectionState::Connected;
 
                 // Update main API client tokens if they were refreshed
                 if let Some((access_token, refresh_token)) = updated_tokens {
@@ -29,23 +30,14 @@ impl App {
                 // Save tokens in case they were refreshed during the API call
                 self.save_tokens();
             }
-            ProfileFetchResult::Error(error_msg) => {
-                // Try to extract status code from error message for logging
-                let status_code = if error_msg.contains("Unauthorized") {
-                    Some(401)
-                } else if error_msg.contains("403") || error_msg.contains("Forbidden") {
-                    Some(403)
-                } else if error_msg.contains("404") {
-                    Some(404)
-                } else if error_msg.contains("500") {
-                    Some(500)
-                } else {
-                    None
-                };
-
+            ProfileFetchResult::Error {
+                message: error_msg,
+                status: status_code,
+            } => {
                 if let Some(code) = status_code {
                     self.log_api_call("GET", "/api/profile", Some(code));
                 }
+                self.connection_state = ConnectionState::from_fetch_status(status_code);
 
                 self.user_info = None;
                 self.add_status_message(format!(
@@ -78,6 +70,7 @@ impl App {
         let base_url = self.api_client.get_base_url();
         let access_token = self.api_client.get_access_token_clone();
         let refresh_token = self.api_client.get_refresh_token_clone();
+        let persist_tokens = self.persists_tokens();
 
         self.status_message = "Fetching profile data...".to_string();
 
@@ -90,7 +83,8 @@ impl App {
                 crate::api_client::ApiClient::new(Some(base_url), access_token, refresh_token);
 
             // Set up callback to save refreshed tokens to storage
-            if let Ok(callback) = crate::api_client::create_token_refresh_callback(None) {
+            if let Ok(callback) = crate::api_client::session_token_refresh_callback(persist_tokens)
+            {
                 api_client.set_token_refresh_callback(callback);
             }
             // Note: We don't fail the profile fetch if callback setup fails, just log it
@@ -115,12 +109,15 @@ impl App {
                 }
                 Err(e) => {
                     let error_msg = match e {
-                        crate::api_client::ApiError::Unauthorized => {
+                        crate::api_client::ApiError::Unauthorized { login_page: false } => {
                             "Unauthorized. Access Token might be invalid or expired".to_string()
                         }
-                        _ => format!("{:?}", e),
+                        _ => e.summary(),
                     };
-                    ProfileFetchResult::Error(error_msg)
+                    ProfileFetchResult::Error {
+                        message: error_msg,
+                        status: e.status_code(),
+                    }
                 }
             };
 
@@ -129,6 +126,100 @@ impl App {
         });
     }
 
+    /// Check the config being entered with a profile fetch; what is typed so far counts
+    pub fn start_connection_test(&mut self) {
+        let typed = self.input_buffer.trim().to_string();
+        let stored_base_url = self.api_client.get_base_url();
+        let stored_tokens = self.api_client.get_tokens();
+        let mut base_url = stored_base_url.clone();
+        let (mut access_token, mut refresh_token) = stored_tokens.clone();
+        match self.input_mode {
+            InputMode::EnterBaseUrl => {
+                let highlighted = &self.base_url_options[self.base_url_selection_index];
+                if highlighted != "Custom" {
+                    base_url = highlighted.clone();
+                }
+            }
+            InputMode::EnterCustomBaseUrlText if !typed.is_empty() => base_url = typed,
+            InputMode::EnterAccessToken if !typed.is_empty() => access_token = Some(typed),
+            InputMode::EnterRefreshToken if !typed.is_empty() => refresh_token = Some(typed),
+            _ => {}
+        }
+
+        self.status_message = format!("Testing connection to {}...", base_url);
+        let (tx, rx) = mpsc::unbounded_channel();
+        self.connection_test_receiver = Some(rx);
+
+        // Tokens rotated by a 426 replace the stored ones only if the test used those
+        let uses_stored_tokens = base_url == stored_base_url
+            && (access_token.clone(), refresh_token.clone()) == stored_tokens;
+
+        tokio::spawn(async move {
+            // No refresh callback: rotated tokens go back to the app, which decides whether to keep them
+            let mut api_client = crate::api_client::ApiClient::new(
+                Some(base_url.clone()),
+                access_token,
+                refresh_token,
+            );
+            let initial_tokens = api_client.get_tokens();
+            let profile = match api_client.get_profile().await {
+                Ok(profile_response) => ProfileFetchResult::Success {
+                    user_infos: profile_response.user_infos,
+                    updated_tokens: None,
+                },
+                Err(e) => ProfileFetchResult::Error {
+                    message: format!("{}: {}", base_url, e.diagnosis()),
+                    status: e.status_code(),
+                },
+            };
+            let tokens = api_client.get_tokens();
+            let _ = tx.send(ConnectionTestResult {
+                profile,
+                updated_tokens: (uses_stored_tokens && tokens != initial_tokens).then_some(tokens),
+            });
+        });
+    }
+
+    /// Report the connection test outcome; the session only changes if the server
+    /// rotated the stored tokens, since the old refresh token is then spent
+    pub fn handle_connection_test_result(&mut self, result: ConnectionTestResult) {
+        self.connection_test_receiver = None;
+        if let Some((access_token, refresh_token)) = result.updated_tokens {
+            self.api_client.set_tokens(access_token, refresh_token);
+            self.save_tokens();
+            self.add_status_message(
+                "🔄 Server refreshed the stored tokens during the test - saved the new ones."
+                    .to_string(),
+            );
+        }
+        let message = match result.profile {
+            ProfileFetchResult::Success { user_infos, .. } => {
+                let now_ms = chrono::Utc::now().timestamp_millis();
+                let active_timers = user_infos.timers.as_ref().map_or(Vec::new(), |timers| {
+                    timers.iter().copied().filter(|&t| t > now_ms).collect()
+                });
+                let available = user_infos.pixel_buffer - active_timers.len() as i32;
+                let cooldown = match active_timers.iter().min() {
+                    Some(next) if available <= 0 => {
+                        format!("next pixel in {}s", (next - now_ms + 999) / 1000)
+                    }
+                    _ => format!("{}/{} pixels ready", available, user_infos.pixel_buffer),
+                };
+                format!(
+                    "✅ Connection OK: logged in as {} - {} ({}s cooldown per pixel)",
+                    user_infos.username.as_deref().unwrap_or("N/A"),
+                    cooldown,
+                    user_infos.pixel_timer
+                )
+            }
+            ProfileFetchResult::Error { message, .. } => {
+                format!("❌ Connection test failed for {}", message)
+            }
+        };
+        self.add_status_message(message.clone());
+        self.status_message = message;
+    }
+
     /// Legacy profile fetch method for compatibility
     #[allow(dead_code)]
     pub async fn fetch_profile_data(&mut self) {
@@ -148,6 +239,7 @@ impl App {
             Ok(profile_response) => {
                 // Log successful API call
                 self.log_api_call("GET", "/api/profile", Some(200));
+                self.connection_state = ConnectionState::Connected;
 
                 let info = profile_response.user_infos;
                 self.status_message = format!(
@@ -167,7 +259,7 @@ impl App {
                     ApiError::ErrorResponse { status, .. } => {
                         self.log_api_call("GET", "/api/profile", Some(status.as_u16()));
                     }
-                    ApiError::Unauthorized => {
+                    ApiError::Unauthorized { .. } => {
                         self.log_api_call("GET", "/api/profile", Some(401));
                     }
                     _ => {
@@ -175,10 +267,17 @@ impl App {
                         self.log_api_call("GET", "/api/profile", None);
                     }
                 }
+                self.connection_state = ConnectionState::from_fetch_status(e.status_code());
 
                 self.user_info = None;
                 match e {
-                    ApiError::Unauthorized => {
+                    ApiError::Unauthorized { login_page: true } => {
+                        self.status_message = format!(
+                            "Error fetching profile: {}. Try 'c' to update.",
+                            crate::api_client::LOGIN_PAGE_HINT
+                        );
+                    }
+                    ApiError::Unauthorized { .. } => {
                         self.status_message = "Error fetching profile: Unauthorized. Access Token might be invalid or expired. Try 'c' to update.".to_string();
                     }
                     _ => {
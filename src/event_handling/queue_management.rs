@@ -1,10 +1,23 @@
-use crate::api_client::UserInfos;
-use crate::app_state::{App, ArtQueueItem, QueueStatus, QueueUpdate};
+use crate::api_client::{min_px_allows_coordinate, UserInfos};
+use crate::app_state::{
+    App, ArtQueueItem, CooldownStrategy, Interleave, PlacementOrder, QueueStatus, QueueUpdate,
+    RunItemStatus, SkipRules,
+};
 use crate::art::{ArtPixel, PixelArt};
-use std::collections::HashSet;
+use crate::event_handling::helpers::{
+    available_pixel_buffer, has_spare_buffer, pause_between_pixels,
+};
+use crate::event_log::{log_event, LogEvent};
+use std::collections::{HashMap, HashSet, VecDeque};
 use std::time::{Duration, Instant};
 use tokio::sync::mpsc;
 
+/// Upper bound for FTPLACE_PLACEMENT_CONCURRENCY
+pub const MAX_PLACEMENT_CONCURRENCY: usize = 8;
+
+/// Times one position is placed again after being overwritten during a run
+const MAX_PIXEL_DEFENSES: u32 = 3;
+
 impl App {
     /// Handle queue processing updates from background queue processing tasks
     pub fn handle_queue_update(&mut self, update: QueueUpdate) {
@@ -14,6 +27,17 @@ impl App {
                 art_name,
                 total_items,
             } => {
+                self.queue_active_index = Some(item_index);
+                self.queue_active_position = None;
+                self.report_item_started(item_index, &art_name);
+                log_event(LogEvent::ItemStarted {
+                    index: item_index,
+                    art: art_name.clone(),
+                });
+                if self.follow_queue {
+                    self.center_viewport_on_queue_item(item_index);
+                }
+
                 self.add_status_message(format!(
                     "🔄 Queue processing: Starting item {}/{} - '{}'",
                     item_index + 1,
@@ -27,13 +51,22 @@ impl App {
                 pixels_placed,
                 total_pixels,
                 position,
-                cooldown_remaining: _,
+                cooldown_remaining,
             } => {
                 // Update the queue item progress in our local queue
                 if let Some(item) = self.art_queue.get_mut(item_index) {
                     item.pixels_placed = pixels_placed; // Now correctly using actual successful placements
                     item.pixels_total = total_pixels; // Update total to reflect actual pixels that need placing
                 }
+                self.queue_active_index = Some(item_index);
+                self.queue_active_position = Some(position);
+
+                // Keep the original start across the task's periodic re-checks so elapsed time keeps growing
+                self.queue_cooldown = cooldown_remaining.map(|secs| {
+                    let now = Instant::now();
+                    let started = self.queue_cooldown.map_or(now, |(started, _)| started);
+                    (started, now + Duration::from_secs(secs as u64))
+                });
 
                 let base_msg = format!(
                     "📋 '{}' - placed {}/{} pixels at ({}, {})",
@@ -46,6 +79,19 @@ impl App {
 
                 self.add_status_message(base_msg);
             }
+            QueueUpdate::PixelPlaced { position } => self.flash_placed_pixel(position),
+            QueueUpdate::ItemCheckpoint {
+                item_index,
+                pixels_placed,
+                total_pixels,
+            } => {
+                // Persist partial progress so a crash doesn't lose the current item's placements
+                if let Some(item) = self.art_queue.get_mut(item_index) {
+                    item.pixels_placed = pixels_placed;
+                    item.pixels_total = total_pixels;
+                }
+                let _ = self.save_queue();
+            }
             QueueUpdate::ItemCompleted {
                 item_index,
                 art_name,
@@ -58,6 +104,15 @@ impl App {
                     item.pixels_placed = pixels_placed;
                     item.pixels_total = total_pixels; // Update total to reflect actual pixels that needed placing
                 }
+                self.clear_active_queue_item(item_index);
+                self.record_art_run(&art_name, pixels_placed, total_pixels);
+                self.report_item_finished(item_index, &art_name, RunItemStatus::Completed, None);
+                log_event(LogEvent::ItemCompleted {
+                    index: item_index,
+                    art: art_name.clone(),
+                    pixels_placed,
+                    total_pixels,
+                });
 
                 self.add_status_message(format!(
                     "✅ '{}' completed - {}/{} pixels placed",
@@ -70,11 +125,28 @@ impl App {
                 item_index,
                 art_name,
                 error_msg,
+                stops_queue,
             } => {
                 // Update the queue item status in our local queue
                 if let Some(item) = self.art_queue.get_mut(item_index) {
                     item.status = QueueStatus::Failed;
                 }
+                self.clear_active_queue_item(item_index);
+                // Setup failures are reported under the "Queue" name, not a real item
+                if let Some(item) = self
+                    .art_queue
+                    .get(item_index)
+                    .filter(|item| item.art.name == art_name)
+                {
+                    let (placed, total) = (item.pixels_placed, item.pixels_total);
+                    self.record_art_run(&art_name, placed, total);
+                }
+                self.report_item_finished(item_index, &art_name, RunItemStatus::Failed, Some(error_msg.clone()));
+                log_event(LogEvent::ItemFailed {
+                    index: item_index,
+                    art: art_name.clone(),
+                    error: error_msg.clone(),
+                });
 
                 self.add_status_message(format!(
                     "❌ '{}' failed - {}",
@@ -82,13 +154,16 @@ impl App {
                     error_msg
                 ));
 
-                // Reset queue processing state when an item fails
+                // Reset queue processing state when the failure ends the run
                 // This allows the queue to be restarted
-                self.queue_processing = false;
-                self.queue_processing_start = None;
-                self.queue_receiver = None;
-                self.queue_control_sender = None;
-                self.queue_paused = false;
+                if stops_queue {
+                    self.finish_run_report("failed", None);
+                    self.queue_processing = false;
+                    self.queue_processing_start = None;
+                    self.queue_receiver = None;
+                    self.queue_control_sender = None;
+                    self.queue_paused = false;
+                }
             }
             QueueUpdate::ItemSkipped {
                 item_index,
@@ -105,6 +180,13 @@ impl App {
                         item.pixels_placed = item.pixels_total;
                     }
                 }
+                self.clear_active_queue_item(item_index);
+                self.report_item_finished(item_index, &art_name, RunItemStatus::Skipped, Some(reason.clone()));
+                log_event(LogEvent::ItemSkipped {
+                    index: item_index,
+                    art: art_name.clone(),
+                    reason: reason.clone(),
+                });
 
                 self.add_status_message(format!(
                     "⏭️ '{}' skipped - {}",
@@ -123,11 +205,29 @@ impl App {
 					total_pixels_placed,
 					duration_secs
 				));
+                // Items placed this run get an integrity check once the refreshed board arrives
+                if self.integrity_threshold.is_some() {
+                    self.integrity_check_items = self
+                        .run_report
+                        .iter()
+                        .flat_map(|report| &report.items)
+                        .filter(|item| item.status == RunItemStatus::Completed)
+                        .map(|item| item.queue_index)
+                        .collect();
+                }
+                self.finish_run_report("completed", Some(total_pixels_placed));
+                log_event(LogEvent::QueueCompleted {
+                    items_processed: total_items_processed,
+                    pixels_placed: total_pixels_placed,
+                    duration_secs,
+                });
 
                 // Reset queue processing state
                 self.queue_processing = false;
                 self.queue_processing_start = None;
                 self.queue_receiver = None;
+                self.queue_active_index = None;
+                self.queue_active_position = None;
 
                 // Auto-start validation if there are completed items and validation is not already enabled
                 let completed_count = self
@@ -156,11 +256,18 @@ impl App {
 					items_processed,
 					total_pixels_placed
 				));
+                self.finish_run_report("cancelled", Some(total_pixels_placed));
+                log_event(LogEvent::QueueCancelled {
+                    items_processed,
+                    pixels_placed: total_pixels_placed,
+                });
 
                 // Reset queue processing state
                 self.queue_processing = false;
                 self.queue_processing_start = None;
                 self.queue_receiver = None;
+                self.queue_active_index = None;
+                self.queue_active_position = None;
             }
             QueueUpdate::QueuePaused {
                 item_index: _,
@@ -228,10 +335,23 @@ impl App {
         }
     }
 
-    /// Add an art to the placement queue
-    pub async fn add_art_to_queue(&mut self, art: PixelArt) {
+    /// Add an art to the placement queue; returns false if it was rejected
+    pub async fn add_art_to_queue(&mut self, art: PixelArt) -> bool {
         let meaningful_pixels = self.filter_meaningful_pixels(&art);
 
+        // Arts made for another instance may use colors this palette doesn't have;
+        // every such pixel would fail on placement, so refuse before spending API calls
+        let missing_colors = self.missing_palette_colors(&meaningful_pixels);
+        if !missing_colors.is_empty() {
+            let ids: Vec<String> = missing_colors.iter().map(|id| id.to_string()).collect();
+            self.add_status_message(format!(
+                "⚠️ '{}' uses color ids not in this board's palette: {}. Not queued - recolor it in the editor first.",
+                art.name,
+                ids.join(", ")
+            ));
+            return false;
+        }
+
         // Calculate pixels that are already correct
         let pixels_already_correct = meaningful_pixels
             .iter()
@@ -243,13 +363,16 @@ impl App {
             .count();
 
         let queue_item = ArtQueueItem {
+            id: next_queue_item_id(&self.art_queue),
             art: art.clone(),
-            priority: 3, // Default priority
+            priority: self.duplicate_priority.take().unwrap_or(3), // A duplicate keeps its source's priority
             status: QueueStatus::Pending,
             pixels_placed: 0, // Start with 0, only count actually placed pixels
             pixels_total: meaningful_pixels.len(), // Total meaningful pixels
             added_time: Instant::now(),
             paused: false, // Default to not paused
+            placement_order: self.next_placement_order,
+            selected: false,
         };
 
         self.art_queue.push(queue_item);
@@ -268,6 +391,25 @@ impl App {
             meaningful_pixels.len(),
             pixels_needing_placement
         );
+        true
+    }
+
+    /// Sorted color ids used by `pixels` that the current palette doesn't define
+    fn missing_palette_colors(&self, pixels: &[ArtPixel]) -> Vec<i32> {
+        // Without a palette there's nothing to check against yet
+        if self.colors.is_empty() {
+            return Vec::new();
+        }
+
+        let mut missing: Vec<i32> = pixels
+            .iter()
+            .map(|pixel| pixel.color)
+            .filter(|color| !self.colors.iter().any(|c| c.id == *color))
+            .collect::<HashSet<_>>()
+            .into_iter()
+            .collect();
+        missing.sort_unstable();
+        missing
     }
 
     /// Sort queue by priority (1=highest, 5=lowest)
@@ -294,6 +436,10 @@ impl App {
 
     /// Trigger non-blocking queue processing if not already in progress
     pub fn trigger_queue_processing(&mut self) {
+        if self.placement_blocked_by_spectator() {
+            return;
+        }
+
         if self.queue_processing {
             self.status_message =
                 "Queue processing already in progress. Press Esc to cancel.".to_string();
@@ -316,9 +462,29 @@ impl App {
             return;
         }
 
+        // Tell the user up front how much of the queue is already on the board
+        let (done, partial, untouched, pixels_to_place) = self.queue_start_summary();
+        self.add_status_message(format!(
+            "📋 Queue start: {} done, {} partial, {} pending ({} pixels to place)",
+            done, partial, untouched, pixels_to_place
+        ));
+        if self.force_placement {
+            self.add_status_message(
+                "⚠️ Force placement: every pixel is placed, even ones the board shows as correct"
+                    .to_string(),
+            );
+        }
+        log_event(LogEvent::QueueStarted {
+            pending_items: pending_count,
+            pixels_to_place,
+            force: self.force_placement,
+        });
+
         // Set up queue processing state
         self.queue_processing = true;
         self.queue_processing_start = Some(Instant::now());
+        self.queue_cooldown = None;
+        self.start_run_report();
 
         // Create channel for queue updates
         let (tx, rx) = mpsc::unbounded_channel();
@@ -332,6 +498,7 @@ impl App {
         let base_url = self.api_client.get_base_url();
         let access_token = self.api_client.get_access_token_clone();
         let refresh_token = self.api_client.get_refresh_token_clone();
+        let persist_tokens = self.persists_tokens();
 
         // Create or get shared reference to board state that can be updated
         let board_state = if let Some(existing_shared_board) = &self.shared_board_state {
@@ -348,10 +515,34 @@ impl App {
             .filter(|(_, item)| item.status == QueueStatus::Pending && !item.paused)
             .map(|(index, item)| (index, item.clone()))
             .collect();
+        // Placing one art (e.g. with Enter) flashes each pixel it places
+        let single_item_run = queue_items.len() == 1;
+        // Last known min_px restriction, until a placement response refreshes it
+        let known_min_px = self.user_info.as_ref().and_then(|info| info.min_px);
+        // Color ids the server accepts; refreshed with the board (empty = unknown, don't check)
+        let mut palette_ids: HashSet<i32> = self.colors.iter().map(|color| color.id).collect();
+        let interleave = self.queue_interleave;
+        let api_debug = self.api_debug;
+        let placement_concurrency = self.placement_concurrency;
+        let cooldown_strategy = self.cooldown_strategy;
+        let reserve_buffer = self.reserve_buffer;
+        let verify_placements = self.verify_placements;
+        let empty_cells_only = self.empty_cells_only;
+        let force_placement = self.force_placement;
+        // Same background colors the interactive paths skip
+        let colors = self.colors.clone();
+        let skip_rules = self.skip_rules.clone();
+        let default_color_id = detect_default_color_id(&self.board);
+        let mut pacer = PlacementPacer::new(self.polite_rate);
 
         self.status_message = format!(
-			"Starting queue processing: {} pending items (intelligent timer-based cooldown management)...",
-			pending_count
+			"Starting queue processing: {} pending items ({}, intelligent timer-based cooldown management{}{}{}{})...",
+			pending_count,
+			interleave.label().to_lowercase(),
+			if reserve_buffer > 0 { format!(", keeping {} pixels in reserve", reserve_buffer) } else { String::new() },
+			self.polite_rate.map_or(String::new(), |rate| format!(", polite: at most {} pixels/min", rate)),
+			if empty_cells_only { ", empty cells only" } else { "" },
+			if force_placement { ", FORCE: correct pixels placed too" } else { "" }
 		);
 
         // Spawn async task for queue processing
@@ -360,13 +551,15 @@ impl App {
                 crate::api_client::ApiClient::new(Some(base_url), access_token, refresh_token);
 
             // Set up callback to save refreshed tokens to storage
-            if let Ok(callback) = crate::api_client::create_token_refresh_callback(None) {
+            if let Ok(callback) = crate::api_client::session_token_refresh_callback(persist_tokens)
+            {
                 api_client.set_token_refresh_callback(callback);
             } else {
                 let _ = tx.send(QueueUpdate::ItemFailed {
                     item_index: 0,
                     art_name: "Queue".to_string(),
                     error_msg: "Failed to initialize token storage".to_string(),
+                    stops_queue: true,
                 });
                 return;
             }
@@ -375,22 +568,18 @@ impl App {
             let mut total_pixels_placed = 0;
             let start_time = Instant::now();
             let mut control_rx = control_rx; // Make it mutable
+            let mut requeued_positions = HashSet::new(); // Pixels already re-queued once by verification
 
-            for (original_index, queue_item) in queue_items {
-                // Check for cancel commands
-                while let Ok(control_cmd) = control_rx.try_recv() {
-                    match control_cmd {
-                        crate::app_state::QueueControl::Cancel => {
-                            let _ = tx.send(QueueUpdate::QueueCancelled {
-                                items_processed: processed_count,
-                                total_pixels_placed,
-                            });
-                            return;
-                        }
-                    }
-                }
+            // Sequential mode finishes each item before starting the next one, while
+            // interleaved mode runs every pending item as a single round-robin batch
+            let batches: Vec<Vec<(usize, ArtQueueItem)>> = if interleave != Interleave::Off {
+                vec![queue_items]
+            } else {
+                queue_items.into_iter().map(|item| vec![item]).collect()
+            };
 
-                // Check for cancel command during processing
+            for batch in batches {
+                // Check for cancel commands
                 while let Ok(control_cmd) = control_rx.try_recv() {
                     match control_cmd {
                         crate::app_state::QueueControl::Cancel => {
@@ -403,98 +592,285 @@ impl App {
                     }
                 }
 
-                // Send item started update
-                let _ = tx.send(QueueUpdate::ItemStarted {
-                    item_index: original_index,
-                    art_name: queue_item.art.name.clone(),
-                    total_items: processed_count + 1, // Will be corrected as we process
-                });
-
-                // Filter meaningful pixels for this art
-                let meaningful_pixels = Self::filter_meaningful_pixels_static(&queue_item.art);
-                let total_meaningful_pixels = meaningful_pixels.len();
+                let mut batch_items = Vec::new();
+                let mut run_states = Vec::new();
+                let mut pixel_lists = Vec::new();
 
-                // Count pixels already correct at start
-                let pixels_already_correct_at_start = {
-                    let board_lock = board_state.read().unwrap();
-                    meaningful_pixels
-                        .iter()
-                        .filter(|art_pixel| {
-                            let abs_x = queue_item.art.board_x + art_pixel.x;
-                            let abs_y = queue_item.art.board_y + art_pixel.y;
-                            Self::is_pixel_already_correct_static(
-                                &board_lock,
-                                abs_x,
-                                abs_y,
-                                art_pixel.color,
-                            )
-                        })
-                        .count()
-                };
-
-                // Filter pixels that need to be placed (check against current board state)
-                let pixels_to_place: Vec<_> = {
-                    let board_lock = board_state.read().unwrap();
-                    meaningful_pixels
-                        .into_iter()
-                        .enumerate()
-                        .filter(|(_, art_pixel)| {
-                            let abs_x = queue_item.art.board_x + art_pixel.x;
-                            let abs_y = queue_item.art.board_y + art_pixel.y;
-                            // Only include pixels that need to be changed
-                            !Self::is_pixel_already_correct_static(
-                                &board_lock,
-                                abs_x,
-                                abs_y,
-                                art_pixel.color,
-                            )
-                        })
-                        .collect()
-                };
-
-                if pixels_to_place.is_empty() {
-                    // Send skip update - all pixels already correct
-                    let _ = tx.send(QueueUpdate::ItemSkipped {
+                for (original_index, queue_item) in batch {
+                    // Send item started update
+                    let _ = tx.send(QueueUpdate::ItemStarted {
                         item_index: original_index,
                         art_name: queue_item.art.name.clone(),
-                        reason: "All pixels already correct".to_string(),
+                        total_items: processed_count + batch_items.len() + 1, // Will be corrected as we process
+                    });
+
+                    // Filter meaningful pixels for this art; off-board ones can never be placed
+                    let meaningful_pixels = retain_on_board_pixels(
+                        Self::filter_meaningful_pixels_for_art(
+                            &queue_item.art,
+                            &colors,
+                            &skip_rules,
+                            queue_item.placement_order,
+                        ),
+                        &queue_item.art,
+                        &board_state.read().unwrap(),
+                    );
+                    let total_meaningful_pixels = meaningful_pixels.len();
+
+                    // Count pixels already correct at start; force placement redoes them all
+                    let pixels_already_correct_at_start = if force_placement {
+                        0
+                    } else {
+                        let board_lock = board_state.read().unwrap();
+                        meaningful_pixels
+                            .iter()
+                            .filter(|art_pixel| {
+                                let abs_x = queue_item.art.board_x + art_pixel.x;
+                                let abs_y = queue_item.art.board_y + art_pixel.y;
+                                Self::is_pixel_already_correct_static(
+                                    &board_lock,
+                                    abs_x,
+                                    abs_y,
+                                    art_pixel.color,
+                                )
+                            })
+                            .count()
+                    };
+
+                    // Filter pixels that need to be placed (check against current board state)
+                    let pixels_to_place: Vec<_> = {
+                        let board_lock = board_state.read().unwrap();
+                        meaningful_pixels
+                            .into_iter()
+                            .enumerate()
+                            .filter(|(_, art_pixel)| {
+                                let abs_x = queue_item.art.board_x + art_pixel.x;
+                                let abs_y = queue_item.art.board_y + art_pixel.y;
+                                // Only include pixels that need to be changed
+                                force_placement
+                                    || !Self::is_pixel_already_correct_static(
+                                        &board_lock,
+                                        abs_x,
+                                        abs_y,
+                                        art_pixel.color,
+                                    )
+                            })
+                            .collect()
+                    };
+
+                    if pixels_to_place.is_empty() {
+                        // Send skip update - all pixels already correct
+                        let _ = tx.send(QueueUpdate::ItemSkipped {
+                            item_index: original_index,
+                            art_name: queue_item.art.name.clone(),
+                            reason: "All pixels already correct".to_string(),
+                        });
+                        continue;
+                    }
+
+                    run_states.push(ItemRunState {
+                        total_meaningful_pixels,
+                        pixels_already_correct_at_start,
+                        pixels_placed: 0, // Only count actually placed pixels
+                        remaining_pixels: pixels_to_place.len(),
+                        pixels_missing_color: 0,
+                        pixels_occupied: 0,
+                        pixels_verified: verify_placements.then_some(0),
+                        pending_verification: 0,
+                        completed: false,
                     });
-                    continue;
+                    pixel_lists.push(pixels_to_place);
+                    batch_items.push((original_index, queue_item));
                 }
 
-                let mut pixels_placed_for_item = 0; // Only count actually placed pixels
+                let priorities: Vec<u8> =
+                    batch_items.iter().map(|(_, item)| item.priority).collect();
+                let schedule = build_placement_schedule(pixel_lists, interleave, &priorities);
+
                 let mut user_info: Option<UserInfos> = None;
                 let mut pixels_placed_since_refresh = 0; // Track pixels placed since last board refresh
                 let mut last_board_refresh = Instant::now(); // Track time since last board refresh
                 const REFRESH_INTERVAL_PIXELS: usize = 10; // Refresh every 10 pixels
-                const REFRESH_INTERVAL_SECONDS: u64 = 60; // Refresh every 1 minute
+                let refresh_interval_seconds = cooldown_strategy.board_refresh_secs();
+
+                // Process each pixel that needs to be placed; pixels a parallel burst
+                // couldn't place are retried first, one at a time
+                let mut schedule = schedule.into_iter().peekable();
+                let mut deferred = VecDeque::new();
+                let mut pending_verification = Vec::new();
+                let mut unconfirmed_pixels = Vec::new(); // Placed but not seen on the board yet (no verification)
+                let mut placed_pixels = Vec::new(); // Seen on the board; re-checked on every refresh
+                let mut defense_counts = HashMap::new();
+                let mut serial_after_refresh = false; // Set when a burst placement refreshed the tokens
+                while let Some((slot, (original_pixel_index, art_pixel))) = match deferred
+                    .pop_front()
+                    .or_else(|| schedule.next())
+                {
+                    Some(next) => Some(next),
+                    // Out of pixels: read the last placements back before finishing
+                    None if !pending_verification.is_empty() => {
+                        match api_client.get_board().await {
+                            Ok(board_response) => {
+                                if let Ok(mut board_lock) = board_state.write() {
+                                    *board_lock = board_response.board;
+                                }
+                                let board_lock = board_state.read().unwrap();
+                                verify_placed_pixels(
+                                    &board_lock,
+                                    &mut pending_verification,
+                                    &mut placed_pixels,
+                                    &mut run_states,
+                                    &mut deferred,
+                                    &mut requeued_positions,
+                                    &tx,
+                                );
+                                confirm_landed_pixels(
+                                    &board_lock,
+                                    &mut unconfirmed_pixels,
+                                    &mut placed_pixels,
+                                    &run_states,
+                                );
+                                requeue_overwritten_pixels(
+                                    &board_lock,
+                                    &mut placed_pixels,
+                                    &mut run_states,
+                                    &mut deferred,
+                                    &mut defense_counts,
+                                    &tx,
+                                );
+                            }
+                            Err(_) => {
+                                let _ = tx.send(QueueUpdate::ApiCall {
+                                        message: format!(
+                                            "🔍 Could not read back {} placed pixels (board refresh failed)",
+                                            pending_verification.len()
+                                        ),
+                                    });
+                                for pending in pending_verification.drain(..) {
+                                    run_states[pending.slot].pending_verification -= 1;
+                                }
+                            }
+                        }
+                        deferred.pop_front()
+                    }
+                    None => None,
+                } {
+                    // Report items whose last pixel was handled on a previous iteration
+                    processed_count += send_finished_items(&batch_items, &mut run_states, &tx);
+
+                    // Check for cancel commands between pixels
+                    while let Ok(control_cmd) = control_rx.try_recv() {
+                        match control_cmd {
+                            crate::app_state::QueueControl::Cancel => {
+                                let _ = tx.send(QueueUpdate::QueueCancelled {
+                                    items_processed: processed_count,
+                                    total_pixels_placed,
+                                });
+                                return;
+                            }
+                        }
+                    }
+
+                    // Drop the leftover pixels of an item that failed mid-run
+                    if run_states[slot].completed {
+                        continue;
+                    }
+                    run_states[slot].remaining_pixels -= 1;
+                    let original_index = batch_items[slot].0;
+                    let queue_item = &batch_items[slot].1;
+                    let total_meaningful_pixels = run_states[slot].total_meaningful_pixels;
+                    let pixels_already_correct_at_start =
+                        run_states[slot].pixels_already_correct_at_start;
+                    let mut pixels_placed_for_item = run_states[slot].pixels_placed;
 
-                // Process each pixel that needs to be placed
-                for (_original_pixel_index, art_pixel) in pixels_to_place {
                     let abs_x = queue_item.art.board_x + art_pixel.x;
                     let abs_y = queue_item.art.board_y + art_pixel.y;
 
+                    // Skip pixels the server would reject because of the min_px restriction
+                    let min_px = user_info
+                        .as_ref()
+                        .and_then(|info| info.min_px)
+                        .or(known_min_px);
+                    if !min_px_allows_coordinate(min_px, abs_x, abs_y) {
+                        let _ = tx.send(QueueUpdate::ApiCall {
+                            message: format!(
+                                "⏭️ Skipping pixel at ({},{}): below min_px {}",
+                                abs_x,
+                                abs_y,
+                                min_px.unwrap_or(0)
+                            ),
+                        });
+                        continue;
+                    }
+
                     // Check if we need to refresh board data (every 20 pixels or 2 minutes)
                     let should_refresh = pixels_placed_since_refresh >= REFRESH_INTERVAL_PIXELS
-                        || last_board_refresh.elapsed().as_secs() >= REFRESH_INTERVAL_SECONDS;
+                        || last_board_refresh.elapsed().as_secs() >= refresh_interval_seconds;
 
                     if should_refresh {
                         // Refresh board data to detect pixels overwritten by other users
                         match api_client.get_board().await {
                             Ok(board_response) => {
+                                // Pick up palette changes so removed colors get skipped
+                                let fresh_ids: HashSet<i32> =
+                                    board_response.colors.iter().map(|color| color.id).collect();
+                                if !fresh_ids.is_empty() && fresh_ids != palette_ids {
+                                    let mut removed: Vec<i32> =
+                                        palette_ids.difference(&fresh_ids).copied().collect();
+                                    if !removed.is_empty() {
+                                        removed.sort_unstable();
+                                        let _ = tx.send(QueueUpdate::ApiCall {
+                                            message: format!(
+                                                "🎨 Palette changed: color ids {:?} are no longer available",
+                                                removed
+                                            ),
+                                        });
+                                    }
+                                    palette_ids = fresh_ids;
+                                }
+
                                 // Update shared board state
                                 if let Ok(mut board_lock) = board_state.write() {
                                     *board_lock = board_response.board;
                                 }
 
-                                // Re-check if this pixel still needs to be placed
+                                // Confirm earlier placements landed; misses go back in line
                                 let board_lock = board_state.read().unwrap();
-                                if Self::is_pixel_already_correct_static(
+                                verify_placed_pixels(
                                     &board_lock,
-                                    abs_x,
-                                    abs_y,
-                                    art_pixel.color,
-                                ) {
+                                    &mut pending_verification,
+                                    &mut placed_pixels,
+                                    &mut run_states,
+                                    &mut deferred,
+                                    &mut requeued_positions,
+                                    &tx,
+                                );
+                                confirm_landed_pixels(
+                                    &board_lock,
+                                    &mut unconfirmed_pixels,
+                                    &mut placed_pixels,
+                                    &run_states,
+                                );
+                                // Overwritten pixels jump the line, ahead of untouched ones
+                                requeue_overwritten_pixels(
+                                    &board_lock,
+                                    &mut placed_pixels,
+                                    &mut run_states,
+                                    &mut deferred,
+                                    &mut defense_counts,
+                                    &tx,
+                                );
+                                pixels_placed_for_item = run_states[slot].pixels_placed;
+
+                                // Re-check if this pixel still needs to be placed
+                                if !force_placement
+                                    && Self::is_pixel_already_correct_static(
+                                        &board_lock,
+                                        abs_x,
+                                        abs_y,
+                                        art_pixel.color,
+                                    )
+                                {
                                     // Pixel was corrected by someone else, skip it
                                     let _ = tx.send(QueueUpdate::ApiCall {
                                         message: format!(
@@ -521,10 +897,42 @@ impl App {
                         }
                     }
 
+                    // A color missing from the palette would be rejected on every retry
+                    if !palette_ids.is_empty() && !palette_ids.contains(&art_pixel.color) {
+                        run_states[slot].pixels_missing_color += 1;
+                        let _ = tx.send(QueueUpdate::ApiCall {
+                            message: format!(
+                                "⏭️ Skipping pixel at ({},{}): color {} is no longer in the palette",
+                                abs_x, abs_y, art_pixel.color
+                            ),
+                        });
+                        continue;
+                    }
+
+                    // Empty cells only: never paint over a cell another player has taken
+                    if empty_cells_only
+                        && !is_cell_free(
+                            &board_state.read().unwrap(),
+                            abs_x,
+                            abs_y,
+                            default_color_id,
+                        )
+                    {
+                        run_states[slot].pixels_occupied += 1;
+                        let _ = tx.send(QueueUpdate::ApiCall {
+                            message: format!(
+                                "🕊️ Skipping pixel at ({},{}): cell already taken (empty cells only)",
+                                abs_x, abs_y
+                            ),
+                        });
+                        continue;
+                    }
+
                     // ALWAYS check cooldown before attempting each pixel (critical fix!)
                     // This ensures we respect cooldowns from previous 425 error responses
                     if let Some(ref info) = user_info {
-                        let (should_pause, wait_time) = should_pause_queue_processing(info);
+                        let (should_pause, wait_time) =
+                            should_pause_queue_processing(info, cooldown_strategy, reserve_buffer);
 
                         if should_pause {
                             // Long cooldown detected - send pause update and wait
@@ -556,8 +964,11 @@ impl App {
 
                                         // Check if we can place now (buffer available or timers expired)
                                         if let Some(ref fresh_info) = user_info {
-                                            let fresh_wait =
-                                                calculate_cooldown_wait_time(fresh_info);
+                                            let fresh_wait = calculate_cooldown_wait_time(
+                                                fresh_info,
+                                                cooldown_strategy,
+                                                reserve_buffer,
+                                            );
                                             if fresh_wait == 0 {
                                                 // We can place now! Break out of waiting loop
                                                 let display_pixels_placed = pixels_placed_for_item
@@ -622,8 +1033,176 @@ impl App {
                         cooldown_remaining: None,
                     });
 
+                    // With spare buffer, send the next few scheduled pixels alongside this one
+                    // (never in politeness mode, which spaces every placement)
+                    let burst_size = placement_burst_size(
+                        placement_concurrency,
+                        user_info.as_ref(),
+                        reserve_buffer,
+                        pacer.is_active() || serial_after_refresh,
+                    );
+                    if burst_size > 1 {
+                        let mut burst = vec![PlacedPixel {
+                            slot,
+                            pixel_index: original_pixel_index,
+                            pixel: art_pixel,
+                            x: abs_x,
+                            y: abs_y,
+                        }];
+                        while burst.len() < burst_size {
+                            let Some((next_slot, (next_index, next_pixel))) =
+                                schedule.next_if(|(next_slot, (_, next_pixel))| {
+                                    let art = &batch_items[*next_slot].1.art;
+                                    let (next_x, next_y) =
+                                        (art.board_x + next_pixel.x, art.board_y + next_pixel.y);
+                                    // Taken cells stay for the serial path, which skips and counts them
+                                    !run_states[*next_slot].completed
+                                        && min_px_allows_coordinate(min_px, next_x, next_y)
+                                        && (palette_ids.is_empty()
+                                            || palette_ids.contains(&next_pixel.color))
+                                        && (!empty_cells_only
+                                            || is_cell_free(
+                                                &board_state.read().unwrap(),
+                                                next_x,
+                                                next_y,
+                                                default_color_id,
+                                            ))
+                                })
+                            else {
+                                break;
+                            };
+                            run_states[next_slot].remaining_pixels -= 1;
+                            let art = &batch_items[next_slot].1.art;
+                            let (next_x, next_y) =
+                                (art.board_x + next_pixel.x, art.board_y + next_pixel.y);
+                            burst.push(PlacedPixel {
+                                slot: next_slot,
+                                pixel_index: next_index,
+                                pixel: next_pixel,
+                                x: next_x,
+                                y: next_y,
+                            });
+                        }
+
+                        let _ = tx.send(QueueUpdate::ApiCall {
+                            message: format!("🎨 POST /api/set ×{} in parallel", burst.len()),
+                        });
+
+                        // Each request gets its own client; they all start from the current tokens
+                        let burst_base_url = api_client.get_base_url();
+                        let burst_tokens = api_client.get_tokens();
+                        let mut in_flight = tokio::task::JoinSet::new();
+                        let mut launched = HashMap::new();
+                        for burst_pixel in burst {
+                            let mut client = crate::api_client::ApiClient::new(
+                                Some(burst_base_url.clone()),
+                                burst_tokens.0.clone(),
+                                burst_tokens.1.clone(),
+                            );
+                            if let Ok(callback) =
+                                crate::api_client::session_token_refresh_callback(persist_tokens)
+                            {
+                                client.set_token_refresh_callback(callback);
+                            }
+                            let (x, y) = (burst_pixel.x, burst_pixel.y);
+                            let color = burst_pixel.pixel.color;
+                            let handle = in_flight.spawn(async move {
+                                let result = client.place_pixel(x, y, color).await;
+                                (result, client.get_tokens())
+                            });
+                            launched.insert(handle.id(), burst_pixel);
+                        }
+
+                        while let Some(joined) =
+                            join_next_burst(&mut in_flight, &mut launched).await
+                        {
+                            let (placed, (result, tokens)) = match joined {
+                                Ok(done) => done,
+                                Err((placed, e)) => {
+                                    // A task that died never reported; retry its pixel serially
+                                    let _ = tx.send(QueueUpdate::ApiCall {
+                                        message: format!(
+                                            "❌ Parallel pixel at ({},{}) task failed: {} - retrying it",
+                                            placed.x, placed.y, e
+                                        ),
+                                    });
+                                    defer_burst_pixel(&mut run_states, &mut deferred, placed);
+                                    continue;
+                                }
+                            };
+                            let (burst_slot, x, y) = (placed.slot, placed.x, placed.y);
+                            if tokens != burst_tokens {
+                                // Parallel refreshes race each other; stay serial until
+                                // the refreshed tokens have placed a pixel
+                                api_client.set_tokens(tokens.0, tokens.1);
+                                serial_after_refresh = true;
+                            }
+
+                            match result {
+                                Ok(response) => {
+                                    let _ = tx.send(QueueUpdate::ApiCall {
+                                        message: format!("🎨 POST /api/set ({},{}) → ✅ 200", x, y),
+                                    });
+                                    run_states[burst_slot].pixels_placed += 1;
+                                    total_pixels_placed += 1;
+                                    pixels_placed_since_refresh += 1;
+                                    if verify_placements {
+                                        run_states[burst_slot].pending_verification += 1;
+                                        pending_verification.push(placed);
+                                    } else {
+                                        unconfirmed_pixels.push(placed);
+                                    }
+                                    // Responses arrive in any order; the last one wins
+                                    user_info = Some(response.user_infos);
+                                    if single_item_run {
+                                        let _ =
+                                            tx.send(QueueUpdate::PixelPlaced { position: (x, y) });
+                                    }
+
+                                    let state = &run_states[burst_slot];
+                                    let _ = tx.send(QueueUpdate::ItemProgress {
+                                        item_index: batch_items[burst_slot].0,
+                                        art_name: batch_items[burst_slot].1.art.name.clone(),
+                                        pixels_placed: state.pixels_placed
+                                            + state.pixels_already_correct_at_start,
+                                        total_pixels: state.total_meaningful_pixels,
+                                        position: (x, y),
+                                        cooldown_remaining: None,
+                                    });
+                                    if state.pixels_placed % REFRESH_INTERVAL_PIXELS == 0 {
+                                        let _ = tx.send(QueueUpdate::ItemCheckpoint {
+                                            item_index: batch_items[burst_slot].0,
+                                            pixels_placed: state.pixels_placed
+                                                + state.pixels_already_correct_at_start,
+                                            total_pixels: state.total_meaningful_pixels,
+                                        });
+                                    }
+                                }
+                                Err(e) => {
+                                    // Cooldowns, event timing and auth errors are handled by the serial path
+                                    let _ = tx.send(QueueUpdate::ApiCall {
+                                        message: format!(
+                                            "⏳ Parallel pixel at ({},{}) deferred: {}",
+                                            x,
+                                            y,
+                                            e.status_code()
+                                                .map_or("ERR".to_string(), |code| code.to_string())
+                                        ),
+                                    });
+                                    defer_burst_pixel(&mut run_states, &mut deferred, placed);
+                                }
+                            }
+                        }
+
+                        let spare = has_spare_buffer(user_info.as_ref(), reserve_buffer);
+                        tokio::time::sleep(pixel_delay(cooldown_strategy, spare)).await;
+                        continue;
+                    }
+
                     // Attempt to place the pixel (no retries for cooldown errors)
                     loop {
+                        pacer.wait_turn().await;
+
                         // Send API call log to main thread
                         let _ = tx.send(QueueUpdate::ApiCall {
                             message: format!(
@@ -640,336 +1219,371 @@ impl App {
                                 });
 
                                 pixels_placed_for_item += 1;
+                                run_states[slot].pixels_placed = pixels_placed_for_item;
+                                serial_after_refresh = false;
                                 total_pixels_placed += 1;
                                 pixels_placed_since_refresh += 1; // Track for board refresh timing
                                 user_info = Some(response.user_infos);
+                                if single_item_run {
+                                    let _ = tx.send(QueueUpdate::PixelPlaced {
+                                        position: (abs_x, abs_y),
+                                    });
+                                }
+                                let placed = PlacedPixel {
+                                    slot,
+                                    pixel_index: original_pixel_index,
+                                    pixel: art_pixel.clone(),
+                                    x: abs_x,
+                                    y: abs_y,
+                                };
+                                if verify_placements {
+                                    run_states[slot].pending_verification += 1;
+                                    pending_verification.push(placed);
+                                } else {
+                                    unconfirmed_pixels.push(placed);
+                                }
+
+                                // Checkpoint progress periodically so it survives a crash
+                                if pixels_placed_for_item % REFRESH_INTERVAL_PIXELS == 0 {
+                                    let _ = tx.send(QueueUpdate::ItemCheckpoint {
+                                        item_index: original_index,
+                                        pixels_placed: pixels_placed_for_item
+                                            + pixels_already_correct_at_start,
+                                        total_pixels: total_meaningful_pixels,
+                                    });
+                                }
                                 break; // Successfully placed, move to next pixel
                             }
                             Err(e) => {
                                 // Send error log with status
-                                let status_text = match &e {
-                                    crate::api_client::ApiError::ErrorResponse {
-                                        status, ..
-                                    } => {
-                                        let status_emoji = match status.as_u16() {
+                                let status_text = match e.status_code() {
+                                    Some(426) => "🔄 426".to_string(),
+                                    Some(status) => {
+                                        let status_emoji = match status {
                                             400..=499 => "❌",
                                             500..=599 => "💥",
                                             _ => "❓",
                                         };
-                                        format!("{} {}", status_emoji, status.as_u16())
-                                    }
-                                    crate::api_client::ApiError::Unauthorized => {
-                                        "❌ 401".to_string()
-                                    }
-                                    crate::api_client::ApiError::TokenRefreshedPleaseRetry => {
-                                        "🔄 426".to_string()
+                                        format!("{} {}", status_emoji, status)
                                     }
-                                    _ => "💥 ERR".to_string(),
+                                    None => "💥 ERR".to_string(),
                                 };
 
                                 let _ = tx.send(QueueUpdate::ApiCall {
                                     message: format!("🎨 POST /api/set → {}", status_text),
                                 });
+                                if api_debug {
+                                    let _ = tx.send(QueueUpdate::ApiCall {
+                                        message: format!(
+                                            "🐞 POST /api/set ({}, {}): {:?}",
+                                            abs_x, abs_y, e
+                                        ),
+                                    });
+                                } else if let Some(raw_body) = e.raw_body_preview(200) {
+                                    let _ = tx.send(QueueUpdate::ApiCall {
+                                        message: format!("🐞 Raw response: {}", raw_body),
+                                    });
+                                }
 
                                 // Handle different types of errors
                                 match &e {
-                                    crate::api_client::ApiError::ErrorResponse {
-                                        status,
-                                        error_response,
+                                    crate::api_client::ApiError::EventNotStarted {
+                                        starts_in,
+                                        ..
                                     } => {
-                                        // Check if this is an event timing error (420)
-                                        if status.as_u16() == 420 {
-                                            // Handle "Enhance Your Calm - Out of event date"
-                                            let wait_time = if let Some(interval) =
-                                                error_response.interval
-                                            {
-                                                if interval > 0 {
-                                                    // Event hasn't started yet - interval is seconds until start
-
-                                                    // Send event timing update to main app
-                                                    let _ = tx.send(QueueUpdate::EventTiming {
-                                                        waiting_for_event: true,
-                                                        event_starts_in_seconds: Some(
-                                                            interval as u64,
-                                                        ),
-                                                        event_message: format!(
-                                                            "Event starts in {}",
-                                                            if interval > 3600 {
-                                                                let hours = interval / 3600;
-                                                                let minutes =
-                                                                    (interval % 3600) / 60;
-                                                                if minutes > 0 {
-                                                                    format!(
-                                                                        "{}h {}m",
-                                                                        hours, minutes
-                                                                    )
-                                                                } else {
-                                                                    format!("{}h", hours)
-                                                                }
-                                                            } else if interval > 60 {
-                                                                let minutes = interval / 60;
-                                                                let seconds = interval % 60;
-                                                                if seconds > 0 {
-                                                                    format!(
-                                                                        "{}m {}s",
-                                                                        minutes, seconds
-                                                                    )
-                                                                } else {
-                                                                    format!("{}m", minutes)
-                                                                }
-                                                            } else {
-                                                                format!("{}s", interval)
-                                                            }
-                                                        ),
-                                                    });
-
-                                                    interval as u64
-                                                } else {
-                                                    // Event has ended - interval is negative seconds since end
-                                                    // Send event ended update
-                                                    let _ = tx.send(QueueUpdate::EventTiming {
-                                                        waiting_for_event: false,
-                                                        event_starts_in_seconds: None,
-                                                        event_message: format!(
-                                                            "Event ended {} seconds ago",
-                                                            interval.abs()
-                                                        ),
-                                                    });
-
-                                                    // For ended events, we should probably stop processing
-                                                    let _ = tx.send(QueueUpdate::ItemFailed {
-                                                        item_index: original_index,
-                                                        art_name: queue_item.art.name.clone(),
-                                                        error_msg: format!(
-                                                            "Event ended {} seconds ago. Event outside active window.",
-                                                            interval.abs()
-                                                        ),
-                                                    });
-                                                    return;
-                                                }
-                                            } else {
-                                                // No interval provided - use default wait
-                                                let _ = tx.send(QueueUpdate::EventTiming {
-                                                    waiting_for_event: true,
-                                                    event_starts_in_seconds: Some(300), // Default 5 minutes
-                                                    event_message:
-                                                        "Event timing unknown, waiting 5 minutes"
-                                                            .to_string(),
-                                                });
-                                                300 // 5 minutes default
-                                            };
-
-                                            // Update display with event timing info
-                                            let display_pixels_placed = pixels_placed_for_item + pixels_already_correct_at_start;
-                                            let _ = tx.send(QueueUpdate::ItemProgress {
-                                                item_index: original_index,
-                                                art_name: queue_item.art.name.clone(),
-                                                pixels_placed: display_pixels_placed,
-                                                total_pixels: total_meaningful_pixels,
-                                                position: (abs_x, abs_y),
-                                                cooldown_remaining: Some(wait_time as u32),
+                                        // Handle "Enhance Your Calm - Out of event date"
+                                        let wait_time = if let Some(interval) = *starts_in {
+                                            // Send event timing update to main app
+                                            let _ = tx.send(QueueUpdate::EventTiming {
+                                                waiting_for_event: true,
+                                                event_starts_in_seconds: Some(interval),
+                                                event_message: format!(
+                                                    "Event starts in {}",
+                                                    if interval > 3600 {
+                                                        let hours = interval / 3600;
+                                                        let minutes = (interval % 3600) / 60;
+                                                        if minutes > 0 {
+                                                            format!("{}h {}m", hours, minutes)
+                                                        } else {
+                                                            format!("{}h", hours)
+                                                        }
+                                                    } else if interval > 60 {
+                                                        let minutes = interval / 60;
+                                                        let seconds = interval % 60;
+                                                        if seconds > 0 {
+                                                            format!("{}m {}s", minutes, seconds)
+                                                        } else {
+                                                            format!("{}m", minutes)
+                                                        }
+                                                    } else {
+                                                        format!("{}s", interval)
+                                                    }
+                                                ),
                                             });
 
-                                            // For very long waits (over 10 minutes), check periodically if event started
-                                            if wait_time > 600 {
-                                                let mut total_waited = 0u64;
-                                                while total_waited < wait_time {
-                                                    let wait_chunk = std::cmp::min(300, wait_time - total_waited); // Check every 5 minutes
-                                                    tokio::time::sleep(Duration::from_secs(wait_chunk)).await;
-                                                    total_waited += wait_chunk;
-
-                                                    // Try a quick test placement to see if event started
-                                                    let test_result = api_client.place_pixel(abs_x, abs_y, art_pixel.color).await;
-                                                    match test_result {
-                                                        Ok(_) => {
-                                                            // Event started! Continue with normal placement
-                                                            let _ = tx.send(QueueUpdate::EventTiming {
-                                                                waiting_for_event: false,
-                                                                event_starts_in_seconds: None,
-                                                                event_message: "Event started! Resuming placement".to_string(),
-                                                            });
-                                                            let _ = tx.send(QueueUpdate::ApiCall {
-                                                                message: "🎉 Event started! Resuming placement...".to_string(),
-                                                            });
-                                                            break;
-                                                        }
-                                                        Err(crate::api_client::ApiError::ErrorResponse { status, error_response }) 
-                                                            if status.as_u16() == 420 => 
-                                                        {
-                                                            // Still waiting for event - update countdown
-                                                            if let Some(new_interval) = error_response.interval {
-                                                                if new_interval > 0 {
-                                                                    let remaining_wait = new_interval as u64;
-                                                                    
-                                                                    // Update event timing
-                                                                    let _ = tx.send(QueueUpdate::EventTiming {
-                                                                        waiting_for_event: true,
-                                                                        event_starts_in_seconds: Some(remaining_wait),
-                                                                        event_message: format!("Event starts in {}", 
-                                                                            if remaining_wait > 60 {
-                                                                                let minutes = remaining_wait / 60;
-                                                                                format!("{}m", minutes)
-                                                                            } else {
-                                                                                format!("{}s", remaining_wait)
-                                                                            }
-                                                                        ),
-                                                                    });
-                                                                    
-                                                                    let _ = tx.send(QueueUpdate::ItemProgress {
-                                                                        item_index: original_index,
-                                                                        art_name: queue_item.art.name.clone(),
-                                                                        pixels_placed: display_pixels_placed,
-                                                                        total_pixels: total_meaningful_pixels,
-                                                                        position: (abs_x, abs_y),
-                                                                        cooldown_remaining: Some(remaining_wait as u32),
-                                                                    });
-                                                                    continue; // Continue waiting with updated time
+                                            interval
+                                        } else {
+                                            // No interval provided - use default wait
+                                            let _ = tx.send(QueueUpdate::EventTiming {
+                                                waiting_for_event: true,
+                                                event_starts_in_seconds: Some(300), // Default 5 minutes
+                                                event_message:
+                                                    "Event timing unknown, waiting 5 minutes"
+                                                        .to_string(),
+                                            });
+                                            300 // 5 minutes default
+                                        };
+
+                                        // Update display with event timing info
+                                        let display_pixels_placed = pixels_placed_for_item
+                                            + pixels_already_correct_at_start;
+                                        let _ = tx.send(QueueUpdate::ItemProgress {
+                                            item_index: original_index,
+                                            art_name: queue_item.art.name.clone(),
+                                            pixels_placed: display_pixels_placed,
+                                            total_pixels: total_meaningful_pixels,
+                                            position: (abs_x, abs_y),
+                                            cooldown_remaining: Some(wait_time as u32),
+                                        });
+
+                                        // For very long waits (over 10 minutes), check periodically if event started
+                                        if wait_time > 600 {
+                                            let mut total_waited = 0u64;
+                                            while total_waited < wait_time {
+                                                let wait_chunk =
+                                                    std::cmp::min(300, wait_time - total_waited); // Check every 5 minutes
+                                                tokio::time::sleep(Duration::from_secs(wait_chunk))
+                                                    .await;
+                                                total_waited += wait_chunk;
+
+                                                // Try a quick test placement to see if event started
+                                                let test_result = api_client
+                                                    .place_pixel(abs_x, abs_y, art_pixel.color)
+                                                    .await;
+                                                match test_result {
+                                                    Ok(_) => {
+                                                        // Event started! Continue with normal placement
+                                                        let _ = tx.send(QueueUpdate::EventTiming {
+                                                            waiting_for_event: false,
+                                                            event_starts_in_seconds: None,
+                                                            event_message: "Event started! Resuming placement".to_string(),
+                                                        });
+                                                        let _ = tx.send(QueueUpdate::ApiCall {
+                                                            message: "🎉 Event started! Resuming placement...".to_string(),
+                                                        });
+                                                        break;
+                                                    }
+                                                    Err(crate::api_client::ApiError::EventNotStarted { starts_in: Some(remaining_wait), .. }) => {
+                                                        // Still waiting for event - update countdown
+                                                        let _ = tx.send(QueueUpdate::EventTiming {
+                                                            waiting_for_event: true,
+                                                            event_starts_in_seconds: Some(remaining_wait),
+                                                            event_message: format!("Event starts in {}", 
+                                                                if remaining_wait > 60 {
+                                                                    let minutes = remaining_wait / 60;
+                                                                    format!("{}m", minutes)
                                                                 } else {
-                                                                    // Event ended while we were waiting
-                                                                    let _ = tx.send(QueueUpdate::EventTiming {
-                                                                        waiting_for_event: false,
-                                                                        event_starts_in_seconds: None,
-                                                                        event_message: format!("Event ended {} seconds ago", new_interval.abs()),
-                                                                    });
-                                                                    let _ = tx.send(QueueUpdate::ItemFailed {
-                                                                        item_index: original_index,
-                                                                        art_name: queue_item.art.name.clone(),
-                                                                        error_msg: "Event ended while waiting. Event outside active window.".to_string(),
-                                                                    });
-                                                                    return;
+                                                                    format!("{}s", remaining_wait)
                                                                 }
-                                                            }
-                                                        }
-                                                        Err(_) => {
-                                                            // Some other error - might be auth, might be network
-                                                            // Don't break the wait, just continue
-                                                            continue;
-                                                        }
+                                                            ),
+                                                        });
+
+                                                        let _ = tx.send(QueueUpdate::ItemProgress {
+                                                            item_index: original_index,
+                                                            art_name: queue_item.art.name.clone(),
+                                                            pixels_placed: display_pixels_placed,
+                                                            total_pixels: total_meaningful_pixels,
+                                                            position: (abs_x, abs_y),
+                                                            cooldown_remaining: Some(remaining_wait as u32),
+                                                        });
+                                                        continue; // Continue waiting with updated time
                                                     }
-                                                }
-                                            } else {
-                                                // Short wait - just wait the full duration
-                                                tokio::time::sleep(Duration::from_secs(wait_time)).await;
-                                            }
-
-                                            // Continue to retry pixel placement after waiting
-                                            continue;
-                                        }
-                                        // Check if this is a regular cooldown/rate limit error
-                                        else if *status == reqwest::StatusCode::TOO_MANY_REQUESTS
-                                            || status.as_u16() == 425
-                                        // Too Early
-                                        {
-                                            // Update user info with new timers from error response
-                                            if let Some(timers) = &error_response.timers {
-                                                if let Some(ref mut info) = user_info {
-                                                    info.timers = Some(timers.clone());
-                                                    // Also update pixel_timer if available
-                                                    if let Some(interval) = error_response.interval
-                                                    {
-                                                        info.pixel_timer = interval as i32;
+                                                    Err(crate::api_client::ApiError::EventClosed { interval, .. }) => {
+                                                        // Event ended while we were waiting
+                                                        let _ = tx.send(QueueUpdate::EventTiming {
+                                                            waiting_for_event: false,
+                                                            event_starts_in_seconds: None,
+                                                            event_message: format!("Event ended {} seconds ago", interval.abs()),
+                                                        });
+                                                        let _ = tx.send(QueueUpdate::ItemFailed {
+                                                            item_index: original_index,
+                                                            art_name: queue_item.art.name.clone(),
+                                                            error_msg: "Event ended while waiting. Event outside active window.".to_string(),
+                                                            stops_queue: true,
+                                                        });
+                                                        return;
+                                                    }
+                                                    Err(_) => {
+                                                        // Some other error - might be auth, might be network
+                                                        // Don't break the wait, just continue
+                                                        continue;
                                                     }
-                                                } else {
-                                                    // Create minimal user info if we don't have it
-                                                    user_info = Some(UserInfos {
-                                                        timers: Some(timers.clone()),
-                                                        pixel_buffer: 0,
-                                                        pixel_timer: error_response
-                                                            .interval
-                                                            .unwrap_or(5000)
-                                                            as i32,
-                                                        id: None,
-                                                        username: None,
-                                                        soft_is_admin: None,
-                                                        soft_is_banned: None,
-                                                        num: None,
-                                                        min_px: None,
-                                                        campus_name: None,
-                                                        iat: None,
-                                                        exp: None,
-                                                    });
                                                 }
                                             }
-
-                                            // For cooldown errors, wait for cooldown and retry
-                                            let wait_time = if let Some(ref info) = user_info {
-                                                let calculated_wait =
-                                                    calculate_cooldown_wait_time(info);
-                                                // For 425 errors, if calculated time is very small, it means
-                                                // the timer calculation failed - use a longer fallback
-                                                if calculated_wait < 5 {
-                                                    30 // 30 seconds when calculation seems wrong
-                                                } else {
-                                                    calculated_wait
-                                                }
-                                            } else {
-                                                30 // Default 30 seconds if no user info
-                                            };
-
-                                            let display_pixels_placed = pixels_placed_for_item
-                                                + pixels_already_correct_at_start;
-                                            let _ = tx.send(QueueUpdate::ItemProgress {
-                                                item_index: original_index,
-                                                art_name: queue_item.art.name.clone(),
-                                                pixels_placed: display_pixels_placed,
-                                                total_pixels: total_meaningful_pixels,
-                                                position: (abs_x, abs_y),
-                                                cooldown_remaining: Some(wait_time as u32),
-                                            });
-
-                                            // Wait for the full cooldown period
+                                        } else {
+                                            // Short wait - just wait the full duration
                                             tokio::time::sleep(Duration::from_secs(wait_time))
                                                 .await;
-                                            // Continue to retry after waiting
-                                            continue;
-                                        } else {
-                                            // Other API errors (auth, server error, etc.) - stop processing
-                                            let _ = tx.send(QueueUpdate::ItemFailed {
-                                                item_index: original_index,
-                                                art_name: queue_item.art.name.clone(),
-                                                error_msg: error_response.message.clone(),
-                                            });
-                                            return;
                                         }
+
+                                        // Continue to retry pixel placement after waiting
+                                        continue;
                                     }
-                                    crate::api_client::ApiError::Unauthorized => {
-                                        // Auth error - stop processing
-                                        let _ = tx.send(QueueUpdate::ItemFailed {
-                                            item_index: original_index,
-                                            art_name: queue_item.art.name.clone(),
-                                            error_msg: "Unauthorized - check tokens".to_string(),
+                                    crate::api_client::ApiError::EventClosed {
+                                        interval, ..
+                                    } => {
+                                        // Event has ended - interval is negative seconds since end
+                                        // Send event ended update
+                                        let _ = tx.send(QueueUpdate::EventTiming {
+                                            waiting_for_event: false,
+                                            event_starts_in_seconds: None,
+                                            event_message: format!(
+                                                "Event ended {} seconds ago",
+                                                interval.abs()
+                                            ),
                                         });
-                                        return;
-                                    }
-                                    _ => {
-                                        // Other errors (network, etc.) - stop processing
+
+                                        // For ended events, we should probably stop processing
                                         let _ = tx.send(QueueUpdate::ItemFailed {
                                             item_index: original_index,
                                             art_name: queue_item.art.name.clone(),
-                                            error_msg: format!("{:?}", e),
+                                            error_msg: format!(
+                                                "Event ended {} seconds ago. Event outside active window.",
+                                                interval.abs()
+                                            ),
+                                            stops_queue: true,
                                         });
                                         return;
                                     }
-                                }
-                            }
-                        }
-                    }
-
-                    // Small delay between pixels
-                    tokio::time::sleep(Duration::from_millis(100)).await;
-                }
+                                    crate::api_client::ApiError::RateLimited {
+                                        retry_after,
+                                        error_response,
+                                        ..
+                                    } => {
+                                        // Update user info with new timers from error response
+                                        if let Some(timers) = &error_response.timers {
+                                            if let Some(ref mut info) = user_info {
+                                                info.timers = Some(timers.clone());
+                                                // Also update pixel_timer if available
+                                                if let Some(interval) = *retry_after {
+                                                    info.pixel_timer = interval as i32;
+                                                }
+                                            } else {
+                                                // Create minimal user info if we don't have it
+                                                user_info = Some(UserInfos {
+                                                    timers: Some(timers.clone()),
+                                                    pixel_buffer: 0,
+                                                    pixel_timer: retry_after.unwrap_or(5000) as i32,
+                                                    id: None,
+                                                    username: None,
+                                                    soft_is_admin: None,
+                                                    soft_is_banned: None,
+                                                    num: None,
+                                                    min_px: None,
+                                                    campus_name: None,
+                                                    iat: None,
+                                                    exp: None,
+                                                });
+                                            }
+                                        }
 
-                // Send item completion update
-                let display_pixels_placed =
-                    pixels_placed_for_item + pixels_already_correct_at_start;
-                let _ = tx.send(QueueUpdate::ItemCompleted {
-                    item_index: original_index,
-                    art_name: queue_item.art.name.clone(),
-                    pixels_placed: display_pixels_placed,
-                    total_pixels: total_meaningful_pixels,
-                });
+                                        // For cooldown errors, wait for cooldown and retry
+                                        let wait_time = if let Some(ref info) = user_info {
+                                            let calculated_wait = calculate_cooldown_wait_time(
+                                                info,
+                                                cooldown_strategy,
+                                                reserve_buffer,
+                                            );
+                                            // For 425 errors, if calculated time is very small, it means
+                                            // the timer calculation failed - use a longer fallback
+                                            if calculated_wait < 5 {
+                                                30 // 30 seconds when calculation seems wrong
+                                            } else {
+                                                calculated_wait
+                                            }
+                                        } else {
+                                            30 // Default 30 seconds if no user info
+                                        };
+
+                                        let display_pixels_placed = pixels_placed_for_item
+                                            + pixels_already_correct_at_start;
+                                        let _ = tx.send(QueueUpdate::ItemProgress {
+                                            item_index: original_index,
+                                            art_name: queue_item.art.name.clone(),
+                                            pixels_placed: display_pixels_placed,
+                                            total_pixels: total_meaningful_pixels,
+                                            position: (abs_x, abs_y),
+                                            cooldown_remaining: Some(wait_time as u32),
+                                        });
 
-                processed_count += 1;
+                                        // Wait for the full cooldown period
+                                        tokio::time::sleep(Duration::from_secs(wait_time)).await;
+                                        // Continue to retry after waiting
+                                        continue;
+                                    }
+                                    crate::api_client::ApiError::ErrorResponse {
+                                        error_response,
+                                        ..
+                                    } => {
+                                        // Other API errors (server error, etc.) - stop this item;
+                                        // interleaved runs carry on with the other items
+                                        let stops_queue = interleave == Interleave::Off;
+                                        let _ = tx.send(QueueUpdate::ItemFailed {
+                                            item_index: original_index,
+                                            art_name: queue_item.art.name.clone(),
+                                            error_msg: error_response.message.clone(),
+                                            stops_queue,
+                                        });
+                                        if stops_queue {
+                                            return;
+                                        }
+                                        run_states[slot].completed = true;
+                                        break;
+                                    }
+                                    crate::api_client::ApiError::Unauthorized { login_page } => {
+                                        // Auth error - stop processing
+                                        let _ = tx.send(QueueUpdate::ItemFailed {
+                                            item_index: original_index,
+                                            art_name: queue_item.art.name.clone(),
+                                            error_msg: if *login_page {
+                                                format!(
+                                                    "Unauthorized - {}",
+                                                    crate::api_client::LOGIN_PAGE_HINT
+                                                )
+                                            } else {
+                                                "Unauthorized - check tokens".to_string()
+                                            },
+                                            stops_queue: true,
+                                        });
+                                        return;
+                                    }
+                                    _ => {
+                                        // Other errors (network, etc.) - stop this item, as above
+                                        let stops_queue = interleave == Interleave::Off;
+                                        let _ = tx.send(QueueUpdate::ItemFailed {
+                                            item_index: original_index,
+                                            art_name: queue_item.art.name.clone(),
+                                            error_msg: e.summary(),
+                                            stops_queue,
+                                        });
+                                        if stops_queue {
+                                            return;
+                                        }
+                                        run_states[slot].completed = true;
+                                        break;
+                                    }
+                                }
+                            }
+                        }
+                    }
+
+                    // Small delay between pixels once the buffer is down to the reserve
+                    let spare = has_spare_buffer(user_info.as_ref(), reserve_buffer);
+                    tokio::time::sleep(pixel_delay(cooldown_strategy, spare)).await;
+                }
+
+                // Report items finished by the last pixels of this batch
+                processed_count += send_finished_items(&batch_items, &mut run_states, &tx);
             }
 
             // Send queue completion update
@@ -1047,12 +1661,32 @@ impl App {
         self.toggle_queue_item_pause(self.queue_selection_index);
     }
 
+    /// Cycle the placement order of the selected item (used from the next queue start)
+    pub fn cycle_selected_item_placement_order(&mut self) {
+        let Some(item) = self.art_queue.get_mut(self.queue_selection_index) else {
+            return;
+        };
+        item.placement_order = item.placement_order.next();
+        self.status_message = format!(
+            "'{}' will be placed {}{}.",
+            item.art.name,
+            item.placement_order.label(),
+            if self.queue_processing {
+                " from the next queue start"
+            } else {
+                ""
+            }
+        );
+        let _ = self.save_queue();
+    }
+
     /// Recalculate queue totals based on current board state
     /// Call this after board refreshes to update pixel counts
     pub fn recalculate_queue_totals(&mut self) {
         // Clone the board and colors to avoid borrowing issues
         let board = self.board.clone();
         let colors = self.colors.clone();
+        let skip_rules = self.skip_rules.clone();
 
         for item in &mut self.art_queue {
             // Only recalculate for pending items
@@ -1061,7 +1695,16 @@ impl App {
             }
 
             // Filter meaningful pixels using static method to avoid borrowing self
-            let meaningful_pixels = Self::filter_meaningful_pixels_for_art(&item.art, &colors);
+            let meaningful_pixels = retain_on_board_pixels(
+                Self::filter_meaningful_pixels_for_art(
+                    &item.art,
+                    &colors,
+                    &skip_rules,
+                    item.placement_order,
+                ),
+                &item.art,
+                &board,
+            );
             let pixels_already_correct = meaningful_pixels
                 .iter()
                 .filter(|art_pixel| {
@@ -1084,47 +1727,140 @@ impl App {
         }
     }
 
-    /// Static helper for filtering meaningful pixels (used in spawned tasks)
-    fn filter_meaningful_pixels_static(art: &PixelArt) -> Vec<ArtPixel> {
-        let mut meaningful_pixels = Vec::new();
-        let mut seen_positions = HashSet::new();
+    /// Classify runnable items against the current board: (done, partial, untouched, pixels to place)
+    fn queue_start_summary(&self) -> (usize, usize, usize, usize) {
+        let mut summary = (0, 0, 0, 0);
+        for item in &self.art_queue {
+            if item.status != QueueStatus::Pending || item.paused {
+                continue;
+            }
 
-        // For now, include all pixels since we can't access colors from static context
-        // This could be improved by passing color filtering rules to the spawned task
-        for pixel in &art.pattern {
-            let position = (pixel.x, pixel.y);
-            if seen_positions.contains(&position) {
+            let meaningful_pixels = retain_on_board_pixels(
+                Self::filter_meaningful_pixels_for_art(
+                    &item.art,
+                    &self.colors,
+                    &self.skip_rules,
+                    item.placement_order,
+                ),
+                &item.art,
+                &self.board,
+            );
+            let pixels_correct = meaningful_pixels
+                .iter()
+                .filter(|art_pixel| {
+                    let abs_x = item.art.board_x + art_pixel.x;
+                    let abs_y = item.art.board_y + art_pixel.y;
+                    Self::is_pixel_already_correct_static(
+                        &self.board,
+                        abs_x,
+                        abs_y,
+                        art_pixel.color,
+                    )
+                })
+                .count();
+
+            if self.force_placement {
+                summary.2 += 1;
+                summary.3 += meaningful_pixels.len();
                 continue;
             }
-            meaningful_pixels.push(pixel.clone());
-            seen_positions.insert(position);
+            if pixels_correct == meaningful_pixels.len() {
+                summary.0 += 1;
+            } else if pixels_correct > 0 {
+                summary.1 += 1;
+            } else {
+                summary.2 += 1;
+            }
+            summary.3 += meaningful_pixels.len() - pixels_correct;
         }
+        summary
+    }
+
+    /// "Queue: 2/5 done, 340 px left, next: 'Logo' @ (120,88)" while the queue runs
+    pub fn queue_summary_line(&self) -> Option<String> {
+        if !self.queue_processing || self.art_queue.is_empty() {
+            return None;
+        }
+
+        let done = self
+            .art_queue
+            .iter()
+            .filter(|item| item.status == QueueStatus::Complete)
+            .count();
+        let pixels_left: usize = self
+            .art_queue
+            .iter()
+            .filter(|item| matches!(item.status, QueueStatus::Pending | QueueStatus::InProgress))
+            .map(|item| item.pixels_total.saturating_sub(item.pixels_placed))
+            .sum();
+
+        // The item being placed right now, else the first one waiting
+        let next = match self
+            .queue_active_index
+            .and_then(|index| self.art_queue.get(index))
+        {
+            Some(item) => Some((
+                item,
+                self.queue_active_position
+                    .unwrap_or((item.art.board_x, item.art.board_y)),
+            )),
+            None => self
+                .art_queue
+                .iter()
+                .find(|item| item.status == QueueStatus::Pending && !item.paused)
+                .map(|item| (item, (item.art.board_x, item.art.board_y))),
+        };
 
-        // Apply border-first ordering
-        order_pixels_border_first(meaningful_pixels)
+        Some(format!(
+            "Queue: {}/{} done, {} px left{}",
+            done,
+            self.art_queue.len(),
+            pixels_left,
+            next.map(|(item, (x, y))| format!(", next: '{}' @ ({},{})", item.art.name, x, y))
+                .unwrap_or_default()
+        ))
     }
 
-    /// Static helper for filtering meaningful pixels with color filtering
+    /// "Cooldown: 12m04s left (waited 3m10s)", ticking locally between queue updates
+    pub fn queue_cooldown_countdown(&self) -> Option<String> {
+        if !self.queue_processing {
+            return None;
+        }
+        let (started, deadline) = self.queue_cooldown?;
+        let now = Instant::now();
+        let format_secs = |secs: u64| {
+            if secs >= 60 {
+                format!("{}m{:02}s", secs / 60, secs % 60)
+            } else {
+                format!("{}s", secs)
+            }
+        };
+
+        let remaining = deadline.saturating_duration_since(now);
+        let left = if remaining.is_zero() {
+            "re-checking...".to_string()
+        } else {
+            format!("{} left", format_secs(remaining.as_secs() + 1))
+        };
+        Some(format!(
+            "Cooldown: {} (waited {})",
+            left,
+            format_secs(now.duration_since(started).as_secs())
+        ))
+    }
+
+    /// Static helper for filtering meaningful pixels with the skip rules (used in spawned tasks)
     fn filter_meaningful_pixels_for_art(
         art: &PixelArt,
         colors: &[crate::api_client::ColorInfo],
+        rules: &SkipRules,
+        order: PlacementOrder,
     ) -> Vec<ArtPixel> {
         let mut meaningful_pixels = Vec::new();
         let mut seen_positions = HashSet::new();
 
         // Define background color IDs that should not be placed
-        let mut background_color_ids = HashSet::new();
-        for color in colors {
-            let name_lower = color.name.to_lowercase();
-            if name_lower.contains("transparent")
-                || name_lower.contains("background")
-                || name_lower.contains("empty")
-                || name_lower == "none"
-                || name_lower.contains("alpha")
-            {
-                background_color_ids.insert(color.id);
-            }
-        }
+        let background_color_ids = rules.background_color_ids(colors);
 
         for pixel in &art.pattern {
             // Skip if this position was already processed (remove duplicates)
@@ -1142,8 +1878,8 @@ impl App {
             seen_positions.insert(position);
         }
 
-        // Apply border-first ordering
-        order_pixels_border_first(meaningful_pixels)
+        // Apply the item's placement order
+        order_pixels(meaningful_pixels, order)
     }
 
     /// Static helper for checking if a pixel is already correct
@@ -1250,6 +1986,10 @@ impl App {
         art: &PixelArt,
         queue_index: usize,
     ) -> Result<usize, String> {
+        if self.placement_blocked_by_spectator() {
+            return Err("Spectator mode: placement is disabled".to_string());
+        }
+
         let meaningful_pixels = self.filter_meaningful_pixels(art);
 
         if meaningful_pixels.is_empty() {
@@ -1303,26 +2043,15 @@ impl App {
                     self.user_info = Some(response.user_infos);
                 }
                 Err(e) => {
-                    // Log API error with status code
-                    match &e {
-                        crate::api_client::ApiError::ErrorResponse { status, .. } => {
-                            self.log_api_call("POST", "/api/set", Some(status.as_u16()));
-                        }
-                        crate::api_client::ApiError::Unauthorized => {
-                            self.log_api_call("POST", "/api/set", Some(401));
-                        }
-                        _ => {
-                            // For network errors or other issues, log without status
-                            self.log_api_call("POST", "/api/set", None);
-                        }
-                    }
+                    // Log API error with status code (None for network errors)
+                    self.log_api_call("POST", "/api/set", e.status_code());
 
-                    return Err(format!("API error: {:?}", e));
+                    return Err(format!("API error: {}", e.summary()));
                 }
             }
 
-            // Small delay between pixels
-            tokio::time::sleep(Duration::from_millis(100)).await;
+            // Small delay between pixels once the buffer is spent
+            pause_between_pixels(self.user_info.as_ref(), self.reserve_buffer).await;
         }
 
         Ok(pixels_placed)
@@ -1340,35 +2069,113 @@ impl App {
             let _ = sender.send(crate::app_state::QueueControl::Cancel);
         }
 
+        // Dropping the receiver means the task's own cancel update never arrives
+        let (items_processed, pixels_placed) = self
+            .finish_run_report("cancelled", None)
+            .unwrap_or_default();
+        log_event(LogEvent::QueueCancelled {
+            items_processed,
+            pixels_placed,
+        });
+
         // Reset queue processing state
         self.queue_processing = false;
         self.queue_paused = false;
         self.queue_receiver = None;
         self.queue_control_sender = None;
+        self.queue_active_index = None;
+        self.queue_active_position = None;
         self.status_message = "Queue processing cancelled.".to_string();
     }
 
+    /// Quit, asking first when that would abandon a running queue
+    pub fn request_quit(&mut self) {
+        if self.queue_processing {
+            self.input_mode = crate::app_state::InputMode::QuitConfirm;
+            self.status_message = "Queue is running - quit anyway? (y/n)".to_string();
+        } else {
+            self.exit = true;
+        }
+    }
+
+    /// Stop the running queue, save it for the next start, then quit
+    pub fn cancel_queue_and_quit(&mut self) {
+        self.cancel_queue_processing();
+        if let Err(e) = self.save_queue() {
+            eprintln!("Warning: Could not save queue: {}", e);
+        }
+        self.exit = true;
+    }
+
+    /// Drop the in-progress highlight once the task is done with `item_index`
+    fn clear_active_queue_item(&mut self, item_index: usize) {
+        if self.queue_active_index == Some(item_index) {
+            self.queue_active_index = None;
+            self.queue_active_position = None;
+        }
+    }
+
     /// Save queue to file
     pub fn save_queue(&self) -> Result<(), Box<dyn std::error::Error>> {
         // Create queue directory if it doesn't exist
-        std::fs::create_dir_all("queue")?;
+        std::fs::create_dir_all(crate::paths::data_path("queue"))?;
 
-        let queue_data = serde_json::to_string_pretty(&self.art_queue)?;
-        std::fs::write("queue/queue.json", queue_data)?;
+        // Pretty JSON stays the default so the file can be inspected by hand
+        let queue_data = if self.compress_saved_files {
+            serde_json::to_string(&self.art_queue)?
+        } else {
+            serde_json::to_string_pretty(&self.art_queue)?
+        };
+        super::helpers::write_json_file(
+            &crate::paths::data_path("queue/queue.json"),
+            &queue_data,
+            self.compress_saved_files,
+        )?;
         Ok(())
     }
 
     /// Load queue from file
     pub fn load_queue(&mut self) -> Result<(), Box<dyn std::error::Error>> {
-        if std::path::Path::new("queue/queue.json").exists() {
-            let queue_data = std::fs::read_to_string("queue/queue.json")?;
-            self.art_queue = serde_json::from_str(&queue_data)?;
+        let queue_path = crate::paths::data_path("queue/queue.json");
+        if queue_path.exists() {
+            // A truncated gzip stream or invalid UTF-8 is as corrupt as bad JSON
+            let loaded = super::helpers::read_json_file(&queue_path).and_then(|queue_data| {
+                Ok(serde_json::from_str::<Vec<ArtQueueItem>>(&queue_data)?)
+            });
+            self.art_queue = match loaded {
+                Ok(queue) => queue,
+                Err(e) => {
+                    // Keep the broken file around instead of overwriting it on the next save
+                    let message = match super::helpers::backup_corrupt_file(&queue_path) {
+                        Ok(backup_path) => format!(
+                            "⚠️ Saved queue was unreadable ({}). Backed it up to {} and started with an empty queue.",
+                            e,
+                            backup_path.display()
+                        ),
+                        Err(backup_error) => format!(
+                            "⚠️ Saved queue was unreadable ({}) and could not be backed up: {}",
+                            e, backup_error
+                        ),
+                    };
+                    self.add_status_message(message.clone());
+                    self.status_message = message;
+                    return Ok(());
+                }
+            };
+
+            normalize_loaded_queue(&mut self.art_queue);
+            self.remap_offline_queue_arts();
 
             let pending_count = self
                 .art_queue
                 .iter()
                 .filter(|item| item.status == QueueStatus::Pending && !item.paused)
                 .count();
+            let failed_count = self
+                .art_queue
+                .iter()
+                .filter(|item| item.status == QueueStatus::Failed)
+                .count();
 
             if pending_count > 0 {
                 self.status_message = format!(
@@ -1380,10 +2187,220 @@ impl App {
                 self.status_message =
                     format!("Loaded {} items from saved queue.", self.art_queue.len());
             }
+            if failed_count > 0 {
+                self.status_message.push_str(&format!(
+                    " {} failed - press F in the queue (w) to retry them.",
+                    failed_count
+                ));
+            }
         }
         Ok(())
     }
 
+    /// Remap queued arts drawn offline to the server palette once one is known. They
+    /// still hold the built-in palette's ids, which name other colors on a real board.
+    pub fn remap_offline_queue_arts(&mut self) {
+        let palette = self.art_palette().to_vec();
+        if palette.is_empty() {
+            return;
+        }
+        let mut remapped = 0;
+        for item in &mut self.art_queue {
+            if item.art.default_palette {
+                crate::art::adapt_to_palette(&mut item.art, &palette);
+                remapped += 1;
+            }
+        }
+        if remapped > 0 {
+            let _ = self.save_queue();
+            self.add_status_message(format!(
+                "🎨 Remapped {} queued offline art(s) to the board palette",
+                remapped
+            ));
+        }
+    }
+
+    /// Load the selected queue item for positioning; Enter moves it instead of adding a copy
+    pub fn move_selected_queue_item(&mut self) {
+        // The running task works on its own copy of the queue
+        if self.queue_processing {
+            self.status_message = "Can't move queue items while the queue is running.".to_string();
+            return;
+        }
+        let Some(item) = self.art_queue.get(self.queue_selection_index) else {
+            self.status_message = "No queue item selected to move.".to_string();
+            return;
+        };
+
+        // Indices shift when the queue is re-sorted, so remember the item by its id
+        let art = item.art.clone();
+        self.moving_queue_item = Some(item.id);
+        self.duplicate_priority = None;
+        self.status_message = format!(
+            "Moving '{}' from ({}, {}). Move it with arrows/mouse, Enter to update the item, Esc to cancel.",
+            art.name, art.board_x, art.board_y
+        );
+        self.loaded_art = Some(art);
+        self.input_mode = crate::app_state::InputMode::None;
+    }
+
+    /// Apply the loaded art's position to the queue item being moved
+    pub fn finish_queue_item_move(&mut self) {
+        let Some(item_id) = self.moving_queue_item.take() else {
+            return;
+        };
+        let Some(art) = self.loaded_art.take() else {
+            return;
+        };
+
+        let Some(index) = self
+            .art_queue
+            .iter()
+            .position(|item| item.id == item_id)
+        else {
+            self.add_status_message(format!(
+                "Queue item '{}' no longer exists - nothing moved.",
+                art.name
+            ));
+            return;
+        };
+
+        let pixels_total = self.filter_meaningful_pixels(&art).len();
+        let item = &mut self.art_queue[index];
+        let from = (item.art.board_x, item.art.board_y);
+        item.art.board_x = art.board_x;
+        item.art.board_y = art.board_y;
+        // A new spot means starting over on it
+        item.pixels_placed = 0;
+        item.pixels_total = pixels_total;
+        item.status = QueueStatus::Pending;
+        let _ = self.save_queue();
+
+        self.queue_selection_index = index;
+        self.add_status_message(format!(
+            "📦 Moved '{}' from ({}, {}) to ({}, {}).",
+            art.name, from.0, from.1, art.board_x, art.board_y
+        ));
+    }
+
+    /// Load a copy of the selected queue item for positioning; Enter queues it as a new item
+    pub fn duplicate_selected_queue_item(&mut self) {
+        let Some(item) = self.art_queue.get(self.queue_selection_index) else {
+            self.status_message = "No queue item selected to duplicate.".to_string();
+            return;
+        };
+
+        // Start on top of the original; add_art_to_queue gives the copy a fresh
+        // id and Pending status once it's placed with Enter, but the same priority
+        // and placement order
+        let art = item.art.clone();
+        self.moving_queue_item = None;
+        self.duplicate_priority = Some(item.priority);
+        self.next_placement_order = item.placement_order;
+        self.status_message = format!(
+            "Duplicating '{}' from ({}, {}). Move it with arrows/mouse, Enter to queue, Esc to cancel.",
+            art.name, art.board_x, art.board_y
+        );
+        self.loaded_art = Some(art);
+        self.input_mode = crate::app_state::InputMode::None;
+    }
+
+    /// Mark or unmark the selected queue item for a batch priority change
+    pub fn toggle_queue_item_selection(&mut self) {
+        let Some(item) = self.art_queue.get_mut(self.queue_selection_index) else {
+            return;
+        };
+        item.selected = !item.selected;
+        let marked = self.art_queue.iter().filter(|item| item.selected).count();
+        self.status_message = format!(
+            "{} item(s) marked. 1-5 sets their priority, Esc clears the marks.",
+            marked
+        );
+    }
+
+    /// Set `priority` on every marked item, or on the selected one when none are marked
+    pub fn set_queue_priority(&mut self, priority: u8) {
+        let marked = self.art_queue.iter().filter(|item| item.selected).count();
+        if marked == 0 {
+            let Some(item) = self.art_queue.get_mut(self.queue_selection_index) else {
+                return;
+            };
+            item.priority = priority;
+            let name = item.art.name.clone();
+            self.sort_queue_by_priority();
+            let _ = self.save_queue(); // Auto-save after priority change
+            self.status_message = format!("Set priority {} for '{}'", priority, name);
+            return;
+        }
+
+        for item in self.art_queue.iter_mut().filter(|item| item.selected) {
+            item.priority = priority;
+            item.selected = false;
+        }
+        self.sort_queue_by_priority();
+        let _ = self.save_queue(); // Auto-save after priority change
+        self.status_message = format!("Set priority {} for {} marked items", priority, marked);
+    }
+
+    /// Remove Complete and Skipped items, keeping Pending and Failed ones
+    pub fn clear_finished_queue_items(&mut self) {
+        if self.queue_processing {
+            // The running task reports progress by queue index
+            self.status_message =
+                "Stop queue processing (Esc) before clearing finished items.".to_string();
+            return;
+        }
+
+        let before = self.art_queue.len();
+        self.art_queue
+            .retain(|item| !matches!(item.status, QueueStatus::Complete | QueueStatus::Skipped));
+        let removed = before - self.art_queue.len();
+
+        if removed == 0 {
+            self.status_message = "No completed or skipped items to clear.".to_string();
+            return;
+        }
+
+        let _ = self.save_queue();
+        self.queue_selection_index = self
+            .queue_selection_index
+            .min(self.art_queue.len().saturating_sub(1));
+        self.add_status_message(format!(
+            "🧹 Cleared {} finished items from the queue ({} left).",
+            removed,
+            self.art_queue.len()
+        ));
+    }
+
+    /// Reset failed queue items to Pending so the next run retries them
+    pub fn retry_failed_queue_items(&mut self) {
+        if self.queue_processing {
+            // The running task works on its own snapshot of which items are pending
+            self.status_message =
+                "Stop queue processing (Esc) before retrying failed items.".to_string();
+            return;
+        }
+
+        let mut retried = 0;
+        for item in &mut self.art_queue {
+            if item.status == QueueStatus::Failed {
+                item.status = QueueStatus::Pending;
+                retried += 1;
+            }
+        }
+
+        if retried == 0 {
+            self.status_message = "No failed items to retry.".to_string();
+            return;
+        }
+
+        let _ = self.save_queue();
+        self.add_status_message(format!(
+            "🔁 {} failed items reset to pending. Press Enter to start processing.",
+            retried
+        ));
+    }
+
     /// Check if queue should auto-resume and start it if conditions are met
     pub fn check_auto_resume_queue(&mut self) {
         // Only auto-resume if:
@@ -1427,19 +2444,30 @@ impl App {
 
     /// Open share selection interface
     pub fn open_share_selection(&mut self) {
-        // Load available shares
-        self.available_shares = crate::art::get_available_shareable_arts();
+        // Load available shares; one broken file shouldn't hide the others
+        let (shares, errors) = crate::art::get_available_shareable_arts(self.art_palette());
+        self.available_shares = shares;
+        for error in &errors {
+            self.add_status_message(format!("⚠️ Skipped share {}", error));
+        }
+        let skipped_note = if errors.is_empty() {
+            String::new()
+        } else {
+            format!(" ({} shares skipped due to errors)", errors.len())
+        };
 
         if self.available_shares.is_empty() {
-            self.status_message =
-                "No shared arts available. Shares are stored in the 'shares/' directory."
-                    .to_string();
+            self.status_message = format!(
+                "No shared arts available. Shares are stored in the 'shares/' directory.{}",
+                skipped_note
+            );
         } else {
             self.input_mode = crate::app_state::InputMode::ShareSelection;
             self.share_selection_index = 0;
             self.status_message = format!(
-                "Found {} shared arts. Use arrows to navigate, Enter to load.",
-                self.available_shares.len()
+                "Found {} shared arts. Use arrows to navigate, Enter to load.{}",
+                self.available_shares.len(),
+                skipped_note
             );
         }
     }
@@ -1455,7 +2483,7 @@ impl App {
                 board_x,
                 board_y
             );
-            let file_path = std::path::Path::new("shares").join(&filename);
+            let file_path = crate::paths::data_path("shares").join(&filename);
 
             match crate::art::save_shareable_pixel_art(
                 art,
@@ -1489,31 +2517,21 @@ impl App {
     /// Load shared art from selection
     pub fn load_shared_art(&mut self, index: usize) {
         if index < self.available_shares.len() {
-            let shareable = &self.available_shares[index];
+            let shareable = self.available_shares[index].clone();
             let mut art = shareable.art.clone();
             art.board_x = shareable.board_x;
             art.board_y = shareable.board_y;
 
             // Move viewport to center on the art location
-            let art_dimensions = crate::art::get_art_dimensions(&art);
-            let art_center_x = art.board_x + art_dimensions.0 / 2;
-            let art_center_y = art.board_y + art_dimensions.1 / 2;
-
-            // Center the viewport on the art (with some padding)
-            if let Some((_, _, board_width, board_height)) = self.board_area_bounds {
-                // Calculate viewport position to center the art
-                let viewport_x = (art_center_x - (board_width as i32 / 2)).max(0) as u16;
-                let viewport_y = (art_center_y - (board_height as i32)).max(0) as u16; // *2 for half-blocks
-
-                self.board_viewport_x = viewport_x;
-                self.board_viewport_y = viewport_y;
-            } else {
-                // Fallback if board bounds not available
-                self.board_viewport_x = (art.board_x - 25).max(0) as u16;
-                self.board_viewport_y = (art.board_y - 15).max(0) as u16;
-            }
+            self.center_viewport_on(
+                art.board_x,
+                art.board_y,
+                crate::art::get_art_dimensions(&art),
+            );
 
             self.loaded_art = Some(art.clone());
+            self.moving_queue_item = None;
+            self.duplicate_priority = None;
             self.input_mode = crate::app_state::InputMode::None;
 
             let share_info = if let Some(msg) = &shareable.share_message {
@@ -1533,31 +2551,17 @@ impl App {
     pub fn apply_share_string(&mut self, share_string: &str) {
         if let Some((art_name, x, y)) = crate::art::parse_share_string(share_string) {
             // Find matching art in available arts
-            let available_arts = crate::art::get_available_pixel_arts();
+            let available_arts = crate::art::get_available_pixel_arts(self.art_palette());
             if let Some(mut art) = available_arts.into_iter().find(|a| a.name == art_name) {
                 art.board_x = x;
                 art.board_y = y;
 
                 // Move viewport to center on the art location
-                let art_dimensions = crate::art::get_art_dimensions(&art);
-                let art_center_x = art.board_x + art_dimensions.0 / 2;
-                let art_center_y = art.board_y + art_dimensions.1 / 2;
-
-                // Center the viewport on the art (with some padding)
-                if let Some((_, _, board_width, board_height)) = self.board_area_bounds {
-                    // Calculate viewport position to center the art
-                    let viewport_x = (art_center_x - (board_width as i32 / 2)).max(0) as u16;
-                    let viewport_y = (art_center_y - (board_height as i32)).max(0) as u16; // *2 for half-blocks
-
-                    self.board_viewport_x = viewport_x;
-                    self.board_viewport_y = viewport_y;
-                } else {
-                    // Fallback if board bounds not available
-                    self.board_viewport_x = (art.board_x - 25).max(0) as u16;
-                    self.board_viewport_y = (art.board_y - 15).max(0) as u16;
-                }
+                self.center_viewport_on(x, y, crate::art::get_art_dimensions(&art));
 
                 self.loaded_art = Some(art.clone());
+                self.moving_queue_item = None;
+                self.duplicate_priority = None;
                 self.input_mode = crate::app_state::InputMode::None;
                 self.status_message = format!(
                     "Applied share coordinates: '{}' positioned at ({}, {}). Viewport moved to art location.",
@@ -1581,36 +2585,129 @@ impl App {
             return; // Invalid selection index
         }
 
+        self.center_viewport_on_queue_item(self.queue_selection_index);
         let selected_art = &self.art_queue[self.queue_selection_index].art;
+        self.status_message = format!(
+            "Centered viewport on '{}' at ({}, {})",
+            selected_art.name, selected_art.board_x, selected_art.board_y
+        );
+    }
 
-        // Get art dimensions to center it properly
-        let art_dimensions = crate::art::get_art_dimensions(selected_art);
-        let art_center_x = selected_art.board_x + art_dimensions.0 / 2;
-        let art_center_y = selected_art.board_y + art_dimensions.1 / 2;
+    /// Move the viewport so the queue item at `index` is in the middle of the board view
+    fn center_viewport_on_queue_item(&mut self, index: usize) {
+        let Some(item) = self.art_queue.get(index) else {
+            return;
+        };
+        let (x, y) = (item.art.board_x, item.art.board_y);
+        let art_dimensions = crate::art::get_art_dimensions(&item.art);
+        self.center_viewport_on(x, y, art_dimensions);
+    }
 
-        // Center the viewport on the art (with some padding)
-        if let Some((_, _, board_width, board_height)) = self.board_area_bounds {
-            // Calculate viewport position to center the art
-            let viewport_x = (art_center_x - (board_width as i32 / 2)).max(0) as u16;
-            let viewport_y = (art_center_y - (board_height as i32)).max(0) as u16; // *2 for half-blocks
+    /// Toggle keeping the viewport on whichever queue item is being processed
+    pub fn toggle_follow_queue(&mut self) {
+        self.follow_queue = !self.follow_queue;
+        if !self.follow_queue {
+            self.status_message = "Follow off.".to_string();
+            return;
+        }
 
-            self.board_viewport_x = viewport_x;
-            self.board_viewport_y = viewport_y;
-        } else {
-            // Fallback if board bounds not available
-            self.board_viewport_x = (selected_art.board_x - 25).max(0) as u16;
-            self.board_viewport_y = (selected_art.board_y - 15).max(0) as u16;
+        if let Some(index) = self.queue_active_index.filter(|_| self.queue_processing) {
+            self.center_viewport_on_queue_item(index);
         }
+        self.status_message =
+            "Following queue processing. Scrolling the board turns it off.".to_string();
+    }
 
-        self.status_message = format!(
-            "Centered viewport on '{}' at ({}, {})",
-            selected_art.name, selected_art.board_x, selected_art.board_y
-        );
+    /// Manual scrolling takes the viewport back from follow mode
+    pub fn stop_following_queue(&mut self) {
+        if self.follow_queue {
+            self.follow_queue = false;
+            self.add_status_message(
+                "Follow off - press 'F' to follow the queue again.".to_string(),
+            );
+        }
+    }
+
+    /// Select and center on the queued art with the most incorrect meaningful pixels
+    pub fn recenter_on_most_griefed_art(&mut self) {
+        if self.art_queue.is_empty() {
+            self.status_message = "Queue is empty - nothing to check for damage.".to_string();
+            return;
+        }
+        if self.board.is_empty() {
+            self.status_message = "Board not loaded yet - press 'r' to refresh.".to_string();
+            return;
+        }
+
+        // Count currently-incorrect pixels for every queued art; off-board ones can't be fixed
+        let mismatch_counts: Vec<usize> = self
+            .art_queue
+            .iter()
+            .map(|item| {
+                let meaningful_pixels = self.filter_meaningful_pixels(&item.art);
+                retain_on_board_pixels(meaningful_pixels, &item.art, &self.board)
+                    .iter()
+                    .filter(|pixel| {
+                        !self.is_pixel_already_correct(
+                            item.art.board_x + pixel.x,
+                            item.art.board_y + pixel.y,
+                            pixel.color,
+                        )
+                    })
+                    .count()
+            })
+            .collect();
+
+        // First art wins ties so the result is stable across refreshes
+        let mut worst_index = 0;
+        for (index, &count) in mismatch_counts.iter().enumerate() {
+            if count > mismatch_counts[worst_index] {
+                worst_index = index;
+            }
+        }
+
+        let damaged_arts = mismatch_counts.iter().filter(|&&count| count > 0).count();
+        if damaged_arts == 0 {
+            self.status_message =
+                format!("🛡️ All {} queued arts are intact.", self.art_queue.len());
+            return;
+        }
+
+        self.queue_selection_index = worst_index;
+        self.center_viewport_on_selected_queue_item();
+
+        let summary = self
+            .art_queue
+            .iter()
+            .zip(&mismatch_counts)
+            .filter(|(_, &count)| count > 0)
+            .map(|(item, count)| format!("{}: {}", item.art.name, count))
+            .collect::<Vec<_>>()
+            .join(", ");
+        self.add_status_message(format!(
+            "🎯 Most damaged: '{}' with {} incorrect pixels ({} of {} arts damaged - {})",
+            self.art_queue[worst_index].art.name,
+            mismatch_counts[worst_index],
+            damaged_arts,
+            self.art_queue.len(),
+            summary
+        ));
     }
 }
 
-/// Calculate how long to wait before we can place a pixel based on user timers and buffer
-pub fn calculate_cooldown_wait_time(user_info: &UserInfos) -> u64 {
+/// Calculate how long to wait before we can place a pixel based on user timers and buffer,
+/// leaving `reserve` buffered pixels untouched
+pub fn calculate_cooldown_wait_time(
+    user_info: &UserInfos,
+    strategy: CooldownStrategy,
+    reserve: usize,
+) -> u64 {
+    if reserve > 0 {
+        if let Some(wait_time) = reserve_wait_time(user_info, strategy, reserve) {
+            return wait_time;
+        }
+    }
+
     // If we have pixel buffer available, we can place immediately
     if user_info.pixel_buffer > 0 {
         return 0;
@@ -1622,7 +2719,7 @@ pub fn calculate_cooldown_wait_time(user_info: &UserInfos) -> u64 {
             // No active timers - this usually means user has no pixels available for a long time
             // Use pixel_timer as base but be more conservative
             let fallback_time = (user_info.pixel_timer as f64 * 60.0) as u64; // Convert minutes to seconds
-            return fallback_time.max(60); // Minimum 1 minute when no timers
+            return fallback_time.max(strategy.fallback_floor_secs()); // Floor when no timers
         }
 
         // Find the earliest timer that will expire
@@ -1639,34 +2736,281 @@ pub fn calculate_cooldown_wait_time(user_info: &UserInfos) -> u64 {
             // All timers have expired but we still got 425 error
             // This suggests the user has no pixels available for a longer period
             let fallback_time = (user_info.pixel_timer as f64 * 60.0) as u64; // Convert minutes to seconds
-            return fallback_time.max(60); // Minimum 1 minute
+            return fallback_time.max(strategy.fallback_floor_secs());
         }
 
         // Calculate exact wait time in seconds
         let wait_time_ms = earliest_expiry - current_time_ms;
         let wait_time_secs = (wait_time_ms as f64 / 1000.0).ceil() as u64;
 
-        // Return the calculated time with small buffer
-        wait_time_secs.max(1) + 2 // Minimum 1 second + 2 second buffer
+        // Return the calculated time with the strategy's safety margin
+        wait_time_secs.max(1)
+            + strategy.timer_margin_secs()
+            + jitter(strategy.cooldown_jitter_secs())
     } else {
         // No timer data at all - very conservative fallback
         let fallback_time = (user_info.pixel_timer as f64 * 60.0) as u64; // Convert minutes to seconds
-        fallback_time.max(120) // Minimum 2 minutes when no timer data
+        fallback_time.max(strategy.fallback_floor_secs() * 2) // Longer floor when no timer data
     }
 }
 
-/// Check if we should pause queue processing due to long cooldowns
-pub fn should_pause_queue_processing(user_info: &UserInfos) -> (bool, u64) {
-    let wait_time = calculate_cooldown_wait_time(user_info);
+/// Wait until more than `reserve` pixels are available, or None if that's already the case.
+/// The reserve is capped below the buffer size so the queue can always make progress.
+fn reserve_wait_time(
+    user_info: &UserInfos,
+    strategy: CooldownStrategy,
+    reserve: usize,
+) -> Option<u64> {
+    let buffer_size = user_info.pixel_buffer.max(0) as usize;
+    let reserve = reserve.min(buffer_size.saturating_sub(1));
+    let available = available_pixel_buffer(user_info);
+    if available > reserve {
+        return None;
+    }
+
+    // Each active timer gives one pixel back; wait for enough of them to clear the reserve
+    let current_time_ms = chrono::Utc::now().timestamp_millis();
+    let mut active_timers: Vec<i64> = user_info
+        .timers
+        .iter()
+        .flatten()
+        .copied()
+        .filter(|&timer_ms| timer_ms > current_time_ms)
+        .collect();
+    if active_timers.is_empty() {
+        let fallback_time = (user_info.pixel_timer as f64 * 60.0) as u64; // Convert minutes to seconds
+        return Some(fallback_time.max(strategy.fallback_floor_secs()));
+    }
+    active_timers.sort_unstable();
+
+    let needed = (reserve - available + 1).min(active_timers.len());
+    let wait_time_ms = active_timers[needed - 1] - current_time_ms;
+    let wait_time_secs = (wait_time_ms as f64 / 1000.0).ceil() as u64;
+    Some(
+        wait_time_secs.max(1)
+            + strategy.timer_margin_secs()
+            + jitter(strategy.cooldown_jitter_secs()),
+    )
+}
 
-    // Pause if we need to wait more than 2 minutes
-    if wait_time > 120 {
+/// Delay between two placements for the strategy. Buffer above the reserve skips the
+/// base pause, except for strategies that keep pacing then (Stealth's jittered delay)
+fn pixel_delay(strategy: CooldownStrategy, spare_buffer: bool) -> Duration {
+    if spare_buffer && !strategy.paces_spare_buffer() {
+        return Duration::ZERO;
+    }
+    Duration::from_millis(strategy.pixel_delay_ms() + jitter(strategy.pixel_jitter_ms()))
+}
+
+/// Pseudo-random value in `0..=max` from the clock's sub-second noise (good enough for pacing)
+fn jitter(max: u64) -> u64 {
+    if max == 0 {
+        return 0;
+    }
+    let nanos = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map_or(0, |elapsed| elapsed.subsec_nanos() as u64);
+    nanos % (max + 1)
+}
+
+/// Politeness mode: keeps placements at least `60s / rate` apart, plus random jitter
+struct PlacementPacer {
+    spacing: Option<Duration>,
+    next_allowed: Instant,
+}
+
+impl PlacementPacer {
+    fn new(pixels_per_minute: Option<u32>) -> Self {
+        Self {
+            spacing: pixels_per_minute.map(|rate| Duration::from_secs(60) / rate.max(1)),
+            next_allowed: Instant::now(),
+        }
+    }
+
+    fn is_active(&self) -> bool {
+        self.spacing.is_some()
+    }
+
+    /// Sleep until the next placement is allowed, then book the slot after it
+    async fn wait_turn(&mut self) {
+        let Some(spacing) = self.spacing else {
+            return;
+        };
+        tokio::time::sleep(self.next_allowed.saturating_duration_since(Instant::now())).await;
+        // Jitter only lengthens the gap, so the cap always holds
+        let jitter_ms = jitter(spacing.as_millis() as u64 / 4);
+        self.next_allowed = Instant::now() + spacing + Duration::from_millis(jitter_ms);
+    }
+}
+
+/// How many placements to send at once: up to `concurrency` while the buffer above the
+/// reserve covers them, or one at a time when the run has to stay serial
+fn placement_burst_size(
+    concurrency: usize,
+    user_info: Option<&UserInfos>,
+    reserve: usize,
+    serial: bool,
+) -> usize {
+    if serial {
+        return 1;
+    }
+    concurrency.min(user_info.map_or(0, |info| {
+        available_pixel_buffer(info).saturating_sub(reserve)
+    }))
+}
+
+/// Check if we should pause queue processing due to long cooldowns
+pub fn should_pause_queue_processing(
+    user_info: &UserInfos,
+    strategy: CooldownStrategy,
+    reserve: usize,
+) -> (bool, u64) {
+    let wait_time = calculate_cooldown_wait_time(user_info, strategy, reserve);
+
+    // Pause if we need to wait longer than the strategy's threshold
+    if wait_time > strategy.pause_threshold_secs() {
         (true, wait_time)
     } else {
         (false, wait_time)
     }
 }
 
+/// Drop pixels that land outside the board at the art's position (kept as-is while the board is unknown)
+pub fn retain_on_board_pixels(
+    mut pixels: Vec<ArtPixel>,
+    art: &PixelArt,
+    board: &[Vec<Option<crate::api_client::PixelNetwork>>],
+) -> Vec<ArtPixel> {
+    if board.is_empty() {
+        return pixels;
+    }
+
+    let board_width = board.len() as i32;
+    let board_height = board[0].len() as i32;
+    pixels.retain(|pixel| {
+        let abs_x = art.board_x + pixel.x;
+        let abs_y = art.board_y + pixel.y;
+        (0..board_width).contains(&abs_x) && (0..board_height).contains(&abs_y)
+    });
+    pixels
+}
+
+/// Fix up a queue read from disk. A queue saved mid-run still has its active item
+/// marked InProgress; nothing picks those up, so hand them back to the pending pool
+fn normalize_loaded_queue(queue: &mut [ArtQueueItem]) {
+    let mut seen_ids = HashSet::new();
+    for index in 0..queue.len() {
+        // Queues saved before items had ids, or edited by hand, get fresh ones
+        if queue[index].id == 0 || !seen_ids.insert(queue[index].id) {
+            queue[index].id = next_queue_item_id(queue);
+            seen_ids.insert(queue[index].id);
+        }
+        let item = &mut queue[index];
+        if item.status == QueueStatus::InProgress {
+            item.status = QueueStatus::Pending;
+        }
+        // Older queue files may hold arts with negative relative pixels
+        crate::art::normalize_art_origin(&mut item.art);
+    }
+}
+
+/// An id no item in `queue` uses yet
+fn next_queue_item_id(queue: &[ArtQueueItem]) -> u64 {
+    queue.iter().map(|item| item.id).max().unwrap_or(0) + 1
+}
+
+/// The board's default color: the one covering a clear majority of all cells, empty ones
+/// included. A sparsely painted board has none, so a large art can't pass for the background.
+pub fn detect_default_color_id(
+    board: &[Vec<Option<crate::api_client::PixelNetwork>>],
+) -> Option<i32> {
+    let mut counts = HashMap::new();
+    let mut total = 0usize;
+    for pixel in board.iter().flatten() {
+        total += 1;
+        if let Some(pixel) = pixel {
+            *counts.entry(pixel.c).or_insert(0usize) += 1;
+        }
+    }
+    counts
+        .into_iter()
+        .filter(|&(_, count)| count * 2 > total)
+        .map(|(color_id, _)| color_id)
+        .next()
+}
+
+/// Whether a cell is empty or shows the default color, i.e. no other player painted it
+fn is_cell_free(
+    board: &[Vec<Option<crate::api_client::PixelNetwork>>],
+    x: i32,
+    y: i32,
+    default_color_id: Option<i32>,
+) -> bool {
+    let cell = board
+        .get(x as usize)
+        .and_then(|column| column.get(y as usize));
+    match cell {
+        Some(Some(pixel)) => Some(pixel.c) == default_color_id,
+        Some(None) => true,
+        None => false,
+    }
+}
+
+/// Order pixels for placement according to `order`
+pub fn order_pixels(
+    pixels: Vec<crate::art::ArtPixel>,
+    order: PlacementOrder,
+) -> Vec<crate::art::ArtPixel> {
+    match order {
+        PlacementOrder::BorderFirst => order_pixels_border_first(pixels),
+        PlacementOrder::ContrastFirst => order_pixels_contrast_first(pixels),
+        PlacementOrder::CenterOut => order_pixels_center_out(pixels),
+    }
+}
+
+/// Order pixels by distance from the center of their bounding box, nearest first,
+/// so a recognizable core shows up early. Ties keep the border-first order.
+pub fn order_pixels_center_out(pixels: Vec<crate::art::ArtPixel>) -> Vec<crate::art::ArtPixel> {
+    let mut ordered = order_pixels_border_first(pixels);
+    let (Some(min_x), Some(max_x)) = (
+        ordered.iter().map(|p| p.x).min(),
+        ordered.iter().map(|p| p.x).max(),
+    ) else {
+        return ordered;
+    };
+    let min_y = ordered.iter().map(|p| p.y).min().unwrap_or(0);
+    let max_y = ordered.iter().map(|p| p.y).max().unwrap_or(0);
+
+    // Doubled coordinates keep the center exact for even sizes
+    let (center_x2, center_y2) = (min_x + max_x, min_y + max_y);
+    ordered.sort_by_key(|pixel| {
+        let dx = (2 * pixel.x - center_x2) as i64;
+        let dy = (2 * pixel.y - center_y2) as i64;
+        dx * dx + dy * dy
+    });
+    ordered
+}
+
+/// Order pixels by contrast: pixels with the most 4-neighbors of a different color
+/// (or no pixel at all) come first, so the design's outlines and details read early.
+/// Ties keep the border-first order.
+pub fn order_pixels_contrast_first(pixels: Vec<crate::art::ArtPixel>) -> Vec<crate::art::ArtPixel> {
+    let colors_by_position: std::collections::HashMap<(i32, i32), i32> =
+        pixels.iter().map(|p| ((p.x, p.y), p.color)).collect();
+    let contrast = |pixel: &crate::art::ArtPixel| {
+        [(-1, 0), (1, 0), (0, -1), (0, 1)]
+            .iter()
+            .filter(|(dx, dy)| {
+                colors_by_position.get(&(pixel.x + dx, pixel.y + dy)) != Some(&pixel.color)
+            })
+            .count()
+    };
+
+    let mut ordered = order_pixels_border_first(pixels);
+    ordered.sort_by_key(|pixel| std::cmp::Reverse(contrast(pixel)));
+    ordered
+}
+
 /// Order pixels with border-first strategy: borders first, then top-to-bottom fill
 /// This is a standalone function that can be used by both queue_management and art_placement
 pub fn order_pixels_border_first(
@@ -1724,3 +3068,857 @@ pub fn order_pixels_border_first(
     result.extend(interior_pixels);
     result
 }
+
+/// Per-item bookkeeping for the items of a queue batch
+#[derive(Default)]
+struct ItemRunState {
+    total_meaningful_pixels: usize,
+    pixels_already_correct_at_start: usize,
+    pixels_placed: usize,
+    remaining_pixels: usize,
+    pixels_missing_color: usize, // Skipped because the color left the palette mid-run
+    pixels_occupied: usize,      // Skipped as another player's cell (empty cells only)
+    pixels_verified: Option<usize>, // Placements seen on the board afterwards (None = not verifying)
+    pending_verification: usize,    // Placed but not read back yet; holds off completion
+    completed: bool,
+}
+
+/// A pixel placed this run, checked against later board refreshes
+#[derive(Clone)]
+struct PlacedPixel {
+    slot: usize,
+    pixel_index: usize,
+    pixel: ArtPixel,
+    x: i32,
+    y: i32,
+}
+
+/// Wait for the next parallel placement; a task that panicked or was cancelled gives
+/// back the pixel it carried along with its error
+async fn join_next_burst<T: 'static>(
+    in_flight: &mut tokio::task::JoinSet<T>,
+    launched: &mut HashMap<tokio::task::Id, PlacedPixel>,
+) -> Option<Result<(PlacedPixel, T), (PlacedPixel, tokio::task::JoinError)>> {
+    loop {
+        let (id, outcome) = match in_flight.join_next_with_id().await? {
+            Ok((id, output)) => (id, Ok(output)),
+            Err(e) => (e.id(), Err(e)),
+        };
+        // Every task is registered right after it's spawned
+        let Some(placed) = launched.remove(&id) else {
+            continue;
+        };
+        return Some(match outcome {
+            Ok(output) => Ok((placed, output)),
+            Err(e) => Err((placed, e)),
+        });
+    }
+}
+
+/// Hand a pixel a burst couldn't place back to the serial path, which retries it first
+fn defer_burst_pixel(
+    run_states: &mut [ItemRunState],
+    deferred: &mut VecDeque<(usize, (usize, ArtPixel))>,
+    placed: PlacedPixel,
+) {
+    run_states[placed.slot].remaining_pixels += 1;
+    deferred.push_back((placed.slot, (placed.pixel_index, placed.pixel)));
+}
+
+/// Check placed pixels against a fresh board: ones showing their color count as verified,
+/// the rest are re-queued once, and reported if they still haven't landed after that
+fn verify_placed_pixels(
+    board: &[Vec<Option<crate::api_client::PixelNetwork>>],
+    pending_verification: &mut Vec<PlacedPixel>,
+    placed_pixels: &mut Vec<PlacedPixel>,
+    run_states: &mut [ItemRunState],
+    deferred: &mut VecDeque<(usize, (usize, ArtPixel))>,
+    requeued_positions: &mut HashSet<(i32, i32)>,
+    tx: &mpsc::UnboundedSender<QueueUpdate>,
+) {
+    for pending in pending_verification.drain(..) {
+        let state = &mut run_states[pending.slot];
+        state.pending_verification -= 1;
+
+        let landed = board
+            .get(pending.x as usize)
+            .and_then(|column| column.get(pending.y as usize))
+            .and_then(|pixel| pixel.as_ref())
+            .is_some_and(|pixel| pixel.c == pending.pixel.color);
+        if landed {
+            if let Some(verified) = &mut state.pixels_verified {
+                *verified += 1;
+            }
+            placed_pixels.push(pending);
+        } else if requeued_positions.insert((pending.x, pending.y)) {
+            let _ = tx.send(QueueUpdate::ApiCall {
+                message: format!(
+                    "🔍 Pixel at ({},{}) didn't land - re-queued",
+                    pending.x, pending.y
+                ),
+            });
+            state.pixels_placed = state.pixels_placed.saturating_sub(1);
+            state.remaining_pixels += 1;
+            deferred.push_back((pending.slot, (pending.pixel_index, pending.pixel)));
+        } else {
+            let _ = tx.send(QueueUpdate::ApiCall {
+                message: format!(
+                    "⚠️ Pixel at ({},{}) still not showing color {} after a retry",
+                    pending.x, pending.y, pending.pixel.color
+                ),
+            });
+        }
+    }
+}
+
+/// Move placements that a refresh shows on the board into `placed_pixels`. A board
+/// that lags behind the placement keeps the pixel waiting rather than counting as griefing
+fn confirm_landed_pixels(
+    board: &[Vec<Option<crate::api_client::PixelNetwork>>],
+    unconfirmed_pixels: &mut Vec<PlacedPixel>,
+    placed_pixels: &mut Vec<PlacedPixel>,
+    run_states: &[ItemRunState],
+) {
+    let (landed, waiting): (Vec<_>, Vec<_>) = std::mem::take(unconfirmed_pixels)
+        .into_iter()
+        .filter(|pending| !run_states[pending.slot].completed)
+        .partition(|pending| {
+            board
+                .get(pending.x as usize)
+                .and_then(|column| column.get(pending.y as usize))
+                .and_then(|pixel| pixel.as_ref())
+                .is_some_and(|pixel| pixel.c == pending.pixel.color)
+        });
+    *unconfirmed_pixels = waiting;
+    placed_pixels.extend(landed);
+}
+
+/// Put pixels placed earlier in the run that now show another color back at the
+/// front of the line, while their item is still being placed. Only pixels seen on
+/// the board after placing them are in `placed_pixels`, so misses are verification's job
+fn requeue_overwritten_pixels(
+    board: &[Vec<Option<crate::api_client::PixelNetwork>>],
+    placed_pixels: &mut Vec<PlacedPixel>,
+    run_states: &mut [ItemRunState],
+    deferred: &mut VecDeque<(usize, (usize, ArtPixel))>,
+    defense_counts: &mut HashMap<(i32, i32), u32>,
+    tx: &mpsc::UnboundedSender<QueueUpdate>,
+) {
+    placed_pixels.retain(|placed| {
+        let state = &mut run_states[placed.slot];
+        if state.completed {
+            return false;
+        }
+
+        let intact = board
+            .get(placed.x as usize)
+            .and_then(|column| column.get(placed.y as usize))
+            .and_then(|pixel| pixel.as_ref())
+            .is_some_and(|pixel| pixel.c == placed.pixel.color);
+        if intact {
+            return true;
+        }
+
+        // Verification may already have put it back in line
+        let already_queued = deferred
+            .iter()
+            .any(|(slot, (index, _))| *slot == placed.slot && *index == placed.pixel_index);
+        let defenses = defense_counts.entry((placed.x, placed.y)).or_insert(0);
+        if already_queued || *defenses >= MAX_PIXEL_DEFENSES {
+            return false;
+        }
+
+        *defenses += 1;
+        let _ = tx.send(QueueUpdate::ApiCall {
+            message: format!(
+                "🛡️ Pixel at ({},{}) was overwritten - placing it again next",
+                placed.x, placed.y
+            ),
+        });
+        state.pixels_placed = state.pixels_placed.saturating_sub(1);
+        state.remaining_pixels += 1;
+        deferred.push_front((placed.slot, (placed.pixel_index, placed.pixel.clone())));
+        false
+    });
+}
+
+/// Flatten per-item pixel lists into `(slot, pixel)` placement order.
+/// Sequential mode keeps items back to back; interleaved mode takes one pixel
+/// from each item per round so every art makes progress together, and priority
+/// mode takes more per round from higher-priority items (5 for priority 1, 1 for 5).
+fn build_placement_schedule<T>(
+    pixel_lists: Vec<Vec<T>>,
+    interleave: Interleave,
+    priorities: &[u8],
+) -> Vec<(usize, T)> {
+    if interleave == Interleave::Off {
+        return pixel_lists
+            .into_iter()
+            .enumerate()
+            .flat_map(|(slot, pixels)| pixels.into_iter().map(move |pixel| (slot, pixel)))
+            .collect();
+    }
+
+    let mut iters: Vec<_> = pixel_lists
+        .into_iter()
+        .map(|pixels| pixels.into_iter())
+        .collect();
+    let per_round: Vec<usize> = priorities
+        .iter()
+        .map(|&priority| match interleave {
+            Interleave::Priority => 6 - priority.clamp(1, 5) as usize,
+            _ => 1,
+        })
+        .collect();
+    let mut schedule = Vec::new();
+    loop {
+        let mut took_any = false;
+        for (slot, iter) in iters.iter_mut().enumerate() {
+            for pixel in iter.by_ref().take(per_round[slot]) {
+                schedule.push((slot, pixel));
+                took_any = true;
+            }
+        }
+        if !took_any {
+            break;
+        }
+    }
+    schedule
+}
+
+/// Send `ItemCompleted` for every item with no pixels left, returning how many finished
+fn send_finished_items(
+    batch_items: &[(usize, ArtQueueItem)],
+    run_states: &mut [ItemRunState],
+    tx: &mpsc::UnboundedSender<QueueUpdate>,
+) -> usize {
+    let mut finished = 0;
+    for ((original_index, queue_item), state) in batch_items.iter().zip(run_states.iter_mut()) {
+        if state.completed || state.remaining_pixels > 0 || state.pending_verification > 0 {
+            continue;
+        }
+        state.completed = true;
+        finished += 1;
+
+        // Send item completion update
+        let _ = tx.send(QueueUpdate::ItemCompleted {
+            item_index: *original_index,
+            art_name: queue_item.art.name.clone(),
+            pixels_placed: state.pixels_placed + state.pixels_already_correct_at_start,
+            total_pixels: state.total_meaningful_pixels,
+        });
+        if state.pixels_missing_color > 0 {
+            let _ = tx.send(QueueUpdate::ApiCall {
+                message: format!(
+                    "⚠️ '{}': {} pixels skipped - their color is no longer in the palette",
+                    queue_item.art.name, state.pixels_missing_color
+                ),
+            });
+        }
+        if state.pixels_occupied > 0 {
+            let _ = tx.send(QueueUpdate::ApiCall {
+                message: format!(
+                    "🕊️ '{}': {} pixels skipped - cells already taken by other players",
+                    queue_item.art.name, state.pixels_occupied
+                ),
+            });
+        }
+        if let Some(verified) = state.pixels_verified {
+            let _ = tx.send(QueueUpdate::ApiCall {
+                message: format!(
+                    "🔍 '{}': {}/{} placed pixels verified on the board",
+                    queue_item.art.name, verified, state.pixels_placed
+                ),
+            });
+        }
+    }
+    finished
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::api_client::ColorInfo;
+
+    fn color(id: i32, name: &str) -> ColorInfo {
+        ColorInfo {
+            id,
+            name: name.to_string(),
+            red: 0,
+            green: 0,
+            blue: 0,
+        }
+    }
+
+    fn pixel(x: i32, y: i32, color: i32) -> ArtPixel {
+        ArtPixel { x, y, color }
+    }
+
+    fn user_info(pixel_buffer: i32, active_timers: usize) -> UserInfos {
+        serde_json::from_value(serde_json::json!({
+            "timers": vec![0; active_timers],
+            "pixel_buffer": pixel_buffer,
+            "pixel_timer": 60,
+        }))
+        .unwrap()
+    }
+
+    fn queue_item(pattern: Vec<ArtPixel>, status: QueueStatus) -> ArtQueueItem {
+        ArtQueueItem {
+            id: 0,
+            art: PixelArt {
+                name: "test".to_string(),
+                width: 2,
+                height: 1,
+                pattern,
+                ..Default::default()
+            },
+            priority: 3,
+            status,
+            pixels_placed: 0,
+            pixels_total: 0,
+            added_time: Instant::now(),
+            paused: false,
+            placement_order: PlacementOrder::default(),
+            selected: false,
+        }
+    }
+
+    #[test]
+    fn queue_task_filter_skips_transparent_pixels() {
+        let colors = vec![color(0, "Transparent"), color(18, "Red")];
+        let art = PixelArt {
+            name: "test".to_string(),
+            width: 2,
+            height: 1,
+            pattern: vec![pixel(0, 0, 0), pixel(1, 0, 18)],
+            ..Default::default()
+        };
+
+        let pixels = App::filter_meaningful_pixels_for_art(
+            &art,
+            &colors,
+            &SkipRules::default(),
+            PlacementOrder::default(),
+        );
+
+        assert_eq!(pixels.len(), 1);
+        assert_eq!((pixels[0].x, pixels[0].color), (1, 18));
+    }
+
+    fn board_of(colors: &[Option<i32>]) -> Vec<Vec<Option<crate::api_client::PixelNetwork>>> {
+        colors
+            .iter()
+            .map(|color| {
+                vec![color.map(|c| crate::api_client::PixelNetwork {
+                    c,
+                    u: String::new(),
+                    t: 0,
+                })]
+            })
+            .collect()
+    }
+
+    fn placed_at(slot: usize, x: i32, color: i32) -> PlacedPixel {
+        PlacedPixel {
+            slot,
+            pixel_index: x as usize,
+            pixel: pixel(x, 0, color),
+            x,
+            y: 0,
+        }
+    }
+
+    #[test]
+    fn overwritten_pixels_jump_the_line_until_defended_enough() {
+        let board = board_of(&[Some(5), Some(7), Some(7), Some(7)]);
+        let mut placed_pixels = vec![
+            placed_at(0, 0, 5), // Intact
+            placed_at(0, 1, 5), // Overwritten
+            placed_at(1, 2, 5), // Overwritten, but its item already finished
+            placed_at(0, 3, 5), // Overwritten, out of defenses
+        ];
+        let mut run_states = vec![ItemRunState::default(), ItemRunState::default()];
+        run_states[0].pixels_placed = 3;
+        run_states[1].completed = true;
+        let mut deferred = VecDeque::from([(0, (9, pixel(9, 0, 5)))]);
+        let mut defense_counts = HashMap::from([((3, 0), MAX_PIXEL_DEFENSES)]);
+        let (tx, _rx) = mpsc::unbounded_channel();
+
+        requeue_overwritten_pixels(
+            &board,
+            &mut placed_pixels,
+            &mut run_states,
+            &mut deferred,
+            &mut defense_counts,
+            &tx,
+        );
+
+        let kept: Vec<_> = placed_pixels.iter().map(|placed| placed.x).collect();
+        assert_eq!(kept, [0]);
+        let queued: Vec<_> = deferred.iter().map(|(slot, (index, _))| (*slot, *index)).collect();
+        assert_eq!(queued, [(0, 1), (0, 9)]);
+        assert_eq!(run_states[0].pixels_placed, 2);
+        assert_eq!(run_states[0].remaining_pixels, 1);
+        assert_eq!(defense_counts[&(1, 0)], 1);
+    }
+
+    #[test]
+    fn pixels_are_defended_only_after_they_were_seen_on_the_board() {
+        let run_states = vec![ItemRunState::default()];
+        let mut unconfirmed = vec![placed_at(0, 0, 5), placed_at(0, 1, 5)];
+        let mut placed_pixels = Vec::new();
+
+        // The refresh predates the second placement
+        confirm_landed_pixels(
+            &board_of(&[Some(5), None]),
+            &mut unconfirmed,
+            &mut placed_pixels,
+            &run_states,
+        );
+        assert_eq!(placed_pixels.len(), 1);
+        assert_eq!(unconfirmed.len(), 1);
+
+        confirm_landed_pixels(
+            &board_of(&[Some(5), Some(5)]),
+            &mut unconfirmed,
+            &mut placed_pixels,
+            &run_states,
+        );
+        assert_eq!(placed_pixels.len(), 2);
+        assert!(unconfirmed.is_empty());
+    }
+
+    #[test]
+    fn pixels_verification_gave_up_on_are_not_defended() {
+        let board = board_of(&[Some(7)]);
+        let mut pending = vec![placed_at(0, 0, 5)];
+        let mut placed_pixels = Vec::new();
+        let mut run_states = vec![ItemRunState::default()];
+        run_states[0].pending_verification = 1;
+        let mut deferred = VecDeque::new();
+        let mut requeued_positions = HashSet::from([(0, 0)]); // Already retried once
+        let mut defense_counts = HashMap::new();
+        let (tx, _rx) = mpsc::unbounded_channel();
+
+        verify_placed_pixels(
+            &board,
+            &mut pending,
+            &mut placed_pixels,
+            &mut run_states,
+            &mut deferred,
+            &mut requeued_positions,
+            &tx,
+        );
+        requeue_overwritten_pixels(
+            &board,
+            &mut placed_pixels,
+            &mut run_states,
+            &mut deferred,
+            &mut defense_counts,
+            &tx,
+        );
+
+        assert!(deferred.is_empty());
+        assert!(defense_counts.is_empty());
+    }
+
+    #[test]
+    fn only_unpainted_or_default_colored_cells_are_free() {
+        let painted = |c| {
+            Some(crate::api_client::PixelNetwork {
+                c,
+                u: String::new(),
+                t: 0,
+            })
+        };
+        let board = vec![vec![None, painted(1), painted(5)]];
+        assert!(is_cell_free(&board, 0, 0, Some(1)));
+        assert!(is_cell_free(&board, 0, 1, Some(1)));
+        assert!(!is_cell_free(&board, 0, 2, Some(1)));
+        assert!(!is_cell_free(&board, 0, 1, None));
+        assert!(!is_cell_free(&board, 1, 0, Some(1)));
+    }
+
+    #[test]
+    fn a_large_art_on_a_mostly_empty_board_is_not_the_default_color() {
+        // A 3-pixel art in color 7 on a 10-cell board: the most painted color, not the background
+        let mut cells = vec![Some(7); 3];
+        cells.extend([None; 7]);
+        let board = board_of(&cells);
+        let default_color_id = detect_default_color_id(&board);
+        assert_eq!(default_color_id, None);
+        assert!(!is_cell_free(&board, 0, 0, default_color_id));
+        assert!(is_cell_free(&board, 5, 0, default_color_id));
+
+        // Once one color covers most of the board it is the untouched background
+        let mut cells = vec![Some(1); 7];
+        cells.extend([Some(7); 3]);
+        let board = board_of(&cells);
+        assert_eq!(detect_default_color_id(&board), Some(1));
+    }
+
+    #[test]
+    fn off_board_pixels_are_not_counted_as_damage() {
+        let mut app = App::for_tests();
+        app.colors = vec![color(18, "Red")];
+        app.board = vec![vec![Some(crate::api_client::PixelNetwork {
+            c: 18,
+            u: String::new(),
+            t: 0,
+        })]];
+        // Only the first pixel is on the 1x1 board, and it is intact
+        app.art_queue = vec![queue_item(
+            vec![pixel(0, 0, 18), pixel(1, 0, 18)],
+            QueueStatus::Complete,
+        )];
+
+        app.recenter_on_most_griefed_art();
+        assert_eq!(app.status_message, "🛡️ All 1 queued arts are intact.");
+    }
+
+    #[test]
+    fn loaded_queue_resumes_items_saved_mid_run() {
+        let statuses = [
+            QueueStatus::Pending,
+            QueueStatus::InProgress,
+            QueueStatus::Complete,
+            QueueStatus::Skipped,
+            QueueStatus::Failed,
+        ];
+        let saved: Vec<_> = statuses
+            .into_iter()
+            .map(|status| queue_item(vec![pixel(0, 0, 18)], status))
+            .collect();
+        // Round-trip through JSON like save_queue/load_queue do
+        let mut queue: Vec<ArtQueueItem> =
+            serde_json::from_str(&serde_json::to_string(&saved).unwrap()).unwrap();
+
+        normalize_loaded_queue(&mut queue);
+
+        let statuses: Vec<_> = queue.iter().map(|item| item.status.clone()).collect();
+        assert_eq!(
+            statuses,
+            [
+                QueueStatus::Pending,
+                QueueStatus::Pending,
+                QueueStatus::Complete,
+                QueueStatus::Skipped,
+                QueueStatus::Failed,
+            ]
+        );
+    }
+
+    #[test]
+    fn loaded_queue_items_get_distinct_ids() {
+        let mut queue: Vec<_> = (0..3)
+            .map(|_| queue_item(vec![pixel(0, 0, 18)], QueueStatus::Pending))
+            .collect();
+        queue[1].id = 7;
+        queue[2].id = 7; // Duplicated by hand in the queue file
+
+        normalize_loaded_queue(&mut queue);
+
+        let ids: HashSet<_> = queue.iter().map(|item| item.id).collect();
+        assert_eq!(ids.len(), 3);
+        assert!(!ids.contains(&0));
+        assert_eq!(queue[1].id, 7);
+    }
+
+    #[test]
+    fn available_buffer_leaves_out_pixels_still_cooling_down() {
+        assert_eq!(available_pixel_buffer(&user_info(8, 0)), 8);
+        assert_eq!(available_pixel_buffer(&user_info(8, 3)), 5);
+        assert_eq!(available_pixel_buffer(&user_info(2, 5)), 0);
+        assert_eq!(available_pixel_buffer(&user_info(-1, 0)), 0);
+    }
+
+    #[test]
+    fn pixels_are_paced_only_once_the_buffer_reaches_the_reserve() {
+        let info = user_info(5, 1); // 4 pixels available
+        assert!(has_spare_buffer(Some(&info), 0));
+        assert!(has_spare_buffer(Some(&info), 3));
+        assert!(!has_spare_buffer(Some(&info), 4));
+        assert!(!has_spare_buffer(Some(&user_info(1, 1)), 0));
+        assert!(!has_spare_buffer(None, 0));
+    }
+
+    #[test]
+    fn stealth_keeps_its_pause_while_buffer_is_spare() {
+        for strategy in [CooldownStrategy::Aggressive, CooldownStrategy::Balanced] {
+            assert_eq!(pixel_delay(strategy, true), Duration::ZERO);
+            assert_eq!(
+                pixel_delay(strategy, false),
+                Duration::from_millis(strategy.pixel_delay_ms())
+            );
+        }
+        let stealth = pixel_delay(CooldownStrategy::Stealth, true);
+        assert!(stealth >= Duration::from_millis(400) && stealth <= Duration::from_millis(1200));
+    }
+
+    #[tokio::test]
+    async fn panicked_burst_tasks_hand_their_pixel_back() {
+        let mut in_flight = tokio::task::JoinSet::new();
+        let mut launched = HashMap::new();
+        for (slot, x) in [(0, 1), (1, 2)] {
+            let handle = in_flight.spawn(async move {
+                if x == 2 {
+                    panic!("placement task died");
+                }
+                x
+            });
+            let placed = PlacedPixel {
+                slot,
+                pixel_index: x as usize,
+                pixel: pixel(x, 0, 18),
+                x,
+                y: 0,
+            };
+            launched.insert(handle.id(), placed);
+        }
+
+        let mut run_states = vec![ItemRunState::default(), ItemRunState::default()];
+        let mut deferred = VecDeque::new();
+        let mut finished = Vec::new();
+        while let Some(joined) = join_next_burst(&mut in_flight, &mut launched).await {
+            match joined {
+                Ok((placed, output)) => {
+                    assert_eq!(output, placed.x);
+                    finished.push(placed.slot);
+                }
+                Err((placed, e)) => {
+                    assert!(e.is_panic());
+                    defer_burst_pixel(&mut run_states, &mut deferred, placed);
+                }
+            }
+        }
+
+        // The pixel of the dead task is retried instead of vanishing from the counts
+        assert_eq!(finished, [0]);
+        assert_eq!(run_states[0].remaining_pixels, 0);
+        assert_eq!(run_states[1].remaining_pixels, 1);
+        let deferred: Vec<_> = deferred
+            .into_iter()
+            .map(|(slot, (pixel_index, _))| (slot, pixel_index))
+            .collect();
+        assert_eq!(deferred, [(1, 2)]);
+        assert!(launched.is_empty());
+    }
+
+    #[test]
+    fn burst_size_is_bounded_by_concurrency_buffer_and_reserve() {
+        let info = user_info(8, 2); // 6 pixels available
+        assert_eq!(placement_burst_size(4, Some(&info), 0, false), 4);
+        assert_eq!(placement_burst_size(8, Some(&info), 0, false), 6);
+        assert_eq!(placement_burst_size(8, Some(&info), 4, false), 2);
+        assert_eq!(placement_burst_size(8, Some(&info), 6, false), 0);
+        // Unknown buffer, politeness mode or a fresh token refresh: one at a time
+        assert!(placement_burst_size(8, None, 0, false) <= 1);
+        assert_eq!(placement_burst_size(8, Some(&info), 0, true), 1);
+    }
+
+    #[test]
+    fn reserve_makes_the_queue_wait_for_regeneration() {
+        let now_ms = chrono::Utc::now().timestamp_millis();
+        // Buffer of 5 with 3 pixels cooling down: 2 can be placed right now
+        let info: UserInfos = serde_json::from_value(serde_json::json!({
+            "timers": [now_ms + 60_000, now_ms + 120_000, now_ms + 180_000],
+            "pixel_buffer": 5,
+            "pixel_timer": 1,
+        }))
+        .unwrap();
+        let strategy = CooldownStrategy::default();
+
+        assert_eq!(calculate_cooldown_wait_time(&info, strategy, 0), 0);
+        assert_eq!(calculate_cooldown_wait_time(&info, strategy, 1), 0);
+        // Keeping both available pixels in reserve waits for the first timer instead
+        assert!(calculate_cooldown_wait_time(&info, strategy, 2) >= 60);
+        assert!(calculate_cooldown_wait_time(&info, strategy, 3) >= 120);
+    }
+
+    #[test]
+    fn interleaved_schedules_place_the_same_pixels_as_the_serial_path() {
+        let pixel_lists = vec![
+            vec![10, 11, 12, 13, 14, 15, 16],
+            vec![20, 21],
+            vec![30, 31, 32],
+        ];
+        let priorities = [5, 1, 3];
+
+        let mut serial =
+            build_placement_schedule(pixel_lists.clone(), Interleave::Off, &priorities);
+        assert_eq!(serial[..3], [(0, 10), (0, 11), (0, 12)]);
+        serial.sort_unstable();
+
+        for interleave in [Interleave::RoundRobin, Interleave::Priority] {
+            let mut schedule =
+                build_placement_schedule(pixel_lists.clone(), interleave, &priorities);
+            // Each item keeps its own pixel order
+            for (slot, pixels) in pixel_lists.iter().enumerate() {
+                let order: Vec<_> = schedule
+                    .iter()
+                    .filter(|(s, _)| *s == slot)
+                    .map(|(_, pixel)| *pixel)
+                    .collect();
+                assert_eq!(&order, pixels);
+            }
+            schedule.sort_unstable();
+            assert_eq!(schedule, serial);
+        }
+    }
+
+    #[test]
+    fn round_robin_takes_one_pixel_per_item_per_round() {
+        let schedule = build_placement_schedule(
+            vec![vec![1, 2], vec![3, 4]],
+            Interleave::RoundRobin,
+            &[3, 3],
+        );
+        assert_eq!(schedule, [(0, 1), (1, 3), (0, 2), (1, 4)]);
+    }
+
+    #[test]
+    fn priority_interleave_weights_pixels_per_round() {
+        // Priority 1 takes five pixels per round, priority 5 takes one
+        let schedule = build_placement_schedule(
+            vec![(0..7).collect(), (10..13).collect()],
+            Interleave::Priority,
+            &[1, 5],
+        );
+        assert_eq!(
+            schedule,
+            [
+                (0, 0),
+                (0, 1),
+                (0, 2),
+                (0, 3),
+                (0, 4),
+                (1, 10),
+                (0, 5),
+                (0, 6),
+                (1, 11),
+                (1, 12),
+            ]
+        );
+    }
+
+    #[test]
+    fn queue_pixel_placements_flash_the_placed_cell() {
+        let mut app = App::for_tests();
+        assert!(app.placement_flash.is_none());
+        app.handle_queue_update(QueueUpdate::PixelPlaced { position: (7, 9) });
+        assert_eq!(
+            app.placement_flash.map(|(position, _)| position),
+            Some((7, 9))
+        );
+    }
+
+    #[tokio::test]
+    async fn duplicates_keep_their_source_priority() {
+        let mut app = App::for_tests();
+        let mut source = queue_item(vec![pixel(0, 0, 1)], QueueStatus::Complete);
+        source.id = 1;
+        source.priority = 1;
+        app.art_queue.push(source);
+        app.art_queue
+            .push(queue_item(vec![pixel(0, 0, 1)], QueueStatus::Pending));
+
+        app.duplicate_selected_queue_item();
+        let art = app.loaded_art.take().unwrap();
+        assert!(app.add_art_to_queue(art).await);
+
+        let copy = app.art_queue.iter().find(|item| item.id > 1).unwrap();
+        assert_eq!(copy.priority, 1);
+        assert_eq!(copy.status, QueueStatus::Pending);
+        assert!(app.duplicate_priority.is_none());
+    }
+
+    #[tokio::test]
+    async fn queue_runs_in_the_session_in_front() {
+        let mut app = App::for_tests();
+        app.toggle_secondary_session();
+        app.api_client =
+            crate::api_client::ApiClient::new(Some("http://127.0.0.1:9".to_string()), None, None);
+        app.art_queue
+            .push(queue_item(vec![pixel(0, 0, 1)], QueueStatus::Pending));
+
+        app.trigger_queue_processing();
+        assert!(app.queue_processing);
+
+        // Its updates land in the active session, so it stays in front until the run ends
+        app.switch_session();
+        assert!(app.secondary_active);
+        app.cancel_queue_processing();
+        app.switch_session();
+        assert!(!app.secondary_active);
+    }
+
+    #[test]
+    fn offline_queued_arts_are_remapped_once_a_server_palette_is_known() {
+        let color = |id, red, green, blue| ColorInfo {
+            id,
+            name: String::new(),
+            red,
+            green,
+            blue,
+        };
+        let mut app = App::for_tests();
+        app.colors = crate::art::default_palette();
+        let black = app.colors.iter().find(|c| c.name == "Black").unwrap().id;
+        let mut item = queue_item(vec![pixel(0, 0, black)], QueueStatus::Pending);
+        item.art.default_palette = true;
+        app.art_queue.push(item);
+
+        // Still offline: nothing to remap to yet
+        app.remap_offline_queue_arts();
+        assert!(app.art_queue[0].art.default_palette);
+
+        app.colors = vec![color(40, 250, 250, 250), color(41, 10, 10, 10)];
+        app.remap_offline_queue_arts();
+        let art = &app.art_queue[0].art;
+        assert!(!art.default_palette);
+        assert_eq!(art.pattern[0].color, 41);
+    }
+
+    #[test]
+    fn truncated_compressed_queue_is_backed_up() {
+        let mut app = App::for_tests();
+        let queue_dir = crate::paths::data_path("queue");
+        std::fs::create_dir_all(&queue_dir).unwrap();
+        // A gzip header with the stream cut off
+        std::fs::write(queue_dir.join("queue.json"), [0x1f, 0x8b, 0x08, 0x00]).unwrap();
+
+        app.load_queue().unwrap();
+
+        assert!(app.art_queue.is_empty());
+        assert!(!queue_dir.join("queue.json").exists());
+        let backups = std::fs::read_dir(&queue_dir).unwrap().count();
+        assert_eq!(backups, 1);
+    }
+
+    #[test]
+    fn cancelling_the_queue_closes_the_run_report() {
+        let mut app = App::for_tests();
+        app.queue_processing = true;
+        app.start_run_report();
+        app.report_item_started(0, "test");
+
+        app.cancel_queue_processing();
+        assert!(app.run_report.is_none());
+        assert!(!app.queue_processing);
+    }
+
+    #[test]
+    fn cancelling_the_queue_logs_a_cancelled_event() {
+        let mut app = App::for_tests();
+        app.queue_processing = true;
+        app.start_run_report();
+        app.report_item_started(4, "cancel-log-test");
+        app.report_item_finished(4, "cancel-log-test", RunItemStatus::Completed, None);
+
+        let log = crate::event_log::capture_events(|| app.cancel_queue_processing());
+        assert!(log.iter().any(|line| {
+            line.contains(r#""event":"queue_cancelled""#) && line.contains(r#""items_processed":1"#)
+        }));
+    }
+}
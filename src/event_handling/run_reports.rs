@@ -0,0 +1,134 @@
+use crate::app_state::{App, RunItemStatus, RunReport, RunReportItem};
+use std::path::PathBuf;
+use std::time::Instant;
+
+impl App {
+    /// Begin recording a queue run
+    pub fn start_run_report(&mut self) {
+        self.run_report = Some(RunReport {
+            started_at: chrono::Local::now().to_rfc3339(),
+            ended_at: String::new(),
+            outcome: String::new(),
+            duration_secs: 0,
+            items: Vec::new(),
+            items_completed: 0,
+            items_failed: 0,
+            items_skipped: 0,
+            total_pixels_placed: 0,
+            started: Instant::now(),
+        });
+    }
+
+    /// Open an item's entry; it stays "interrupted" unless it completes, fails or is skipped
+    pub fn report_item_started(&mut self, item_index: usize, art_name: &str) {
+        let Some(report) = &mut self.run_report else {
+            return;
+        };
+        report.items.push(RunReportItem {
+            name: art_name.to_string(),
+            status: RunItemStatus::Interrupted,
+            pixels_placed: 0,
+            pixels_total: 0,
+            duration_secs: 0,
+            reason: None,
+            queue_index: item_index,
+            started: Instant::now(),
+        });
+    }
+
+    /// Record how an item ended; items skipped before starting get their own entry
+    pub fn report_item_finished(
+        &mut self,
+        item_index: usize,
+        art_name: &str,
+        status: RunItemStatus,
+        reason: Option<String>,
+    ) {
+        let Some(report) = &mut self.run_report else {
+            return;
+        };
+        let position = report
+            .items
+            .iter()
+            .rposition(|item| item.queue_index == item_index && item.status == RunItemStatus::Interrupted);
+        let item = match position {
+            Some(position) => &mut report.items[position],
+            None => {
+                report.items.push(RunReportItem {
+                    name: art_name.to_string(),
+                    status,
+                    pixels_placed: 0,
+                    pixels_total: 0,
+                    duration_secs: 0,
+                    reason: None,
+                    queue_index: item_index,
+                    started: Instant::now(),
+                });
+                report.items.last_mut().unwrap()
+            }
+        };
+        item.status = status;
+        item.reason = reason;
+        item.duration_secs = item.started.elapsed().as_secs();
+        if let Some(queue_item) = self.art_queue.get(item_index) {
+            item.pixels_placed = queue_item.pixels_placed;
+            item.pixels_total = queue_item.pixels_total;
+        }
+    }
+
+    /// Close the run and write it to `reports/run_<timestamp>.json`; without a total
+    /// from the queue task, the items' placed pixels are summed.
+    /// Returns the items that finished and the pixels placed, if a run was open.
+    pub fn finish_run_report(
+        &mut self,
+        outcome: &str,
+        total_pixels_placed: Option<usize>,
+    ) -> Option<(usize, usize)> {
+        let mut report = self.run_report.take()?;
+
+        // Items still open were cut short by a cancel or a failure elsewhere
+        for item in report
+            .items
+            .iter_mut()
+            .filter(|i| i.status == RunItemStatus::Interrupted)
+        {
+            item.duration_secs = item.started.elapsed().as_secs();
+            if let Some(queue_item) = self.art_queue.get(item.queue_index) {
+                item.pixels_placed = queue_item.pixels_placed;
+                item.pixels_total = queue_item.pixels_total;
+            }
+        }
+
+        let count = |status| report.items.iter().filter(|i| i.status == status).count();
+        report.items_completed = count(RunItemStatus::Completed);
+        report.items_failed = count(RunItemStatus::Failed);
+        report.items_skipped = count(RunItemStatus::Skipped);
+        report.outcome = outcome.to_string();
+        report.total_pixels_placed = total_pixels_placed
+            .unwrap_or_else(|| report.items.iter().map(|i| i.pixels_placed).sum());
+        report.duration_secs = report.started.elapsed().as_secs();
+        report.ended_at = chrono::Local::now().to_rfc3339();
+
+        match write_run_report(&report) {
+            Ok(path) => {
+                self.add_status_message(format!("📝 Run report saved to {}", path.display()))
+            }
+            Err(e) => self.add_status_message(format!("❌ Could not save run report: {}", e)),
+        }
+        Some((
+            report.items_completed + report.items_failed + report.items_skipped,
+            report.total_pixels_placed,
+        ))
+    }
+}
+
+fn write_run_report(report: &RunReport) -> Result<PathBuf, Box<dyn std::error::Error>> {
+    let dir = crate::paths::data_path("reports");
+    std::fs::create_dir_all(&dir)?;
+    let path = dir.join(format!(
+        "run_{}.json",
+        chrono::Local::now().format("%Y%m%d-%H%M%S")
+    ));
+    std::fs::write(&path, serde_json::to_string_pretty(report)?)?;
+    Ok(path)
+}
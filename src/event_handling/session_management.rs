@@ -0,0 +1,320 @@
+use crate::api_client::ApiClient;
+use crate::app_state::{App, BoardFetchResult, BoardSession, ConnectionState, InputMode};
+use std::time::{Duration, Instant};
+use tokio::sync::mpsc;
+
+impl App {
+    /// Open a second session (or close it if it already exists)
+    pub fn toggle_secondary_session(&mut self) {
+        if self.secondary_session.is_some() {
+            // The first session always survives, so bring it back to the front first
+            if self.secondary_active {
+                if self.session_switch_blocked() {
+                    return;
+                }
+                self.switch_session();
+            }
+            if let Some(session) = self.secondary_session.take() {
+                self.add_status_message(format!(
+                    "🗂️ Closed second session {}.",
+                    session.api_client.get_base_url()
+                ));
+            }
+            return;
+        }
+
+        if self.session_switch_blocked() {
+            return;
+        }
+
+        // No refresh callback: the token file keeps the first session's tokens
+        let previous = self.swap_active_session(BoardSession {
+            api_client: ApiClient::new(None, None, None),
+            board: Vec::new(),
+            colors: Vec::new(),
+            user_info: None,
+            board_viewport_x: 0,
+            board_viewport_y: 0,
+            initial_board_fetched: false,
+            last_board_refresh: None,
+            shared_board_state: None,
+            watched_pixels: Vec::new(),
+        });
+        self.add_status_message(format!(
+            "🗂️ Opened a second session; {} kept in the background (Tab to switch).",
+            previous.api_client.get_base_url()
+        ));
+        self.secondary_session = Some(previous);
+        self.secondary_active = true;
+
+        // Configure the new session with the usual Base URL and token prompts
+        self.input_mode = InputMode::EnterBaseUrl;
+        self.status_message = "Select API Base URL for the second session:".to_string();
+        self.base_url_selection_index = 0;
+        self.input_buffer.clear();
+    }
+
+    /// Swap the active session with the background one
+    pub fn switch_session(&mut self) {
+        if self.secondary_session.is_none() {
+            self.status_message = "No second session open. Press B to open one.".to_string();
+            return;
+        }
+
+        if self.session_switch_blocked() {
+            return;
+        }
+
+        self.save_viewport();
+        if let Some(background) = self.secondary_session.take() {
+            let previous = self.swap_active_session(background);
+            self.secondary_session = Some(previous);
+            self.secondary_active = !self.secondary_active;
+        }
+
+        self.add_status_message(format!(
+            "🗂️ Switched to session {}.",
+            self.api_client.get_base_url()
+        ));
+
+        if self.initial_board_fetched {
+            self.trigger_board_fetch();
+        }
+    }
+
+    /// Keep the background session's board current, at the same pace as the active one
+    pub fn refresh_background_session(&mut self) {
+        if self.background_fetch_receiver.is_some() {
+            return;
+        }
+        let Some(session) = &self.secondary_session else {
+            return;
+        };
+        let recently_refreshed = session
+            .last_board_refresh
+            .is_some_and(|last_refresh| last_refresh.elapsed() < Duration::from_secs(10));
+        if !session.initial_board_fetched || recently_refreshed {
+            return;
+        }
+
+        let base_url = session.api_client.get_base_url();
+        let (access_token, refresh_token) = session.api_client.get_tokens();
+        // The session in the background is the first one while the second is in front
+        let persist_tokens = self.secondary_active && !self.env_credentials;
+        let (tx, rx) = mpsc::unbounded_channel();
+        self.background_fetch_receiver = Some(rx);
+
+        tokio::spawn(async move {
+            let mut api_client = ApiClient::new(Some(base_url), access_token, refresh_token);
+            if let Ok(callback) = crate::api_client::session_token_refresh_callback(persist_tokens)
+            {
+                api_client.set_token_refresh_callback(callback);
+            }
+
+            let initial_tokens = api_client.get_tokens();
+            let result = match api_client.get_board().await {
+                Ok(board_response) => {
+                    let tokens = api_client.get_tokens();
+                    BoardFetchResult::Success {
+                        board_response,
+                        updated_tokens: (tokens != initial_tokens).then_some(tokens),
+                    }
+                }
+                Err(e) => BoardFetchResult::Error {
+                    message: e.summary(),
+                    status: e.status_code(),
+                },
+            };
+            let _ = tx.send(result);
+        });
+    }
+
+    /// Store a background refresh in the session it was made for
+    pub fn handle_background_fetch_result(&mut self, result: BoardFetchResult) {
+        self.background_fetch_receiver = None;
+        let Some(session) = &mut self.secondary_session else {
+            return;
+        };
+        // Failures retry on the next interval without filling the status log
+        session.last_board_refresh = Some(Instant::now());
+
+        if let BoardFetchResult::Success {
+            board_response,
+            updated_tokens,
+        } = result
+        {
+            if let Some((access_token, refresh_token)) = updated_tokens {
+                session.api_client.set_tokens(access_token, refresh_token);
+            }
+            if !board_response.colors.is_empty() {
+                session.colors = board_response.colors;
+            }
+            session.board = board_response.board;
+        }
+    }
+
+    /// Ask before logging out, since it discards the session
+    pub fn request_logout(&mut self) {
+        if self.queue_processing || self.placement_in_progress {
+            self.status_message =
+                "Can't log out while pixels are being placed - cancel the queue first.".to_string();
+            return;
+        }
+        self.input_mode = InputMode::LogoutConfirm;
+        self.status_message = "Log out and clear the saved tokens? (y/n)".to_string();
+    }
+
+    /// Forget the tokens (in memory and on disk) and this account's data, then return to setup
+    pub fn logout(&mut self) {
+        let base_url = self.api_client.get_base_url();
+        // The validation task holds its own copy of the tokens
+        if self.validation_enabled {
+            self.stop_validation();
+        }
+        self.api_client.clear_tokens();
+        self.clear_saved_tokens();
+        self.user_info = None;
+        self.board.clear();
+        self.board_info = None;
+        self.shared_board_state = None;
+        self.initial_board_fetched = false;
+        self.last_board_refresh = None;
+        self.last_full_board_fetch = None;
+        self.board_delta_supported = None;
+        self.connection_state = ConnectionState::Unknown;
+        self.add_status_message(format!("🔒 Logged out of {}; tokens cleared.", base_url));
+
+        self.input_mode = InputMode::EnterBaseUrl;
+        self.status_message = "Select API Base URL or choose Custom:".to_string();
+        self.base_url_selection_index = 0;
+        self.input_buffer.clear();
+    }
+
+    /// Background tasks, the queue included, run against the active session's client and
+    /// board and report into it, so don't swap while any are running
+    fn session_switch_blocked(&mut self) -> bool {
+        let busy = if self.queue_processing {
+            Some("queue processing")
+        } else if self.placement_in_progress {
+            Some("art placement")
+        } else if self.board_loading {
+            Some("a board fetch")
+        } else if self.profile_receiver.is_some() {
+            Some("a profile fetch")
+        } else {
+            None
+        };
+
+        if let Some(task) = busy {
+            self.status_message = format!("Can't switch sessions during {}.", task);
+            true
+        } else {
+            false
+        }
+    }
+
+    /// Install `session` as the active one and return the previously active session
+    fn swap_active_session(&mut self, session: BoardSession) -> BoardSession {
+        let previous = BoardSession {
+            api_client: std::mem::replace(&mut self.api_client, session.api_client),
+            board: std::mem::replace(&mut self.board, session.board),
+            colors: std::mem::replace(&mut self.colors, session.colors),
+            user_info: std::mem::replace(&mut self.user_info, session.user_info),
+            board_viewport_x: std::mem::replace(
+                &mut self.board_viewport_x,
+                session.board_viewport_x,
+            ),
+            board_viewport_y: std::mem::replace(
+                &mut self.board_viewport_y,
+                session.board_viewport_y,
+            ),
+            initial_board_fetched: std::mem::replace(
+                &mut self.initial_board_fetched,
+                session.initial_board_fetched,
+            ),
+            last_board_refresh: std::mem::replace(
+                &mut self.last_board_refresh,
+                session.last_board_refresh,
+            ),
+            shared_board_state: std::mem::replace(
+                &mut self.shared_board_state,
+                session.shared_board_state,
+            ),
+            watched_pixels: std::mem::replace(&mut self.watched_pixels, session.watched_pixels),
+        };
+        self.board_info = None;
+        // A background refresh in flight was made for the session that is now in front
+        self.background_fetch_receiver = None;
+        // Delta state belongs to the previous server; start over with a full fetch
+        self.last_full_board_fetch = None;
+        self.board_delta_supported = None;
+        self.load_palette_names();
+        previous
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::app_state::{App, BoardFetchResult, WatchedPixel};
+
+    #[test]
+    fn watch_list_stays_with_the_first_session() {
+        let mut app = App::for_tests();
+        app.watched_pixels.push(WatchedPixel {
+            x: 1,
+            y: 2,
+            last_color: None,
+        });
+
+        app.toggle_secondary_session();
+        assert!(app.secondary_active);
+        assert!(app.watched_pixels.is_empty());
+        assert!(app.shared_board_state.is_none());
+
+        app.switch_session();
+        assert!(!app.secondary_active);
+        assert_eq!(app.watched_pixels.len(), 1);
+
+        // Closing from the second session brings the first one back
+        app.switch_session();
+        app.toggle_secondary_session();
+        assert!(!app.secondary_active);
+        assert!(app.secondary_session.is_none());
+        assert_eq!(app.watched_pixels.len(), 1);
+    }
+
+    #[test]
+    fn second_session_leaves_the_saved_tokens_alone() {
+        let mut app = App::for_tests();
+        let saved = app.token_storage.load().0.access_token;
+
+        app.toggle_secondary_session();
+        assert!(!app.persists_tokens());
+        app.api_client
+            .set_tokens(Some("second-session-token".to_string()), None);
+        app.save_tokens();
+        app.clear_saved_tokens();
+        assert_eq!(app.token_storage.load().0.access_token, saved);
+    }
+
+    #[test]
+    fn background_refresh_lands_in_the_background_session() {
+        let mut app = App::for_tests();
+        app.toggle_secondary_session();
+
+        let board_response = serde_json::from_value(serde_json::json!({
+            "colors": [],
+            "board": [[null, null], [null, null], [null, null]],
+        }))
+        .unwrap();
+        app.handle_background_fetch_result(BoardFetchResult::Success {
+            board_response,
+            updated_tokens: None,
+        });
+
+        let background = app.secondary_session.as_ref().unwrap();
+        assert_eq!(background.board.len(), 3);
+        assert!(background.last_board_refresh.is_some());
+        assert!(app.board.is_empty());
+    }
+}
@@ -1,4 +1,6 @@
-use crate::app_state::{App, ArtQueueItem, QueueStatus, ValidationControl, ValidationUpdate};
+use crate::app_state::{
+    App, ArtQueueItem, QueueStatus, SkipRules, ValidationControl, ValidationUpdate,
+};
 use crate::art::PixelArt;
 use std::time::{Duration, Instant};
 use tokio::sync::mpsc;
@@ -60,6 +62,83 @@ impl App {
         }
     }
 
+    /// Report how much of each item from the last run still holds on the board,
+    /// re-queueing the ones below `integrity_threshold`
+    pub fn run_integrity_check(&mut self) {
+        let Some(threshold) = self.integrity_threshold else {
+            return;
+        };
+        if self.integrity_check_items.is_empty() {
+            return;
+        }
+
+        let mut checked = 0;
+        let mut requeued = 0;
+        for index in std::mem::take(&mut self.integrity_check_items) {
+            let Some(art) = self
+                .art_queue
+                .get(index)
+                .filter(|item| item.status == QueueStatus::Complete)
+                .map(|item| item.art.clone())
+            else {
+                continue;
+            };
+            let pixels = self.filter_meaningful_pixels(&art);
+            if pixels.is_empty() {
+                continue;
+            }
+            let correct = pixels
+                .iter()
+                .filter(|p| {
+                    self.is_pixel_already_correct(art.board_x + p.x, art.board_y + p.y, p.color)
+                })
+                .count();
+            let integrity = (correct * 100 / pixels.len()) as u32;
+            checked += 1;
+            crate::event_log::log_event(crate::event_log::LogEvent::ItemIntegrity {
+                index,
+                art: art.name.clone(),
+                pixels_correct: correct,
+                total_pixels: pixels.len(),
+                requeued: integrity < threshold,
+            });
+
+            if integrity < threshold {
+                if let Some(item) = self.art_queue.get_mut(index) {
+                    item.status = QueueStatus::Pending;
+                    item.pixels_placed = correct;
+                }
+                requeued += 1;
+                self.add_status_message(format!(
+                    "🔍 Integrity: '{}' {}% ({}/{} pixels) - below {}%, re-queued",
+                    art.name,
+                    integrity,
+                    correct,
+                    pixels.len(),
+                    threshold
+                ));
+            } else {
+                self.add_status_message(format!(
+                    "🔍 Integrity: '{}' {}% ({}/{} pixels)",
+                    art.name,
+                    integrity,
+                    correct,
+                    pixels.len()
+                ));
+            }
+        }
+
+        if checked > 0 {
+            self.add_status_message(format!(
+                "🔍 Post-run check: {} items checked, {} re-queued (below {}%)",
+                checked, requeued, threshold
+            ));
+        }
+        if requeued > 0 {
+            let _ = self.save_queue();
+        }
+    }
+
     /// Start periodic validation of completed queue items
     pub fn start_validation(&mut self) {
         if self.validation_enabled {
@@ -98,7 +177,9 @@ impl App {
         let base_url = self.api_client.get_base_url();
         let access_token = self.api_client.get_access_token_clone();
         let refresh_token = self.api_client.get_refresh_token_clone();
+        let persist_tokens = self.persists_tokens();
         let colors = self.colors.clone();
+        let skip_rules = self.skip_rules.clone();
 
         // Get completed queue items to validate
         let completed_items: Vec<(usize, ArtQueueItem)> = self
@@ -121,7 +202,8 @@ impl App {
             let mut control_rx = control_rx;
 
             // Set up callback to save refreshed tokens to storage
-            if let Ok(callback) = crate::api_client::create_token_refresh_callback(None) {
+            if let Ok(callback) = crate::api_client::session_token_refresh_callback(persist_tokens)
+            {
                 api_client.set_token_refresh_callback(callback);
             }
             // Note: We don't fail the validation if callback setup fails
@@ -160,7 +242,10 @@ impl App {
                     Ok(board_response) => board_response.board,
                     Err(e) => {
                         let _ = tx.send(ValidationUpdate::ValidationError {
-                            error_msg: format!("Failed to fetch board for validation: {:?}", e),
+                            error_msg: format!(
+                                "Failed to fetch board for validation: {}",
+                                e.summary()
+                            ),
                         });
                         continue;
                     }
@@ -171,8 +256,11 @@ impl App {
                 // Validate each completed item
                 for (original_index, queue_item) in &completed_items {
                     // Filter meaningful pixels for this art
-                    let meaningful_pixels =
-                        filter_meaningful_pixels_for_validation(&queue_item.art, &colors);
+                    let meaningful_pixels = filter_meaningful_pixels_for_validation(
+                        &queue_item.art,
+                        &colors,
+                        &skip_rules,
+                    );
                     let total_meaningful_pixels = meaningful_pixels.len();
 
                     // Count how many pixels are still correct
@@ -248,23 +336,13 @@ impl App {
 fn filter_meaningful_pixels_for_validation(
     art: &PixelArt,
     colors: &[crate::api_client::ColorInfo],
+    rules: &SkipRules,
 ) -> Vec<crate::art::ArtPixel> {
     let mut meaningful_pixels = Vec::new();
     let mut seen_positions = std::collections::HashSet::new();
 
     // Define background color IDs that should not be placed
-    let mut background_color_ids = std::collections::HashSet::new();
-    for color in colors {
-        let name_lower = color.name.to_lowercase();
-        if name_lower.contains("transparent")
-            || name_lower.contains("background")
-            || name_lower.contains("empty")
-            || name_lower == "none"
-            || name_lower.contains("alpha")
-        {
-            background_color_ids.insert(color.id);
-        }
-    }
+    let background_color_ids = rules.background_color_ids(colors);
 
     for pixel in &art.pattern {
         // Skip if this position was already processed (remove duplicates)
@@ -0,0 +1,136 @@
+use crate::app_state::{App, WatchedPixel};
+
+impl App {
+    /// Watch (or stop watching) the board pixel that was clicked last
+    pub fn toggle_watch_at_cursor(&mut self) {
+        let Some((x, y)) = self.board_cursor else {
+            self.status_message =
+                "Click a board pixel first, then press 'W' to watch it.".to_string();
+            return;
+        };
+
+        if let Some(index) = self
+            .watched_pixels
+            .iter()
+            .position(|watched| watched.x == x && watched.y == y)
+        {
+            self.watched_pixels.remove(index);
+            self.status_message = format!(
+                "Stopped watching ({}, {}). {} pixels watched.",
+                x,
+                y,
+                self.watched_pixels.len()
+            );
+        } else {
+            let last_color = self.board_color_at(x, y);
+            self.watched_pixels.push(WatchedPixel { x, y, last_color });
+            self.status_message = format!(
+                "👁️ Watching ({}, {}). {} pixels watched.",
+                x,
+                y,
+                self.watched_pixels.len()
+            );
+        }
+
+        if let Err(e) = self.save_watch_list() {
+            self.add_status_message(format!("❌ Could not save watch list: {}", e));
+        }
+    }
+
+    /// Compare watched pixels against the freshly loaded board and report any that changed
+    pub fn check_watched_pixels(&mut self) {
+        if self.watched_pixels.is_empty() || self.board.is_empty() {
+            return;
+        }
+
+        let mut changes = Vec::new();
+        for index in 0..self.watched_pixels.len() {
+            let WatchedPixel { x, y, last_color } = self.watched_pixels[index];
+            let current_color = self.board_color_at(x, y);
+            if current_color == last_color {
+                continue;
+            }
+
+            changes.push(format!(
+                "({}, {}) {} → {}",
+                x,
+                y,
+                self.color_label(last_color),
+                self.color_label(current_color)
+            ));
+            self.watched_pixels[index].last_color = current_color;
+        }
+
+        if changes.is_empty() {
+            return;
+        }
+
+        let message = format!(
+            "👁️ Watched pixel{} changed: {}",
+            if changes.len() == 1 { "" } else { "s" },
+            changes.join(", ")
+        );
+        self.add_status_message(message.clone());
+        self.status_message = message.clone();
+        self.send_watch_webhook(message);
+
+        let _ = self.save_watch_list();
+    }
+
+    /// Load the watch list from `watch.json` in the data directory
+    pub fn load_watch_list(&mut self) -> Result<(), Box<dyn std::error::Error>> {
+        let watch_path = crate::paths::data_path("watch.json");
+        if watch_path.exists() {
+            let watch_data = std::fs::read_to_string(&watch_path)?;
+            self.watched_pixels = serde_json::from_str(&watch_data)?;
+        }
+        Ok(())
+    }
+
+    fn save_watch_list(&self) -> Result<(), Box<dyn std::error::Error>> {
+        // watch.json holds the first session's list; the second one lives in memory
+        if self.secondary_active {
+            return Ok(());
+        }
+        let watch_data = serde_json::to_string_pretty(&self.watched_pixels)?;
+        std::fs::write(crate::paths::data_path("watch.json"), watch_data)?;
+        Ok(())
+    }
+
+    /// POST the alert to FTPLACE_WATCH_WEBHOOK, if configured (fire and forget). Goes
+    /// through the shared client so the proxy, TLS and timeout settings apply.
+    fn send_watch_webhook(&self, message: String) {
+        let Some(webhook_url) = self.watch_webhook.clone() else {
+            return;
+        };
+
+        tokio::spawn(async move {
+            let _ = crate::api_client::http_client()
+                .post(webhook_url)
+                .json(&serde_json::json!({ "text": message }))
+                .send()
+                .await;
+        });
+    }
+
+    fn board_color_at(&self, x: i32, y: i32) -> Option<i32> {
+        if x < 0 || y < 0 {
+            return None;
+        }
+        self.board
+            .get(x as usize)
+            .and_then(|column| column.get(y as usize))
+            .and_then(|pixel| pixel.as_ref())
+            .map(|pixel| pixel.c)
+    }
+
+    pub fn color_label(&self, color_id: Option<i32>) -> String {
+        match color_id {
+            Some(id) => self.colors.iter().find(|color| color.id == id).map_or_else(
+                || format!("#{}", id),
+                |color| self.color_display_name(color),
+            ),
+            None => "empty".to_string(),
+        }
+    }
+}
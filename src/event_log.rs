@@ -0,0 +1,141 @@
+use std::io::Write;
+use std::path::PathBuf;
+use std::sync::{Mutex, OnceLock};
+
+/// Where `--log-json` writes events
+pub enum LogTarget {
+    File(PathBuf),
+    Stderr, // `--log-json=-`; stdout is taken by the interface
+}
+
+static EVENT_LOG: OnceLock<Mutex<Box<dyn Write + Send>>> = OnceLock::new();
+
+#[cfg(test)]
+thread_local! {
+    // Lines logged on this test thread while `capture_events` runs
+    static CAPTURED: std::cell::RefCell<Option<Vec<String>>> = const { std::cell::RefCell::new(None) };
+}
+
+/// Significant events for external monitoring, written one JSON object per line
+#[derive(Debug, serde::Serialize)]
+#[serde(tag = "event", rename_all = "snake_case")]
+pub enum LogEvent {
+    PixelPlaced {
+        x: i32,
+        y: i32,
+        color: i32,
+    },
+    PlacementFailed {
+        x: i32,
+        y: i32,
+        color: i32,
+        error: String,
+    },
+    Error {
+        message: String,
+    },
+    QueueStarted {
+        pending_items: usize,
+        pixels_to_place: usize,
+        force: bool,
+    },
+    ItemStarted {
+        index: usize,
+        art: String,
+    },
+    ItemCompleted {
+        index: usize,
+        art: String,
+        pixels_placed: usize,
+        total_pixels: usize,
+    },
+    ItemFailed {
+        index: usize,
+        art: String,
+        error: String,
+    },
+    ItemSkipped {
+        index: usize,
+        art: String,
+        reason: String,
+    },
+    QueueCompleted {
+        items_processed: usize,
+        pixels_placed: usize,
+        duration_secs: u64,
+    },
+    QueueCancelled {
+        items_processed: usize,
+        pixels_placed: usize,
+    },
+    ItemIntegrity {
+        index: usize,
+        art: String,
+        pixels_correct: usize,
+        total_pixels: usize,
+        requeued: bool,
+    },
+}
+
+#[derive(serde::Serialize)]
+struct LogLine<'a> {
+    timestamp: String, // RFC 3339, UTC
+    #[serde(flatten)]
+    event: &'a LogEvent,
+}
+
+/// Turn on JSON event logging once at startup; files are appended to
+pub fn init_event_log(target: LogTarget) -> std::io::Result<()> {
+    let sink: Box<dyn Write + Send> = match target {
+        LogTarget::File(path) => {
+            if let Some(parent) = path.parent() {
+                std::fs::create_dir_all(parent)?;
+            }
+            Box::new(
+                std::fs::OpenOptions::new()
+                    .create(true)
+                    .append(true)
+                    .open(path)?,
+            )
+        }
+        LogTarget::Stderr => Box::new(std::io::stderr()),
+    };
+    let _ = EVENT_LOG.set(Mutex::new(sink));
+    Ok(())
+}
+
+/// Write `event` as one JSON line; a no-op unless `--log-json` was given
+pub fn log_event(event: LogEvent) {
+    let line = LogLine {
+        timestamp: chrono::Utc::now().to_rfc3339(),
+        event: &event,
+    };
+    #[cfg(test)]
+    {
+        let captured = CAPTURED.with(|captured| match captured.borrow_mut().as_mut() {
+            Some(lines) => {
+                lines.extend(serde_json::to_string(&line));
+                true
+            }
+            None => false,
+        });
+        if captured {
+            return;
+        }
+    }
+    let Some(sink) = EVENT_LOG.get() else {
+        return;
+    };
+    if let (Ok(json), Ok(mut sink)) = (serde_json::to_string(&line), sink.lock()) {
+        let _ = writeln!(sink, "{}", json);
+        let _ = sink.flush();
+    }
+}
+
+/// Run `f` and return the JSON lines it logged on this thread, without touching the global sink
+#[cfg(test)]
+pub fn capture_events(f: impl FnOnce()) -> Vec<String> {
+    CAPTURED.with(|captured| *captured.borrow_mut() = Some(Vec::new()));
+    f();
+    CAPTURED.with(|captured| captured.borrow_mut().take().unwrap_or_default())
+}
@@ -1,4 +1,4 @@
-use std::collections::VecDeque;
+use std::collections::{HashMap, VecDeque};
 use std::io::{self, stdout};
 
 use crossterm::{
@@ -12,10 +12,14 @@ mod api_client;
 mod app_state;
 mod art;
 mod event_handling;
+mod event_log;
+mod palette_names;
+mod paths;
 mod token_storage;
 mod ui;
 use api_client::ApiClient;
-use app_state::{App, InputMode};
+use app_state::{App, ConnectionState, InputMode, Interleave};
+use palette_names::PaletteNames;
 use token_storage::TokenStorage;
 
 impl App {
@@ -31,7 +35,10 @@ impl App {
         };
 
         // Load saved tokens
-        let saved_tokens = token_storage.load();
+        let (mut saved_tokens, token_recovery_note) = token_storage.load();
+
+        // Environment variables (for containers/CI) take precedence over the saved file
+        let env_credentials = saved_tokens.apply_env_overrides();
 
         // Initialize API client with saved tokens and base URL
         let mut api_client = ApiClient::new(
@@ -40,13 +47,15 @@ impl App {
             saved_tokens.refresh_token.clone(),
         );
 
-        // Set up callback to save refreshed tokens to storage
-        if let Ok(callback) =
-            crate::api_client::create_token_refresh_callback(saved_tokens.base_url.clone())
-        {
-            api_client.set_token_refresh_callback(callback);
-        } else {
-            eprintln!("Warning: Could not set up token refresh callback");
+        // Set up callback to save refreshed tokens to storage; env credentials stay out of it
+        if !env_credentials {
+            if let Ok(callback) =
+                crate::api_client::create_token_refresh_callback(saved_tokens.base_url.clone())
+            {
+                api_client.set_token_refresh_callback(callback);
+            } else {
+                eprintln!("Warning: Could not set up token refresh callback");
+            }
         }
 
         let base_url_options = vec![
@@ -65,7 +74,12 @@ impl App {
                 (
                     InputMode::ShowHelp,
                     format!(
-                        "Restored session: {}. Press any key to continue or 'c' to reconfigure.",
+                        "{} session: {}. Press any key to continue or 'c' to reconfigure.",
+                        if env_credentials {
+                            "Environment"
+                        } else {
+                            "Restored"
+                        },
                         saved_tokens.base_url.as_deref().unwrap_or("Unknown URL")
                     ),
                     true, // Trigger board fetch
@@ -79,24 +93,88 @@ impl App {
                 )
             };
 
+        // Status log cap, overridable for long unattended runs
+        let status_log_capacity = std::env::var("FTPLACE_STATUS_LOG_CAPACITY")
+            .ok()
+            .and_then(|value| value.parse::<usize>().ok())
+            .filter(|&capacity| capacity > 0)
+            .unwrap_or(event_handling::helpers::DEFAULT_STATUS_LOG_CAPACITY);
+
+        // Parallel placements are opt-in; servers may not tolerate them
+        let placement_concurrency = std::env::var("FTPLACE_PLACEMENT_CONCURRENCY")
+            .ok()
+            .and_then(|value| value.parse::<usize>().ok())
+            .map_or(1, |concurrency| {
+                concurrency.clamp(
+                    1,
+                    event_handling::queue_management::MAX_PLACEMENT_CONCURRENCY,
+                )
+            });
+
+        // Pixels the queue leaves in the buffer for manual fixes
+        let reserve_buffer = std::env::var("FTPLACE_RESERVE_BUFFER")
+            .ok()
+            .and_then(|value| value.parse::<usize>().ok())
+            .unwrap_or(0);
+
+        // Politeness mode: never place faster than this many pixels per minute
+        let polite_rate = std::env::var("FTPLACE_MAX_PIXELS_PER_MINUTE")
+            .ok()
+            .and_then(|value| value.parse::<u32>().ok())
+            .filter(|&rate| rate > 0);
+
+        // Interleaved placement across queue items; 'i' switches it for the session
+        let queue_interleave = std::env::var("FTPLACE_INTERLEAVE")
+            .ok()
+            .and_then(|value| Interleave::from_config(&value))
+            .unwrap_or_default();
+
+        // Post-run integrity check: items holding less than this percentage get re-queued
+        let integrity_threshold = std::env::var("FTPLACE_INTEGRITY_CHECK")
+            .ok()
+            .and_then(|value| value.parse::<u32>().ok())
+            .filter(|percent| (1..=100).contains(percent));
+
+        // Gzip large save files; loading detects compressed files either way
+        let compress_saved_files = std::env::var("FTPLACE_COMPRESS")
+            .is_ok_and(|value| matches!(value.as_str(), "1" | "true" | "yes"));
+
         let mut app = Self {
             exit: false,
+            force_redraw: false,
             api_client,
             token_storage,
+            env_credentials,
             input_mode: initial_mode,
             input_buffer: String::new(),
             status_message: initial_message.clone(),
             status_messages: VecDeque::new(),
+            status_log_capacity,
+            compress_saved_files,
             cooldown_status: String::new(),
             board: Vec::new(),
             colors: Vec::new(),
+            palette_names: PaletteNames::default(),
             user_info: None,
             loaded_art: None,
+            snap_grid_size: None,
+            next_placement_order: Default::default(),
+            fit_region_active: false,
+            fit_region_corner: None,
+            capture_region_active: false,
+            capture_region_corner: None,
+            overlap_count_due: None,
             board_viewport_x: 0,
             board_viewport_y: 0,
             initial_board_fetched: false,
             last_board_refresh: None,
+            last_full_board_fetch: None,
+            board_last_pixel_time: None,
+            board_delta_supported: None,
+            board_fetch_is_delta: false,
+            empty_palette_retried: false,
             should_fetch_board_on_start: should_fetch_on_start,
+            connection_state: ConnectionState::Unknown,
             board_loading: false,
             board_load_start: None,
             board_fetch_receiver: None,
@@ -108,28 +186,81 @@ impl App {
             queue_control_sender: None,
             queue_processing_start: None,
             profile_receiver: None,
+            connection_test_receiver: None,
+            color_search_receiver: None,
+            secondary_session: None,
+            secondary_active: false,
+            background_fetch_receiver: None,
             base_url_options,
             base_url_selection_index: 0,
             current_editing_art: None,
+            editor_dirty: false,
+            editor_autosave_due: false,
+            editing_saved_art: false,
+            editor_last_autosave: None,
             art_editor_cursor_x: 0,
             art_editor_cursor_y: 0,
             art_editor_selected_color_id: 1,
             art_editor_color_palette_index: 0,
-            art_editor_canvas_width: 30,
-            art_editor_canvas_height: 20,
+            art_editor_canvas_width: app_state::EDITOR_CANVAS_SIZE.0,
+            art_editor_canvas_height: app_state::EDITOR_CANVAS_SIZE.1,
             art_editor_viewport_x: 0,
             art_editor_viewport_y: 0,
             available_pixel_arts: Vec::new(),
+            available_art_sizes: Vec::new(),
             art_selection_index: 0,
             art_preview_art: None,
             art_queue: Vec::new(),
             queue_selection_index: 0,
+            queue_list_offset: 0,
             queue_processing: false,
             queue_paused: false,
+            queue_interleave,
+            placement_concurrency,
+            reserve_buffer,
+            polite_rate,
+            integrity_threshold,
+            integrity_check_items: Vec::new(),
+            queue_active_index: None,
+            queue_active_position: None,
+            queue_cooldown: None,
+            moving_queue_item: None,
+            duplicate_priority: None,
+            resume_art: None,
+            help_return_mode: InputMode::None,
+            loaded_art_saved: None,
+            completed_overlay: Default::default(),
+            cooldown_strategy: Default::default(),
+            verify_placements: false,
+            board_cursor: None,
+            spectator_mode: false,
+            ascii_status: false,
+            placement_bell: false,
+            cooldown_bell: false,
+            cooldown_bell_armed: false,
+            empty_cells_only: false,
+            force_placement: false,
+            placement_flash: None,
+            queue_sidebar: false,
+            follow_queue: false,
+            watched_pixels: Vec::new(),
+            color_search: None,
+            snapshot_diff: None,
+            art_stats: HashMap::new(),
+            skip_rules: Default::default(),
+            run_report: None,
+            watch_webhook: std::env::var("FTPLACE_WATCH_WEBHOOK")
+                .ok()
+                .filter(|url| !url.is_empty()),
             queue_blink_state: false,
             last_blink_time: None,
             shared_board_state: None,
             board_area_bounds: None,
+            last_terminal_size: None,
+            spinner_frame: 0,
+            board_info: None,
+            compact_layout: None,
+            api_debug: false,
             available_shares: Vec::new(),
             share_selection_index: 0,
             current_share_art: None,
@@ -146,40 +277,152 @@ impl App {
             last_validation_time: None,
         };
 
-        // Load saved queue
-        let _ = app.load_queue();
+        // Colors from the last session with this server, until the board arrives
+        app.load_cached_palette();
 
-        // Load saved status messages
+        // Load saved status messages first so load warnings land after the old history
         let _ = app.load_status_messages();
 
+        app.load_skip_rules();
+
+        // Load saved queue
+        let _ = app.load_queue();
+        let _ = app.load_watch_list();
+        let _ = app.load_art_stats();
+        app.load_preferences();
+        app.offer_positioning_resume();
+        if api_client::tls_checks_disabled() {
+            app.add_status_message(
+                "⚠️ TLS certificate checks are DISABLED (--insecure) - only use this with servers you trust".to_string(),
+            );
+        }
+
         // Add initial status message if we have saved config
         if should_fetch_on_start {
             app.add_status_message(initial_message);
         }
+        if let Some(note) = token_recovery_note {
+            app.add_status_message(note);
+        }
 
         app
     }
 
     pub async fn run(&mut self, terminal: &mut Terminal<impl Backend>) -> io::Result<()> {
         while !self.exit {
+            // Ctrl+L: throw away the diff state so every cell is repainted
+            if std::mem::take(&mut self.force_redraw) {
+                terminal.clear()?;
+            }
             terminal.draw(|frame| ui::render_ui(self, frame))?;
             self.handle_events().await?;
         }
 
-        // Save status messages before exiting
+        // Save status messages and the viewport before exiting
         let _ = self.save_status_messages();
+        self.save_viewport();
 
         Ok(())
     }
 }
 
+/// Held while creating test apps, since `App::new` reads environment variables
+#[cfg(test)]
+static TEST_ENV: std::sync::Mutex<()> = std::sync::Mutex::new(());
+
+/// Numbers each test app's data dir so parallel tests never share files
+#[cfg(test)]
+static TEST_APP_COUNTER: std::sync::atomic::AtomicUsize = std::sync::atomic::AtomicUsize::new(0);
+
+#[cfg(test)]
+impl App {
+    /// A fresh app with its own scratch data dir, so tests never read real tokens or each other's files
+    pub fn for_tests() -> Self {
+        Self::for_tests_with_env(&[])
+    }
+
+    /// Like `for_tests`, with environment variables set only while the app is created
+    pub fn for_tests_with_env(vars: &[(&str, &str)]) -> Self {
+        let _env = TEST_ENV.lock().unwrap_or_else(|poisoned| poisoned.into_inner());
+        let id = TEST_APP_COUNTER.fetch_add(1, std::sync::atomic::Ordering::Relaxed);
+        let data_dir = std::env::temp_dir()
+            .join("ftplace_tui_tests")
+            .join(format!("{}-{}", std::process::id(), id));
+        let _ = std::fs::remove_dir_all(&data_dir);
+        paths::set_test_data_dir(data_dir);
+        for (name, value) in vars {
+            std::env::set_var(name, value);
+        }
+        let app = Self::new();
+        for (name, _) in vars {
+            std::env::remove_var(name);
+        }
+        app
+    }
+}
+
 #[tokio::main]
 async fn main() -> io::Result<()> {
+    // Resolve the data directory before anything touches the disk
+    let cli_args = match parse_cli_args(std::env::args().skip(1)) {
+        Ok(cli_args) => cli_args,
+        Err(message) => {
+            eprintln!("{}", message);
+            std::process::exit(2);
+        }
+    };
+    if let Err(message) = api_client::init_proxy(cli_args.proxy)
+        .and_then(|()| api_client::init_tls(cli_args.insecure, cli_args.ca_bundle))
+        .and_then(|()| api_client::init_http_client())
+    {
+        eprintln!("{}", message);
+        std::process::exit(2);
+    }
+    let data_dir = paths::init_data_dir(cli_args.data_dir);
+    if let Err(e) = std::fs::create_dir_all(data_dir) {
+        eprintln!(
+            "Warning: Could not create data directory {}: {}",
+            data_dir.display(),
+            e
+        );
+    }
+    if let Some(log_json) = cli_args.log_json {
+        let target = match log_json.as_deref() {
+            Some("-") => event_log::LogTarget::Stderr,
+            Some(path) => event_log::LogTarget::File(path.into()),
+            None => event_log::LogTarget::File(paths::data_path("logs/events.jsonl")),
+        };
+        if let Err(e) = event_log::init_event_log(target) {
+            eprintln!("Could not open the JSON event log: {}", e);
+            std::process::exit(2);
+        }
+    }
+
+    if cli_args.migrate_arts {
+        let report = art::migrate_art_library();
+        println!(
+            "Migrated {} art file(s), {} already up to date, {} failed (originals kept as .json.bak)",
+            report.migrated,
+            report.up_to_date,
+            report.failed.len()
+        );
+        for failure in &report.failed {
+            eprintln!("  {}", failure);
+        }
+        return Ok(());
+    }
+
     enable_raw_mode()?;
     let mut terminal = Terminal::new(CrosstermBackend::new(stdout()))?;
     execute!(stdout(), EnterAlternateScreen, EnableMouseCapture)?;
 
     let mut app = App::new();
+    if cli_args.spectator {
+        app.spectator_mode = true;
+        app.add_status_message(
+            "👁️ Spectator mode: placement is disabled (press 'S' to toggle).".to_string(),
+        );
+    }
     let res = app.run(&mut terminal).await;
 
     disable_raw_mode()?;
@@ -187,3 +430,84 @@ async fn main() -> io::Result<()> {
 
     res
 }
+
+/// Options accepted on the command line
+#[derive(Default)]
+struct CliArgs {
+    data_dir: Option<std::path::PathBuf>,
+    spectator: bool,       // Start read-only, with every placement path disabled
+    migrate_arts: bool,    // Rewrite saved arts in the current schema, then exit
+    proxy: Option<String>, // HTTP(S) proxy URL for every API request
+    insecure: bool,        // Accept invalid TLS certificates (self-signed dev servers)
+    ca_bundle: Option<std::path::PathBuf>, // Extra trusted CAs, PEM
+    log_json: Option<Option<String>>, // JSON event lines; inner None = logs/events.jsonl
+}
+
+/// Read `--data-dir <path>` (or `--data-dir=<path>`), `--proxy <url>`, `--ca-bundle <pem>`,
+/// `--insecure`, `--spectator`, `--migrate-arts` and `--log-json[=<path>|-]` from the command line
+fn parse_cli_args(mut args: impl Iterator<Item = String>) -> Result<CliArgs, String> {
+    let mut cli_args = CliArgs::default();
+    while let Some(arg) = args.next() {
+        if arg == "--data-dir" {
+            let value = args.next().ok_or("--data-dir needs a directory argument")?;
+            cli_args.data_dir = Some(value.into());
+        } else if let Some(value) = arg.strip_prefix("--data-dir=") {
+            cli_args.data_dir = Some(value.into());
+        } else if arg == "--spectator" {
+            cli_args.spectator = true;
+        } else if arg == "--migrate-arts" {
+            cli_args.migrate_arts = true;
+        } else if arg == "--proxy" {
+            let value = args.next().ok_or("--proxy needs a URL argument")?;
+            cli_args.proxy = Some(value);
+        } else if let Some(value) = arg.strip_prefix("--proxy=") {
+            cli_args.proxy = Some(value.to_string());
+        } else if arg == "--insecure" {
+            cli_args.insecure = true;
+        } else if arg == "--ca-bundle" {
+            let value = args.next().ok_or("--ca-bundle needs a PEM file argument")?;
+            cli_args.ca_bundle = Some(value.into());
+        } else if let Some(value) = arg.strip_prefix("--ca-bundle=") {
+            cli_args.ca_bundle = Some(value.into());
+        } else if arg == "--log-json" {
+            cli_args.log_json = Some(None);
+        } else if let Some(value) = arg.strip_prefix("--log-json=") {
+            cli_args.log_json = Some(Some(value.to_string()));
+        } else {
+            return Err(format!(
+                "Unknown argument '{}'. Usage: ftplace_tui [--data-dir <path>] [--proxy <url>] [--ca-bundle <pem>] [--insecure] [--spectator] [--migrate-arts] [--log-json[=<path>|-]]",
+                arg
+            ));
+        }
+    }
+    Ok(cli_args)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn env_credentials_configure_the_client_without_being_saved() {
+        let mut app = App::for_tests_with_env(&[
+            ("FTPLACE_BASE_URL", "http://env.example:7979"),
+            ("FTPLACE_ACCESS_TOKEN", "env-access"),
+            ("FTPLACE_REFRESH_TOKEN", "env-refresh"),
+        ]);
+
+        assert_eq!(app.api_client.get_base_url(), "http://env.example:7979");
+        assert_eq!(
+            app.api_client.get_tokens(),
+            (Some("env-access".to_string()), Some("env-refresh".to_string()))
+        );
+        // Interactive configuration is skipped
+        assert_eq!(app.input_mode, InputMode::ShowHelp);
+
+        assert!(app.env_credentials);
+        assert!(!app.persists_tokens());
+        app.save_tokens();
+        let saved = app.token_storage.load().0;
+        assert_ne!(saved.access_token.as_deref(), Some("env-access"));
+        assert_ne!(saved.refresh_token.as_deref(), Some("env-refresh"));
+    }
+}
@@ -0,0 +1,75 @@
+use crate::api_client::ColorInfo;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+/// Friendly color names imported from a palette file, shown in the UI only
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct PaletteNames {
+    pub by_id: HashMap<i32, String>,
+    pub by_rgb: Vec<([u8; 3], String)>,
+}
+
+impl PaletteNames {
+    /// Parse a GIMP/ACES style palette: `R G B Name` lines, plus `ID Name` lines
+    /// for palettes keyed by color id. Headers and `#` comments are skipped.
+    pub fn parse(text: &str) -> Self {
+        let mut names = PaletteNames::default();
+        for line in text.lines() {
+            let line = line.trim();
+            if line.is_empty()
+                || line.starts_with('#')
+                || line.eq_ignore_ascii_case("GIMP Palette")
+                || line.starts_with("Name:")
+                || line.starts_with("Columns:")
+            {
+                continue;
+            }
+
+            let tokens = line.split_whitespace().collect::<Vec<_>>();
+            let rgb = tokens
+                .iter()
+                .take(3)
+                .map(|token| token.parse::<u8>())
+                .collect::<Result<Vec<_>, _>>();
+            match rgb {
+                Ok(rgb) if rgb.len() == 3 && tokens.len() > 3 => {
+                    names
+                        .by_rgb
+                        .push(([rgb[0], rgb[1], rgb[2]], tokens[3..].join(" ")));
+                }
+                _ => {
+                    if let (Some(id), true) = (
+                        tokens.first().and_then(|token| token.parse::<i32>().ok()),
+                        tokens.len() > 1,
+                    ) {
+                        names.by_id.insert(id, tokens[1..].join(" "));
+                    }
+                }
+            }
+        }
+        names
+    }
+
+    pub fn entry_count(&self) -> usize {
+        self.by_id.len() + self.by_rgb.len()
+    }
+
+    /// Name for `color`: an id entry always wins, the nearest RGB entry only names unnamed colors
+    pub fn name_for(&self, color: &ColorInfo) -> Option<&str> {
+        if let Some(name) = self.by_id.get(&color.id) {
+            return Some(name);
+        }
+        if !color.name.trim().is_empty() {
+            return None;
+        }
+
+        let distance = |[red, green, blue]: [u8; 3]| {
+            let delta = |a: u8, b: u8| (a as i32 - b as i32).pow(2);
+            delta(red, color.red) + delta(green, color.green) + delta(blue, color.blue)
+        };
+        self.by_rgb
+            .iter()
+            .min_by_key(|(rgb, _)| distance(*rgb))
+            .map(|(_, name)| name.as_str())
+    }
+}
@@ -0,0 +1,76 @@
+use std::path::{Path, PathBuf};
+use std::sync::OnceLock;
+
+/// Directories the app used to create in the working directory
+const LEGACY_DIRS: [&str; 4] = ["patterns", "shares", "queue", "logs"];
+
+struct DataDir {
+    root: PathBuf,
+    explicit: bool, // Chosen with --data-dir / FTPLACE_DATA_DIR rather than defaulted
+}
+
+static DATA_DIR: OnceLock<DataDir> = OnceLock::new();
+
+#[cfg(test)]
+thread_local! {
+    // Per-test data dir, so tests running in parallel never share files
+    static TEST_DATA_DIR: std::cell::Cell<Option<&'static Path>> = const { std::cell::Cell::new(None) };
+}
+
+/// Point this test thread at its own data directory
+#[cfg(test)]
+pub fn set_test_data_dir(dir: PathBuf) {
+    let dir: &'static Path = Box::leak(dir.into_boxed_path());
+    TEST_DATA_DIR.with(|cell| cell.set(Some(dir)));
+}
+
+/// Pick the data directory once at startup: `--data-dir`, then FTPLACE_DATA_DIR,
+/// then the platform data dir. A working directory that already holds the old
+/// relative folders keeps being used so existing arts and queues aren't orphaned.
+pub fn init_data_dir(cli_dir: Option<PathBuf>) -> &'static Path {
+    let data_dir = DATA_DIR.get_or_init(|| {
+        let explicit = cli_dir.or_else(|| std::env::var_os("FTPLACE_DATA_DIR").map(PathBuf::from));
+        if let Some(root) = explicit {
+            return DataDir {
+                root,
+                explicit: true,
+            };
+        }
+
+        let platform_dir = dirs::data_dir().map(|dir| dir.join("ftplace_tui"));
+        let has_legacy_data = LEGACY_DIRS.iter().any(|dir| Path::new(dir).is_dir());
+        let root = match platform_dir {
+            Some(dir) if dir.exists() || !has_legacy_data => dir,
+            _ => PathBuf::from("."),
+        };
+        DataDir {
+            root,
+            explicit: false,
+        }
+    });
+    &data_dir.root
+}
+
+/// Root directory for arts, shares, the queue and logs
+pub fn data_dir() -> &'static Path {
+    #[cfg(test)]
+    if let Some(dir) = TEST_DATA_DIR.with(|cell| cell.get()) {
+        return dir;
+    }
+    init_data_dir(None)
+}
+
+/// `relative` resolved inside the data directory
+pub fn data_path(relative: impl AsRef<Path>) -> PathBuf {
+    data_dir().join(relative)
+}
+
+/// Whether the user picked the data directory, in which case tokens live there too
+pub fn data_dir_is_explicit() -> bool {
+    #[cfg(test)]
+    if TEST_DATA_DIR.with(|cell| cell.get()).is_some() {
+        return true;
+    }
+    data_dir();
+    DATA_DIR.get().is_some_and(|data_dir| data_dir.explicit)
+}
@@ -1,4 +1,7 @@
+use crate::api_client::ColorInfo;
+use crate::palette_names::PaletteNames;
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
 use std::fs;
 use std::path::PathBuf;
 
@@ -9,28 +12,90 @@ pub struct TokenData {
     pub base_url: Option<String>,
 }
 
+impl TokenData {
+    /// Let FTPLACE_BASE_URL / FTPLACE_ACCESS_TOKEN / FTPLACE_REFRESH_TOKEN override the saved values.
+    /// Returns true if any of them was set.
+    pub fn apply_env_overrides(&mut self) -> bool {
+        let env_value = |name: &str| std::env::var(name).ok().filter(|value| !value.is_empty());
+
+        let mut overridden = false;
+        for (name, field) in [
+            ("FTPLACE_BASE_URL", &mut self.base_url),
+            ("FTPLACE_ACCESS_TOKEN", &mut self.access_token),
+            ("FTPLACE_REFRESH_TOKEN", &mut self.refresh_token),
+        ] {
+            if let Some(value) = env_value(name) {
+                *field = Some(value);
+                overridden = true;
+            }
+        }
+        overridden
+    }
+}
+
 #[derive(Debug)]
 pub struct TokenStorage {
     file_path: PathBuf,
+    palette_path: PathBuf,  // Sidecar with the last palette seen per base URL
+    viewport_path: PathBuf, // Sidecar with the last viewport position per base URL
+    palette_names_path: PathBuf, // Sidecar with imported color names per base URL
 }
 
 impl TokenStorage {
     pub fn new() -> Result<Self, Box<dyn std::error::Error>> {
-        let home_dir = dirs::home_dir().ok_or("Could not find home directory")?;
-        let file_path = home_dir.join(".ftplace_tokens.json");
+        // An explicit data dir keeps everything together (e.g. one dir per account)
+        let (file_path, palette_path, viewport_path, palette_names_path) =
+            if crate::paths::data_dir_is_explicit() {
+                (
+                    crate::paths::data_path("tokens.json"),
+                    crate::paths::data_path("palettes.json"),
+                    crate::paths::data_path("viewports.json"),
+                    crate::paths::data_path("palette_names.json"),
+                )
+            } else {
+                let home_dir = dirs::home_dir().ok_or("Could not find home directory")?;
+                (
+                    home_dir.join(".ftplace_tokens.json"),
+                    home_dir.join(".ftplace_palettes.json"),
+                    home_dir.join(".ftplace_viewports.json"),
+                    home_dir.join(".ftplace_palette_names.json"),
+                )
+            };
 
-        Ok(Self { file_path })
+        Ok(Self {
+            file_path,
+            palette_path,
+            viewport_path,
+            palette_names_path,
+        })
     }
 
-    pub fn load(&self) -> TokenData {
+    /// Load saved tokens, plus a note for the user if a corrupt file had to be set aside
+    pub fn load(&self) -> (TokenData, Option<String>) {
         match self.try_load() {
             Ok(data) => {
                 // eprintln!("Loaded saved tokens from {}", self.file_path.display());
-                data
+                (data, None)
+            }
+            Err(e) if e.is::<serde_json::Error>() => {
+                let note = match crate::event_handling::helpers::backup_corrupt_file(
+                    &self.file_path,
+                ) {
+                    Ok(backup_path) => format!(
+                        "⚠️ Saved tokens were unreadable ({}). Backed them up to {}; please log in again.",
+                        e,
+                        backup_path.display()
+                    ),
+                    Err(backup_error) => format!(
+                        "⚠️ Saved tokens were unreadable ({}) and could not be backed up: {}",
+                        e, backup_error
+                    ),
+                };
+                (TokenData::default(), Some(note))
             }
             Err(e) => {
                 eprintln!("Could not load saved tokens: {}. Starting fresh.", e);
-                TokenData::default()
+                (TokenData::default(), None)
             }
         }
     }
@@ -68,6 +133,99 @@ impl TokenStorage {
         Ok(())
     }
 
+    /// Last palette cached for `base_url`, if any
+    pub fn load_palette(&self, base_url: &str) -> Option<Vec<ColorInfo>> {
+        let mut palettes = self.try_load_palettes().ok()?;
+        palettes
+            .remove(base_url)
+            .filter(|colors| !colors.is_empty())
+    }
+
+    /// Remember the palette for `base_url`, keeping the other instances' palettes
+    pub fn save_palette(
+        &self,
+        base_url: &str,
+        colors: &[ColorInfo],
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        let mut palettes = self.try_load_palettes().unwrap_or_default();
+        palettes.insert(base_url.to_string(), colors.to_vec());
+        let json = serde_json::to_string_pretty(&palettes)?;
+        fs::write(&self.palette_path, json)?;
+        Ok(())
+    }
+
+    fn try_load_palettes(
+        &self,
+    ) -> Result<HashMap<String, Vec<ColorInfo>>, Box<dyn std::error::Error>> {
+        if !self.palette_path.exists() {
+            return Ok(HashMap::new());
+        }
+
+        let content = fs::read_to_string(&self.palette_path)?;
+        Ok(serde_json::from_str(&content)?)
+    }
+
+    /// Last viewport position saved for `base_url`, if any
+    pub fn load_viewport(&self, base_url: &str) -> Option<(u16, u16)> {
+        self.try_load_viewports().ok()?.remove(base_url)
+    }
+
+    /// Remember the viewport position for `base_url`, keeping the other instances' positions
+    pub fn save_viewport(
+        &self,
+        base_url: &str,
+        viewport: (u16, u16),
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        let mut viewports = self.try_load_viewports().unwrap_or_default();
+        viewports.insert(base_url.to_string(), viewport);
+        let json = serde_json::to_string_pretty(&viewports)?;
+        fs::write(&self.viewport_path, json)?;
+        Ok(())
+    }
+
+    fn try_load_viewports(
+        &self,
+    ) -> Result<HashMap<String, (u16, u16)>, Box<dyn std::error::Error>> {
+        if !self.viewport_path.exists() {
+            return Ok(HashMap::new());
+        }
+
+        let content = fs::read_to_string(&self.viewport_path)?;
+        Ok(serde_json::from_str(&content)?)
+    }
+
+    /// Color names imported for `base_url`, if any
+    pub fn load_palette_names(&self, base_url: &str) -> Option<PaletteNames> {
+        self.try_load_palette_names().ok()?.remove(base_url)
+    }
+
+    /// Remember the color names for `base_url`; `None` forgets them
+    pub fn save_palette_names(
+        &self,
+        base_url: &str,
+        names: Option<&PaletteNames>,
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        let mut all_names = self.try_load_palette_names().unwrap_or_default();
+        match names {
+            Some(names) => all_names.insert(base_url.to_string(), names.clone()),
+            None => all_names.remove(base_url),
+        };
+        let json = serde_json::to_string_pretty(&all_names)?;
+        fs::write(&self.palette_names_path, json)?;
+        Ok(())
+    }
+
+    fn try_load_palette_names(
+        &self,
+    ) -> Result<HashMap<String, PaletteNames>, Box<dyn std::error::Error>> {
+        if !self.palette_names_path.exists() {
+            return Ok(HashMap::new());
+        }
+
+        let content = fs::read_to_string(&self.palette_names_path)?;
+        Ok(serde_json::from_str(&content)?)
+    }
+
     #[allow(dead_code)]
     pub fn get_file_path(&self) -> &PathBuf {
         &self.file_path
@@ -16,14 +16,27 @@ pub fn render_art_editor_ui(app: &mut App, frame: &mut Frame, area: Rect) {
     let palette_area = editor_layout[1];
 
     let selected_color_name = get_color_name(app, app.art_editor_selected_color_id);
+    // Arts get a board position when placed; (0, 0) means none was set yet
+    let board_cursor = app
+        .current_editing_art
+        .as_ref()
+        .filter(|art| art.board_x != 0 || art.board_y != 0)
+        .map_or(String::new(), |art| {
+            format!(
+                " → Board: {},{}",
+                art.board_x + app.art_editor_cursor_x,
+                art.board_y + app.art_editor_cursor_y
+            )
+        });
     let editor_block = Block::default()
 		.borders(Borders::ALL)
 		.title(format!(
-			"Pixel Art Editor (Canvas: {}x{}, Cursor: {},{}, Color: {}) - Arrows, Space, Tab, s:Save, Esc:Exit",
+			"Pixel Art Editor (Canvas: {}x{}, Cursor: {},{}{}, Color: {}) - Arrows, Space, Tab, s:Save, Esc:Exit",
 			app.art_editor_canvas_width,
 			app.art_editor_canvas_height,
 			app.art_editor_cursor_x,
 			app.art_editor_cursor_y,
+			board_cursor,
 			selected_color_name
 		));
     frame.render_widget(editor_block.clone(), canvas_area); // Clone for the title, draw border over full area
@@ -158,11 +171,7 @@ fn render_color_palette(app: &App, frame: &mut Frame, area: Rect) {
         .iter()
         .enumerate()
         .map(|(idx, color)| {
-            let color_name = if color.name.trim().is_empty() {
-                format!("Color {}", color.id)
-            } else {
-                color.name.clone()
-            };
+            let color_name = app.color_display_name(color);
 
             let is_selected = app.art_editor_selected_color_id == color.id;
             let _is_highlighted = idx == app.art_editor_color_palette_index;
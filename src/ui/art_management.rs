@@ -1,7 +1,10 @@
 use crate::app_state::App;
-use crate::ui::helpers::get_ratatui_color;
+use crate::ui::helpers::{get_ratatui_color, spinner_char};
 use ratatui::prelude::*;
-use ratatui::widgets::{Block, Borders, List, ListItem, ListState, Paragraph, Wrap};
+use ratatui::widgets::{
+    Block, Borders, List, ListItem, ListState, Paragraph, Scrollbar, ScrollbarOrientation,
+    ScrollbarState, Wrap,
+};
 
 /// Render the art selection UI (full width, no small preview)
 pub fn render_art_selection_ui(app: &App, frame: &mut Frame, area: Rect) {
@@ -21,14 +24,13 @@ pub fn render_art_selection_ui(app: &App, frame: &mut Frame, area: Rect) {
         .iter()
         .enumerate()
         .map(|(idx, art)| {
-            let dimensions = crate::art::get_art_dimensions(art);
-            let item_text = format!(
-                "{} ({}x{}, {} pixels)",
-                art.name,
-                dimensions.0,
-                dimensions.1,
-                art.pattern.len()
-            );
+            let item_text = match app.available_art_sizes.get(idx) {
+                Some(((width, height), meaningful_pixels)) => format!(
+                    "{} ({}x{}, {} pixels)",
+                    art.name, width, height, meaningful_pixels
+                ),
+                None => art.name.clone(),
+            };
 
             if idx == app.art_selection_index {
                 ListItem::new(item_text).style(
@@ -61,8 +63,67 @@ pub fn render_art_selection_ui(app: &App, frame: &mut Frame, area: Rect) {
     frame.render_stateful_widget(art_list, area, &mut list_state);
 }
 
+fn queue_status_symbol(status: &crate::app_state::QueueStatus) -> &'static str {
+    match status {
+        crate::app_state::QueueStatus::Pending => "⏳",
+        crate::app_state::QueueStatus::InProgress => "🚀",
+        crate::app_state::QueueStatus::Complete => "✅",
+        crate::app_state::QueueStatus::Skipped => "⏭️",
+        crate::app_state::QueueStatus::Failed => "❌",
+    }
+}
+
+/// Narrow queue list shown beside the board: status, name and progress per item
+pub fn render_queue_sidebar(app: &App, frame: &mut Frame, area: Rect) {
+    let items: Vec<ListItem> = app
+        .art_queue
+        .iter()
+        .enumerate()
+        .map(|(idx, item)| {
+            let is_active = app.queue_active_index == Some(idx);
+            let mut text = format!(
+                "{} {}",
+                if is_active {
+                    "🚀"
+                } else {
+                    queue_status_symbol(&item.status)
+                },
+                item.art.name
+            );
+            if item.pixels_total > 0 {
+                text.push_str(&format!(" {}/{}", item.pixels_placed, item.pixels_total));
+            }
+            if item.paused {
+                text.push_str(" ⏸️");
+            }
+
+            let list_item = ListItem::new(text);
+            if is_active {
+                list_item.style(Style::default().fg(Color::Black).bg(Color::LightGreen))
+            } else {
+                list_item
+            }
+        })
+        .collect();
+
+    let title = if app.queue_processing {
+        format!("Queue {} {}", spinner_char(app), app.art_queue.len())
+    } else {
+        format!("Queue ({})", app.art_queue.len())
+    };
+    let list = List::new(items)
+        .block(Block::default().borders(Borders::ALL).title(title))
+        .highlight_style(Style::default().add_modifier(Modifier::REVERSED));
+
+    let mut list_state = ListState::default();
+    if !app.art_queue.is_empty() {
+        list_state.select(Some(app.queue_selection_index.min(app.art_queue.len() - 1)));
+    }
+    frame.render_stateful_widget(list, area, &mut list_state);
+}
+
 /// Render the art queue management UI
-pub fn render_art_queue_ui(app: &App, frame: &mut Frame, area: Rect) {
+pub fn render_art_queue_ui(app: &mut App, frame: &mut Frame, area: Rect) {
     if app.art_queue.is_empty() {
         let empty_message = Paragraph::new(vec![
             Line::from("Queue is empty"),
@@ -93,13 +154,7 @@ pub fn render_art_queue_ui(app: &App, frame: &mut Frame, area: Rect) {
         .iter()
         .enumerate()
         .map(|(idx, item)| {
-            let status_symbol = match item.status {
-                crate::app_state::QueueStatus::Pending => "⏳",
-                crate::app_state::QueueStatus::InProgress => "🚀",
-                crate::app_state::QueueStatus::Complete => "✅",
-                crate::app_state::QueueStatus::Skipped => "⏭️",
-                crate::app_state::QueueStatus::Failed => "❌",
-            };
+            let status_symbol = queue_status_symbol(&item.status);
 
             let priority_color = match item.priority {
                 1 => Color::Red,
@@ -118,6 +173,17 @@ pub fn render_art_queue_ui(app: &App, frame: &mut Frame, area: Rect) {
 
             let pause_indicator = if item.paused { " ⏸️" } else { "" };
 
+            // Row the queue task is working on right now, with its latest coordinate
+            let is_active = app.queue_active_index == Some(idx);
+            let active_indicator = if is_active {
+                match app.queue_active_position {
+                    Some((x, y)) => format!(" ◀ placing ({},{})", x, y),
+                    None => " ◀ starting".to_string(),
+                }
+            } else {
+                String::new()
+            };
+
             // Calculate estimated time for pending items
             let estimated_time =
                 if item.status == crate::app_state::QueueStatus::Pending && !item.paused {
@@ -134,15 +200,22 @@ pub fn render_art_queue_ui(app: &App, frame: &mut Frame, area: Rect) {
                 };
 
             let item_text = format!(
-                "{} P{} '{}' @ ({},{}){}{}{}",
-                status_symbol,
+                "{}{} P{} '{}' @ ({},{}){}{}{}{}{}",
+                if item.selected { "☑ " } else { "" },
+                if is_active { "🚀" } else { status_symbol },
                 item.priority,
                 item.art.name,
                 item.art.board_x,
                 item.art.board_y,
+                if item.placement_order == Default::default() {
+                    String::new()
+                } else {
+                    format!(" [{}]", item.placement_order.label())
+                },
                 progress,
                 estimated_time,
-                pause_indicator
+                pause_indicator,
+                active_indicator
             );
 
             let mut list_item = ListItem::new(item_text);
@@ -154,16 +227,57 @@ pub fn render_art_queue_ui(app: &App, frame: &mut Frame, area: Rect) {
                 );
             }
 
-            list_item.style(Style::default().fg(priority_color))
+            if is_active {
+                list_item.style(
+                    Style::default()
+                        .fg(Color::Black)
+                        .bg(Color::LightGreen)
+                        .add_modifier(Modifier::BOLD),
+                )
+            } else {
+                list_item.style(Style::default().fg(priority_color))
+            }
         })
         .collect();
 
-    let queue_list = List::new(queue_items)
-        .block(
-            Block::default()
-                .borders(Borders::ALL)
-                .title(format!("Art Queue ({} items)", app.art_queue.len())),
+    // Keep the processing item in view during a run, otherwise follow the selection
+    let visible_rows = queue_layout[0].height.saturating_sub(2).max(1) as usize;
+    let follow_active = app.queue_processing && app.queue_active_index.is_some();
+    let focus_index = if follow_active {
+        app.queue_active_index.unwrap_or(0)
+    } else {
+        app.queue_selection_index
+    };
+    if focus_index < app.queue_list_offset {
+        app.queue_list_offset = focus_index;
+    } else if focus_index >= app.queue_list_offset + visible_rows {
+        app.queue_list_offset = focus_index + 1 - visible_rows;
+    }
+    app.queue_list_offset = app
+        .queue_list_offset
+        .min(app.art_queue.len().saturating_sub(visible_rows));
+
+    let scroll_indicator = if app.art_queue.len() > visible_rows {
+        format!(
+            " [{}-{}]",
+            app.queue_list_offset + 1,
+            (app.queue_list_offset + visible_rows).min(app.art_queue.len())
         )
+    } else {
+        String::new()
+    };
+
+    let queue_list = List::new(queue_items)
+        .block(Block::default().borders(Borders::ALL).title(format!(
+            "Art Queue ({} items){}{}",
+            app.art_queue.len(),
+            scroll_indicator,
+            if app.queue_processing {
+                format!(" {} processing", spinner_char(app))
+            } else {
+                String::new()
+            }
+        )))
         .highlight_style(
             Style::default()
                 .bg(Color::Blue)
@@ -171,11 +285,29 @@ pub fn render_art_queue_ui(app: &App, frame: &mut Frame, area: Rect) {
         )
         .highlight_symbol("▶ ");
 
-    let mut list_state = ListState::default();
-    list_state.select(Some(app.queue_selection_index));
+    // The list scrolls to whatever is selected, so only mark the selection while it's in view
+    let selection_visible = app.queue_selection_index >= app.queue_list_offset
+        && app.queue_selection_index < app.queue_list_offset + visible_rows;
+    let mut list_state = ListState::default().with_offset(app.queue_list_offset);
+    if selection_visible {
+        list_state.select(Some(app.queue_selection_index));
+    }
 
     frame.render_stateful_widget(queue_list, queue_layout[0], &mut list_state);
 
+    if app.art_queue.len() > visible_rows {
+        let mut scrollbar_state =
+            ScrollbarState::new(app.art_queue.len() - visible_rows).position(app.queue_list_offset);
+        frame.render_stateful_widget(
+            Scrollbar::new(ScrollbarOrientation::VerticalRight),
+            queue_layout[0].inner(Margin {
+                vertical: 1,
+                horizontal: 0,
+            }),
+            &mut scrollbar_state,
+        );
+    }
+
     // Render controls and info panel
     let pending_count = app
         .art_queue
@@ -216,6 +348,19 @@ pub fn render_art_queue_ui(app: &App, frame: &mut Frame, area: Rect) {
             pending_count, paused_count
         )),
         Line::from(format!("Total Pixels: {}", total_pixels)),
+        Line::from(format!(
+            "Mode: {} (i to change)",
+            app.queue_interleave.label()
+        )),
+        Line::from(format!("Cooldown: {} (t)", app.cooldown_strategy.label())),
+        Line::from(format!(
+            "Verify placements: {} (v)",
+            if app.verify_placements { "on" } else { "off" }
+        )),
+        Line::from(format!(
+            "Done overlay: {} (o)",
+            app.completed_overlay.label()
+        )),
     ];
 
     // Add total estimated time if available
@@ -238,9 +383,31 @@ pub fn render_art_queue_ui(app: &App, frame: &mut Frame, area: Rect) {
             user_info.pixel_buffer
         };
 
+        // Politeness mode caps the server's refill rate
+        let polite_text = app.polite_rate.map_or(String::new(), |rate| {
+            let server_rate = if cooldown_minutes > 0.0 {
+                buffer_size as f64 / cooldown_minutes
+            } else {
+                f64::INFINITY
+            };
+            format!(
+                " | Polite: ≤{}/min (effective {:.1}/min)",
+                rate,
+                server_rate.min(rate as f64)
+            )
+        });
+
         controls_text.push(Line::from(format!(
-            "Rate: {} pixels/{:.1}min | Now: {}",
-            buffer_size, cooldown_minutes, available_pixels
+            "Rate: {} pixels/{:.1}min | Now: {}{}{}",
+            buffer_size,
+            cooldown_minutes,
+            available_pixels,
+            if app.reserve_buffer > 0 {
+                format!(" | Reserve: {}", app.reserve_buffer)
+            } else {
+                String::new()
+            },
+            polite_text
         )));
     }
 
@@ -288,7 +455,8 @@ pub fn render_art_queue_ui(app: &App, frame: &mut Frame, area: Rect) {
         )),
         Line::from("↑/↓: Navigate"),
         Line::from("Enter: Start/Resume"),
-        Line::from("1-5: Set priority"),
+        Line::from("Space: Mark item"),
+        Line::from("1-5: Set priority (marked)"),
         Line::from("d: Remove item"),
         Line::from("Esc: Exit"),
     ]);
@@ -530,6 +698,17 @@ fn render_share_details(
     frame.render_widget(details_paragraph, area);
 }
 
+/// Title warning for arts with conflicting pixels at the same position
+fn conflict_note(art: &crate::art::PixelArt) -> String {
+    match crate::art::count_conflicting_pixels(art) {
+        0 => String::new(),
+        conflicts => format!(
+            " ⚠️ {} positions with conflicting colors (first pixel wins)",
+            conflicts
+        ),
+    }
+}
+
 /// Render a full-screen art preview for art selection (always visible)
 pub fn render_art_preview_fullscreen(
     art: &crate::art::PixelArt,
@@ -547,8 +726,9 @@ pub fn render_art_preview_fullscreen(
             .borders(Borders::ALL)
             .border_style(Style::default().fg(Color::White))
             .title(format!(
-                "Preview: {} (Enter: load, d: delete, Esc: cancel)",
-                art.name
+                "Preview: {} (Enter: load, d: delete, Esc: cancel){}",
+                art.name,
+                conflict_note(art)
             )),
         popup_area,
     );
@@ -656,8 +836,13 @@ pub fn render_art_preview_fullscreen(
     };
 
     let info_text = format!(
-        "Size: {}x{} pixels | Scale: {:.1}x | Use ↑↓ to browse, 'd' to delete, Enter to load",
-        art_width, art_height, scale
+        "Size: {}x{} pixels | Scale: {:.1}x{} | ↑↓: browse, v: full view, e: edit, y: duplicate, d: delete, Enter: load",
+        art_width,
+        art_height,
+        scale,
+        app.art_stats_summary(&art.name)
+            .map(|summary| format!(" | {}", summary))
+            .unwrap_or_default()
     );
 
     frame.render_widget(
@@ -681,8 +866,11 @@ pub fn render_art_preview_ui(app: &App, frame: &mut Frame, area: Rect) {
                 .borders(Borders::ALL)
                 .border_style(Style::default().fg(Color::White))
                 .title(format!(
-                    "Full Preview: {} (Enter to load, Esc to return)",
-                    art.name
+                    "Full Preview: {} ({}/{}) (←/→ to browse, Enter to load, Esc to return){}",
+                    art.name,
+                    app.art_selection_index + 1,
+                    app.available_pixel_arts.len(),
+                    conflict_note(art)
                 )),
             popup_area,
         );
@@ -700,31 +888,32 @@ pub fn render_art_preview_ui(app: &App, frame: &mut Frame, area: Rect) {
             return;
         }
 
-        // Calculate art bounds
-        let dimensions = crate::art::get_art_dimensions(art);
-        let art_width = dimensions.0 as u16;
-        let art_height = dimensions.1 as u16;
-
-        // Scale preview to fit available space, but allow scaling up for small arts
-        let max_preview_width = inner_area.width;
-        let max_preview_height = inner_area.height * 2; // Since we use half-blocks
+        // Art pixels by position, relative to the art's top-left corner (first one wins)
+        let (art_width, art_height) = crate::art::get_art_dimensions(art);
+        let min_x = art.pattern.iter().map(|p| p.x).min().unwrap_or(0);
+        let min_y = art.pattern.iter().map(|p| p.y).min().unwrap_or(0);
+        let mut pixel_colors = std::collections::HashMap::new();
+        for pixel in &art.pattern {
+            pixel_colors
+                .entry((pixel.x - min_x, pixel.y - min_y))
+                .or_insert(pixel.color);
+        }
 
-        let scale_x = if art_width == 0 {
-            1.0
+        // Each cell shows two stacked pixels, so a pixel is one column wide and half a row
+        // tall - square on a typical terminal font. Scale uniformly in pixel units, keeping
+        // two rows for the info line; whole-number upscaling keeps every pixel the same size.
+        let available_width = inner_area.width as f32;
+        let available_height = inner_area.height.saturating_sub(2) as f32 * 2.0;
+        let fit = (available_width / art_width as f32).min(available_height / art_height as f32);
+        let scale = if fit >= 1.0 {
+            fit.floor().min(8.0) // Max 8x scaling
         } else {
-            max_preview_width as f32 / art_width as f32
+            fit
         };
-        let scale_y = if art_height == 0 {
-            1.0
-        } else {
-            max_preview_height as f32 / art_height as f32
-        };
-
-        // For full-screen preview, allow scaling up to a reasonable limit
-        let scale = scale_x.min(scale_y).min(8.0); // Max 8x scaling
 
-        let preview_width = (art_width as f32 * scale) as u16;
-        let preview_height = ((art_height as f32 * scale) / 2.0) as u16; // Divide by 2 for half-blocks
+        let preview_width = ((art_width as f32 * scale).ceil() as u16).min(inner_area.width);
+        let preview_pixel_height = (art_height as f32 * scale).ceil() as u16;
+        let preview_height = preview_pixel_height.div_ceil(2);
 
         // First, fill the entire inner area with black background
         for y in 0..inner_area.height {
@@ -739,37 +928,35 @@ pub fn render_art_preview_ui(app: &App, frame: &mut Frame, area: Rect) {
             }
         }
 
-        // Center the preview
+        // Center the preview above the info line
         let start_x = inner_area.x + (inner_area.width.saturating_sub(preview_width)) / 2;
-        let start_y = inner_area.y + (inner_area.height.saturating_sub(preview_height)) / 2;
+        let start_y = inner_area.y
+            + (inner_area
+                .height
+                .saturating_sub(2)
+                .saturating_sub(preview_height))
+                / 2;
+
+        // Color of the art pixel under a preview pixel (Black for empty areas and padding)
+        let color_at = |preview_x: u16, preview_y: u16| {
+            if preview_y >= preview_pixel_height {
+                return Color::Black;
+            }
+            let art_x = (preview_x as f32 / scale) as i32;
+            let art_y = (preview_y as f32 / scale) as i32;
+            pixel_colors
+                .get(&(art_x, art_y))
+                .map_or(Color::Black, |&color| {
+                    get_ratatui_color(app, color, Color::Black)
+                })
+        };
 
         // Render the art preview using half-blocks
         for screen_y in 0..preview_height {
             for screen_x in 0..preview_width {
-                let art_pixel_y_top = ((screen_y * 2) as f32 / scale) as i32;
-                let art_pixel_y_bottom = art_pixel_y_top + (1.0 / scale) as i32;
-                let art_pixel_x = (screen_x as f32 / scale) as i32;
-
-                let top_pixel = art
-                    .pattern
-                    .iter()
-                    .find(|p| p.x == art_pixel_x && p.y == art_pixel_y_top);
-
-                let bottom_pixel = art
-                    .pattern
-                    .iter()
-                    .find(|p| p.x == art_pixel_x && p.y == art_pixel_y_bottom);
-
-                let top_pixel_color = top_pixel
-                    .map(|p| get_ratatui_color(app, p.color, Color::Black))
-                    .unwrap_or(Color::Black); // Use Black for empty areas
-
-                let bottom_pixel_color = bottom_pixel
-                    .map(|p| get_ratatui_color(app, p.color, Color::Black))
-                    .unwrap_or(Color::Black); // Use Black for empty areas
-
-                let cell_char = '▀';
-                let style = Style::default().fg(top_pixel_color).bg(bottom_pixel_color);
+                let style = Style::default()
+                    .fg(color_at(screen_x, screen_y * 2))
+                    .bg(color_at(screen_x, screen_y * 2 + 1));
 
                 if start_x + screen_x < frame.size().width
                     && start_y + screen_y < frame.size().height
@@ -777,7 +964,7 @@ pub fn render_art_preview_ui(app: &App, frame: &mut Frame, area: Rect) {
                     frame
                         .buffer_mut()
                         .get_mut(start_x + screen_x, start_y + screen_y)
-                        .set_char(cell_char)
+                        .set_char('▀')
                         .set_style(style);
                 }
             }
@@ -791,9 +978,19 @@ pub fn render_art_preview_ui(app: &App, frame: &mut Frame, area: Rect) {
             height: 1,
         };
 
+        let scale_label = if scale >= 1.0 {
+            format!("{}x", scale)
+        } else {
+            format!("{:.0}%", scale * 100.0)
+        };
         let info_text = format!(
-            "Size: {}x{} pixels | Scale: {:.1}x | Controls: Enter=Load, Esc=Return",
-            art_width, art_height, scale
+            "True size: {}x{} pixels | Shown at {}, aspect kept{} | Controls: Enter=Load, Esc=Return",
+            art_width,
+            art_height,
+            scale_label,
+            app.art_stats_summary(&art.name)
+                .map(|summary| format!(" | {}", summary))
+                .unwrap_or_default()
         );
 
         frame.render_widget(
@@ -1,5 +1,82 @@
 use crate::app_state::App;
 use ratatui::prelude::*;
+use std::borrow::Cow;
+
+/// Emoji used in status text and the plain tags shown instead in ascii mode
+const ASCII_STATUS_TAGS: [(&str, &str); 44] = [
+    ("🔄", "[RUN]"),
+    ("✅", "[OK]"),
+    ("❌", "[ERR]"),
+    ("💥", "[ERR]"),
+    ("⚠", "[WARN]"),
+    ("⏭", "[SKIP]"),
+    ("🎨", "[SET]"),
+    ("📡", "[NET]"),
+    ("🐞", "[DBG]"),
+    ("📋", "[QUEUE]"),
+    ("⏳", "[WAIT]"),
+    ("🕐", "[WAIT]"),
+    ("🕒", "[WAIT]"),
+    ("⏰", "[TIME]"),
+    ("⏱", "[TIME]"),
+    ("🔍", "[CHECK]"),
+    ("👁", "[WATCH]"),
+    ("🟢", "[OK]"),
+    ("🟡", "[WAIT]"),
+    ("🔴", "[NONE]"),
+    ("⚪", "[INFO]"),
+    ("🎉", "[DONE]"),
+    ("🚀", "[GO]"),
+    ("⏸", "[PAUSE]"),
+    ("▶", "[RESUME]"),
+    ("🛑", "[STOP]"),
+    ("💡", "[TIP]"),
+    ("📢", "[MSG]"),
+    ("🗂", "[SESSION]"),
+    ("📦", "[MOVE]"),
+    ("🧹", "[CLEAN]"),
+    ("🔁", "[RETRY]"),
+    ("🛡", "[OK]"),
+    ("🎯", "[TARGET]"),
+    ("✏", "[EDIT]"),
+    ("🕊", "[EMPTY]"),
+    ("📸", "[SNAP]"),
+    ("🔔", "[BELL]"),
+    ("📝", "[REPORT]"),
+    ("🔒", "[LOGOUT]"),
+    ("🔗", "[NET]"),
+    ("❓", "[?]"),
+    ("→", "->"),
+    ("•", "*"),
+];
+
+/// Status text as displayed: emoji become plain tags when ascii mode is on
+pub fn status_display<'a>(app: &App, text: &'a str) -> Cow<'a, str> {
+    if !app.ascii_status || text.is_ascii() {
+        return Cow::Borrowed(text);
+    }
+    // Drop variation selectors first so "⚠️" and "⚠" map the same way
+    let mut display = text.replace('\u{FE0F}', "");
+    for (emoji, tag) in ASCII_STATUS_TAGS {
+        display = display.replace(emoji, tag);
+    }
+    // Emoji without a tag would still show as boxes, so drop them
+    display.retain(|ch| !is_emoji_symbol(ch));
+    Cow::Owned(display)
+}
+
+/// Emoji and pictographs that terminals without emoji fonts can't draw. Block and box
+/// drawing characters (the cooldown timeline, board cells) work there and are kept.
+fn is_emoji_symbol(ch: char) -> bool {
+    matches!(
+        ch as u32,
+        0x2300..=0x23FF // Misc technical (hourglasses, media controls)
+            | 0x2600..=0x27BF // Misc symbols and dingbats
+            | 0x2B00..=0x2BFF // Misc symbols and arrows (stars, squares)
+            | 0x1F000..=0x1FAFF // Emoji and pictographs
+            | 0x200D // Zero-width joiner inside emoji sequences
+    )
+}
 
 /// helper function to create a centered rect using up certain percentage of the available rect `r`
 pub fn centered_rect(percent_x: u16, percent_y: u16, r: Rect) -> Rect {
@@ -22,6 +99,14 @@ pub fn centered_rect(percent_x: u16, percent_y: u16, r: Rect) -> Rect {
         .split(popup_layout[1])[1]
 }
 
+/// Braille frames for the busy spinner shown while background work runs
+const SPINNER_FRAMES: [char; 10] = ['⠋', '⠙', '⠹', '⠸', '⠼', '⠴', '⠦', '⠧', '⠇', '⠏'];
+
+/// Current spinner character; advanced once per frame by `render_ui`
+pub fn spinner_char(app: &App) -> char {
+    SPINNER_FRAMES[app.spinner_frame % SPINNER_FRAMES.len()]
+}
+
 pub fn get_ratatui_color(app: &App, color_id: i32, default_fallback_color: Color) -> Color {
     app.colors
         .iter()
@@ -35,13 +120,7 @@ pub fn get_color_name(app: &App, color_id: i32) -> String {
     app.colors
         .iter()
         .find(|c| c.id == color_id)
-        .map(|color_info| {
-            if color_info.name.trim().is_empty() {
-                format!("Color {}", color_id)
-            } else {
-                color_info.name.clone()
-            }
-        })
+        .map(|color_info| app.color_display_name(color_info))
         .unwrap_or_else(|| format!("Unknown Color {}", color_id))
 }
 
@@ -99,3 +178,27 @@ pub fn get_current_board_color_ui(
         Color::Black
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn ascii_mode_leaves_no_emoji_in_status_text() {
+        let mut app = App::for_tests();
+        app.ascii_status = true;
+
+        for (emoji, tag) in ASCII_STATUS_TAGS {
+            assert!(tag.is_ascii(), "{}", tag);
+            assert_eq!(status_display(&app, emoji), tag);
+        }
+        assert_eq!(
+            status_display(&app, "🕊️ Skipping pixel at (1,2)"),
+            "[EMPTY] Skipping pixel at (1,2)"
+        );
+        // Emoji without a tag are dropped rather than shown as boxes
+        assert!(status_display(&app, "🆕 New art ⭐").is_ascii());
+        // The cooldown timeline keeps its block characters
+        assert_eq!(status_display(&app, "█░● ✅"), "█░● [OK]");
+    }
+}
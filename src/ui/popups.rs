@@ -1,83 +1,157 @@
-use crate::app_state::App;
-use crate::ui::helpers::centered_rect;
+use crate::app_state::{App, InputMode};
+use crate::ui::helpers::{centered_rect, status_display};
 use ratatui::prelude::*;
 use ratatui::widgets::{Block, Borders, Clear, Paragraph, Wrap};
 
-pub fn render_help_popup(_app: &App, frame: &mut Frame) {
+pub fn render_help_popup(app: &App, frame: &mut Frame) {
     let popup_area = centered_rect(60, 50, frame.size()); // Adjust size as needed
 
-    let help_text = vec![
-        Line::from(Span::styled(
-            "--- General ---",
-            Style::default().add_modifier(Modifier::BOLD),
-        )),
-        Line::from(" q: Quit application"),
-        Line::from(" ?: Toggle this help screen"),
-        Line::from(" c: Configure/Re-enter Base URL and tokens"),
-        Line::from(" b: Change Base URL"),
-        Line::from(" r: Refresh board data"),
-        Line::from(" p: Fetch profile data"),
-        Line::from(" i: Show user profile panel"),
-        Line::from(" h: Show status log history"),
-        Line::from(" w: Work queue management"),
-        Line::from(" x: Share loaded art with coordinates"),
-        Line::from(" v: View/import shared arts"),
-        Line::from(" V: Toggle periodic validation (monitor completed arts)"),
-        Line::from(" z: Enter share string for quick positioning"),
-        Line::from(" Arrows: Scroll board viewport"),
-        Line::from(" Mouse Wheel: Scroll board viewport vertically"),
-        Line::from(" Left Click: Show coordinates (or move loaded art)"),
-        Line::from(""),
-        Line::from(Span::styled(
-            "--- Pixel Art Placement ---",
-            Style::default().add_modifier(Modifier::BOLD),
-        )),
-        Line::from(" l: Open art selection"),
-        Line::from(" Arrows: Navigate available arts"),
-        Line::from(" Enter: Load selected art for positioning"),
-        Line::from(""),
-        Line::from(Span::styled(
-            "--- Loaded Art (positioning & placement) ---",
-            Style::default().add_modifier(Modifier::BOLD),
-        )),
-        Line::from(" Arrows: Move loaded art on board"),
-        Line::from(" Left Click: Move loaded art to mouse position"),
-        Line::from(" Right Click: Move and place art immediately"),
-        Line::from(" Enter: Add positioned art to queue & start processing"),
-        Line::from(" Esc: Cancel loaded art or stop queue processing"),
-        Line::from(""),
-        Line::from(Span::styled(
-            "--- Pixel Art Editor (enter with 'e') ---",
-            Style::default().add_modifier(Modifier::BOLD),
-        )),
-        Line::from(" Arrows: Move cursor on canvas"),
-        Line::from(" Space: Draw pixel with selected color"),
-        Line::from(" Tab/Shift+Tab: Navigate color palette"),
-        Line::from(" s: Save current art to file (prompts for name)"),
-        Line::from(" Esc: Exit editor (changes not saved automatically)"),
-        Line::from(""),
-        Line::from(Span::styled(
-            "--- Work Queue System (enter with 'w') ---",
-            Style::default().add_modifier(Modifier::BOLD),
-        )),
-        Line::from(" w: Open work queue management"),
-        Line::from(" ↑/↓: Navigate queue items"),
-        Line::from(" u/k: Move item up in queue"),
-        Line::from(" j/n: Move item down in queue"),
-        Line::from(" Enter: Start automated queue processing"),
-        Line::from(" 1-5: Set priority for selected queue item"),
-        Line::from(" d/Del: Remove item from queue"),
-        Line::from(" c: Clear entire queue"),
-        Line::from(""),
-        Line::from(Span::styled(
-            "--- Input Fields (Tokens, Filenames, etc.) ---",
-            Style::default().add_modifier(Modifier::BOLD),
-        )),
-        Line::from(" Enter: Confirm input"),
-        Line::from(" Esc: Cancel input / Go back"),
-        Line::from(" Backspace: Delete last character"),
+    let mut sections = vec![
+        (
+            "General",
+            vec![
+                Line::from(" q: Quit application (asks first while the queue is running)"),
+                Line::from(" ?: Toggle this help screen (keys for the current screen first)"),
+                Line::from(" Ctrl+L: Redraw the whole screen"),
+                Line::from(" c: Configure/Re-enter Base URL and tokens"),
+                Line::from(" b: Change Base URL"),
+                Line::from(" B: Open/close a second session (Tab to switch)"),
+                Line::from(" C: Log out (clear saved tokens, back to setup)"),
+                Line::from(" W: Watch/unwatch the last clicked board pixel"),
+                Line::from(
+                    " /: Search the board for a color or the loaded art (n/N: next/previous, Esc: clear)",
+                ),
+                Line::from(" k: Save a board snapshot"),
+                Line::from(" K: Diff two board snapshots (Esc: clear)"),
+                Line::from(" P: Import color names from a palette file (GIMP .gpl)"),
+                Line::from(" Q: Show/hide the queue sidebar ([ / ]: select and center an item)"),
+                Line::from(" F: Follow queue processing (scrolling the board turns it off)"),
+                Line::from(" R: Copy a board region (two clicks) into a new art"),
+                Line::from(" S: Toggle spectator mode (read only, no placement)"),
+                Line::from(" A: Toggle ascii status tags ([OK]/[ERR] instead of emoji)"),
+                Line::from(" a: Toggle a bell on each pixel of a single-art queue run"),
+                Line::from(" T: Toggle a bell when the cooldown ends"),
+                Line::from(" E: Toggle empty cells only (never paint over other players)"),
+                Line::from(" X: Toggle force placement (place pixels that look correct too)"),
+                Line::from(" r: Refresh board data"),
+                Line::from(" p: Fetch profile data"),
+                Line::from(" i: Show user profile panel"),
+                Line::from(" I: Show board info (dimensions, fill, colors)"),
+                Line::from(" h: Show status log history"),
+                Line::from(" w: Work queue management"),
+                Line::from(" x: Share loaded art with coordinates"),
+                Line::from(" v: View/import shared arts"),
+                Line::from(" V: Toggle periodic validation (monitor completed arts)"),
+                Line::from(" z: Enter share string for quick positioning"),
+                Line::from(" m: Cycle layout (auto / compact / standard)"),
+                Line::from(" D: Toggle API debug logging (full error details)"),
+                Line::from(" Arrows: Scroll board viewport"),
+                Line::from(" Mouse Wheel: Scroll board viewport vertically"),
+                Line::from(" Left Click: Show coordinates (or move loaded art)"),
+            ],
+        ),
+        (
+            "Pixel Art Placement",
+            vec![
+                Line::from(" l: Open art selection"),
+                Line::from(" Arrows: Navigate available arts"),
+                Line::from(" Enter: Load selected art for positioning"),
+                Line::from(" v: Full-screen preview (Left/Right: browse)"),
+                Line::from(" e: Edit the selected art"),
+                Line::from(" y: Duplicate the selected art"),
+                Line::from(" d: Delete the selected art"),
+                Line::from(" ?: Help for this screen"),
+            ],
+        ),
+        (
+            "Loaded Art (positioning & placement)",
+            vec![
+                Line::from(" Arrows: Move loaded art on board"),
+                Line::from(" g: Cycle snap-to-grid size (off/4/8/16/32)"),
+                Line::from(" Left Click: Move loaded art to mouse position"),
+                Line::from(" Right Click: Move and place art immediately"),
+                Line::from(" f: Fit loaded art to a region (click two corners)"),
+                Line::from(" o: Flip next placement order (border-first/center-out)"),
+                Line::from(" Enter: Add positioned art to queue & start processing"),
+                Line::from(" Esc: Cancel loaded art or stop queue processing"),
+            ],
+        ),
+        (
+            "Pixel Art Editor (enter with 'e')",
+            vec![
+                Line::from(" Arrows: Move cursor on canvas"),
+                Line::from(" Space: Draw pixel with selected color"),
+                Line::from(" Tab/Shift+Tab: Navigate color palette"),
+                Line::from(" s: Save current art to file and keep editing"),
+                Line::from(" d/D: Resolve duplicate pixels (keep last / keep first)"),
+                Line::from(" r: Restore the autosaved work in progress"),
+                Line::from(" Esc: Exit editor (asks before discarding unsaved changes)"),
+                Line::from(" ?: Help for this screen"),
+            ],
+        ),
+        (
+            "Work Queue System (enter with 'w')",
+            vec![
+                Line::from(" w: Open work queue management"),
+                Line::from(" ↑/↓: Navigate queue items"),
+                Line::from(" u/k: Move item up in queue"),
+                Line::from(" j/n: Move item down in queue"),
+                Line::from(" Enter: Start automated queue processing"),
+                Line::from(" Space: Mark/unmark item for a batch priority change"),
+                Line::from(" 1-5: Set priority for marked items (or the selected one)"),
+                Line::from(" d/Del: Remove item from queue"),
+                Line::from(" c: Clear entire queue"),
+                Line::from(" x: Clear completed and skipped items"),
+                Line::from(" y: Duplicate selected item at a new position"),
+                Line::from(" m: Move selected item to a new position"),
+                Line::from(" o: Cycle completed-item overlay (full / outline / hidden)"),
+                Line::from(" i: Cycle sequential / interleaved / interleaved by priority"),
+                Line::from(" t: Cycle cooldown strategy (aggressive / balanced / stealth)"),
+                Line::from(" v: Toggle read-back verification of placed pixels"),
+                Line::from(" p: Cycle placement order of selected item (border / contrast / center)"),
+                Line::from(" G: Jump to the most damaged queued art"),
+                Line::from(" F: Retry failed items (reset them to pending)"),
+                Line::from(" ?: Help for this screen"),
+            ],
+        ),
+        (
+            "Input Fields (Tokens, Filenames, etc.)",
+            vec![
+                Line::from(" Enter: Confirm input"),
+                Line::from(" Esc: Cancel input / Go back"),
+                Line::from(" Backspace: Delete last character"),
+            ],
+        ),
     ];
 
+    // Keys for the mode help was opened from come first, then the general ones
+    let current = match app.help_return_mode {
+        InputMode::ArtSelection | InputMode::ArtPreview => 1,
+        InputMode::None if app.loaded_art.is_some() => 2,
+        InputMode::ArtEditor => 3,
+        InputMode::ArtQueue => 4,
+        _ => 0,
+    };
+    let section = sections.remove(current);
+    sections.insert(0, section);
+
+    let mut help_text = Vec::new();
+    for (index, (title, lines)) in sections.into_iter().enumerate() {
+        if index > 0 {
+            help_text.push(Line::from(""));
+        }
+        let title = if index == 0 && current != 0 {
+            format!("--- {} (current) ---", title)
+        } else {
+            format!("--- {} ---", title)
+        };
+        help_text.push(Line::from(Span::styled(
+            title,
+            Style::default().add_modifier(Modifier::BOLD),
+        )));
+        help_text.extend(lines);
+    }
+
     let help_paragraph = Paragraph::new(help_text)
         .block(
             Block::default()
@@ -359,7 +433,7 @@ pub fn render_status_log_popup(app: &App, frame: &mut Frame) {
     ];
 
     // Add timer status as sticky header
-    let timer_status = app.get_formatted_timer_status();
+    let timer_status = status_display(app, &app.get_formatted_timer_status()).into_owned();
 
     // Handle multi-line timer status properly
     if timer_status.contains('\n') {
@@ -428,7 +502,10 @@ pub fn render_status_log_popup(app: &App, frame: &mut Frame) {
                         .fg(Color::Gray)
                         .add_modifier(Modifier::ITALIC),
                 ),
-                Span::styled(message, Style::default().fg(Color::White)),
+                Span::styled(
+                    status_display(app, message),
+                    Style::default().fg(Color::White),
+                ),
             ]));
         }
     }
@@ -452,3 +529,89 @@ pub fn render_status_log_popup(app: &App, frame: &mut Frame) {
     frame.render_widget(Clear, popup_area);
     frame.render_widget(log_paragraph, popup_area);
 }
+
+pub fn render_board_info_popup(app: &App, frame: &mut Frame) {
+    let popup_area = centered_rect(50, 40, frame.size());
+
+    let info_text = if let Some(info) = &app.board_info {
+        let total_pixels = info.width * info.height;
+        let fill_percent = if total_pixels > 0 {
+            info.non_empty_pixels as f64 * 100.0 / total_pixels as f64
+        } else {
+            0.0
+        };
+
+        let field = |label: &'static str, value: String, color: Color| {
+            Line::from(vec![
+                Span::styled(label, Style::default().add_modifier(Modifier::BOLD)),
+                Span::styled(value, Style::default().fg(color)),
+            ])
+        };
+
+        vec![
+            Line::from(Span::styled(
+                "--- Board Info ---",
+                Style::default()
+                    .add_modifier(Modifier::BOLD)
+                    .fg(Color::Cyan),
+            )),
+            Line::from(""),
+            field("Instance: ", info.base_url.clone(), Color::Green),
+            field(
+                "Dimensions: ",
+                format!("{}x{}", info.width, info.height),
+                Color::Yellow,
+            ),
+            field(
+                "Coordinate Range: ",
+                format!(
+                    "x 0..{}, y 0..{}",
+                    info.width.saturating_sub(1),
+                    info.height.saturating_sub(1)
+                ),
+                Color::Yellow,
+            ),
+            field(
+                "Non-empty Pixels: ",
+                format!(
+                    "{}/{} ({:.1}%)",
+                    info.non_empty_pixels, total_pixels, fill_percent
+                ),
+                Color::Blue,
+            ),
+            field("Colors: ", info.color_count.to_string(), Color::Magenta),
+            field(
+                "Colors in View: ",
+                format!(
+                    "{} (viewport {}x{} @ {},{})",
+                    info.viewport_color_count,
+                    info.viewport.2,
+                    info.viewport.3,
+                    info.viewport.0,
+                    info.viewport.1
+                ),
+                Color::Magenta,
+            ),
+            Line::from(""),
+            Line::from(Span::styled(
+                "r: Recompute | Esc, q, or I to close",
+                Style::default()
+                    .fg(Color::Gray)
+                    .add_modifier(Modifier::ITALIC),
+            )),
+        ]
+    } else {
+        vec![Line::from("No board info computed yet.")]
+    };
+
+    let info_paragraph = Paragraph::new(info_text)
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .title("Board Info (Press Esc, q, or I to close)"),
+        )
+        .wrap(Wrap { trim: false });
+
+    frame.render_widget(Clear, popup_area);
+    frame.render_widget(info_paragraph, popup_area);
+}
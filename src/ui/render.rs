@@ -1,22 +1,77 @@
-use crate::app_state::{App, InputMode};
+use crate::app_state::{
+    App, BoardSession, CompletedOverlay, ConnectionState, InputMode, PixelChange,
+};
 use crate::ui::art_editor::render_art_editor_ui;
 use crate::ui::art_management::{
     render_art_preview_fullscreen, render_art_preview_ui, render_art_queue_ui,
-    render_art_selection_ui, render_share_selection_ui,
+    render_art_selection_ui, render_queue_sidebar, render_share_selection_ui,
 };
 use crate::ui::helpers::{
-    get_current_board_color_ui, get_ratatui_color, is_pixel_already_correct_ui,
+    get_current_board_color_ui, get_ratatui_color, is_pixel_already_correct_ui, spinner_char,
+    status_display,
+};
+use crate::ui::popups::{
+    render_board_info_popup, render_help_popup, render_profile_popup, render_status_log_popup,
 };
-use crate::ui::popups::{render_help_popup, render_profile_popup, render_status_log_popup};
 use ratatui::prelude::*;
 use ratatui::widgets::{Block, Borders, Clear, List, ListItem, ListState, Paragraph, Wrap};
+use std::borrow::Cow;
+
+/// Terminals shorter than this switch to the compact layout unless overridden with 'm'
+const COMPACT_LAYOUT_MAX_HEIGHT: u16 = 24;
+
+/// Width of the queue sidebar toggled with 'Q'
+const QUEUE_SIDEBAR_WIDTH: u16 = 32;
+
+/// How long a just-placed pixel stays highlighted in single-art mode
+const PLACEMENT_FLASH_DURATION: std::time::Duration = std::time::Duration::from_millis(450);
 
 pub fn render_ui(app: &mut App, frame: &mut Frame) {
+    // Collapse the config header into a one-line status bar on short terminals.
+    // Only modes that would show the config header can use it; the others need the input area.
+    let use_compact_layout = app
+        .compact_layout
+        .unwrap_or(frame.size().height < COMPACT_LAYOUT_MAX_HEIGHT)
+        && matches!(
+            app.input_mode,
+            InputMode::None
+                | InputMode::ArtEditor
+                | InputMode::ArtEditorExitConfirm
+                | InputMode::ArtOverwriteConfirm
+                | InputMode::ArtRestoreConfirm
+                | InputMode::QuitConfirm
+                | InputMode::ResumePositioning
+                | InputMode::LogoutConfirm
+                | InputMode::ShowHelp
+                | InputMode::ShowProfile
+                | InputMode::ShowStatusLog
+                | InputMode::ShowBoardInfo
+                | InputMode::ArtDeleteConfirmation
+        );
+
+    // Board renderers record their drawable rect below; clear it first so a frame
+    // without a board (editor, preview, resize to nothing) never leaves stale bounds
+    app.board_area_bounds = None;
+    app.last_terminal_size = Some((frame.size().width, frame.size().height));
+    if app.board_loading || app.queue_processing {
+        app.spinner_frame = app.spinner_frame.wrapping_add(1);
+    }
+
     // Check if terminal is wide enough for side-by-side layout
-    let use_wide_layout = frame.size().width >= 140;
+    let use_wide_layout = !use_compact_layout && frame.size().width >= 140;
 
     // Create different layouts based on width
-    let main_layout = if use_wide_layout {
+    let main_layout = if use_compact_layout {
+        // Compact layout: board takes the full height above a single status line
+        Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([
+                Constraint::Length(0), // Input area (collapsed into the status line)
+                Constraint::Min(0),    // Content area (board only)
+                Constraint::Length(1), // Status line
+            ])
+            .split(frame.size())
+    } else if use_wide_layout {
         // Wide layout: Input area + full content area (no status box)
         Layout::default()
             .direction(Direction::Vertical)
@@ -58,7 +113,7 @@ pub fn render_ui(app: &mut App, frame: &mut Frame) {
                 .block(
                     Block::default()
                         .borders(Borders::ALL)
-                        .title("Select API Base URL (Enter to confirm, q to quit):"),
+                        .title("Select API Base URL (Enter to confirm, Tab to test, q to quit):"),
                 )
                 .highlight_style(
                     Style::default()
@@ -77,14 +132,20 @@ pub fn render_ui(app: &mut App, frame: &mut Frame) {
         | InputMode::EnterRefreshToken
         | InputMode::ArtEditorNewArtName
         | InputMode::EnterShareMessage
-        | InputMode::EnterShareString => {
+        | InputMode::EnterShareString
+        | InputMode::EnterColorSearch
+        | InputMode::EnterSnapshotDiff
+        | InputMode::EnterPaletteFile => {
             let title = match app.input_mode {
-                InputMode::EnterCustomBaseUrlText => "Custom Base URL (Editing):",
-                InputMode::EnterAccessToken => "Access Token (Editing):",
-                InputMode::EnterRefreshToken => "Refresh Token (Editing):",
+                InputMode::EnterCustomBaseUrlText => "Custom Base URL (Editing, Tab to test):",
+                InputMode::EnterAccessToken => "Access Token (Editing, Tab to test):",
+                InputMode::EnterRefreshToken => "Refresh Token (Editing, Tab to test):",
                 InputMode::ArtEditorNewArtName => "New Pixel Art Name (Editing):",
                 InputMode::EnterShareMessage => "Share Message (Optional):",
                 InputMode::EnterShareString => "Share String (ftplace-share: NAME at (X, Y)):",
+                InputMode::EnterColorSearch => "Search Color (id, name or 'empty'):",
+                InputMode::EnterSnapshotDiff => "Snapshots to Diff (OLD NEW, empty = latest two):",
+                InputMode::EnterPaletteFile => "Palette File for Color Names (or 'none'):",
                 _ => "Input:", // Should not happen if logic is correct
             };
 
@@ -137,6 +198,9 @@ pub fn render_ui(app: &mut App, frame: &mut Frame) {
         InputMode::ShareSelection => {
             render_share_selection_ui(app, frame, input_area_rect);
         }
+        _ if use_compact_layout => {
+            // Config is shown in the compact status line instead
+        }
         _ => {
             // For InputMode::None or ArtEditor modes, show current config (simplified)
             let mut display_text = format!("URL: {}", app.api_client.get_base_url_config_display());
@@ -161,7 +225,14 @@ pub fn render_ui(app: &mut App, frame: &mut Frame) {
     // --- Main Content Area ---
     let content_area = main_layout[1];
     match app.input_mode {
-        InputMode::ArtEditor => {
+        InputMode::ArtEditor
+        | InputMode::ArtEditorExitConfirm
+        | InputMode::ArtOverwriteConfirm
+        | InputMode::ArtRestoreConfirm => {
+            render_art_editor_ui(app, frame, content_area);
+        }
+        // Keep the editor behind help opened from it
+        InputMode::ShowHelp if app.help_return_mode == InputMode::ArtEditor => {
             render_art_editor_ui(app, frame, content_area);
         }
         InputMode::ArtPreview => {
@@ -169,6 +240,18 @@ pub fn render_ui(app: &mut App, frame: &mut Frame) {
             // This will be handled after the status area rendering
         }
         _ => {
+            // Optional queue sidebar ('Q') takes a narrow strip on the right
+            let content_area = if app.queue_sidebar {
+                let sidebar_layout = Layout::default()
+                    .direction(Direction::Horizontal)
+                    .constraints([Constraint::Min(0), Constraint::Length(QUEUE_SIDEBAR_WIDTH)])
+                    .split(content_area);
+                render_queue_sidebar(app, frame, sidebar_layout[1]);
+                sidebar_layout[0]
+            } else {
+                content_area
+            };
+
             if use_wide_layout {
                 // Wide layout: Board on left (80%), Log history on right (20%)
                 render_wide_layout(app, frame, content_area, Rect::default()); // No status area in wide layout
@@ -180,11 +263,15 @@ pub fn render_ui(app: &mut App, frame: &mut Frame) {
     }
 
     // --- Status Message Area - Only render if not using wide layout ---
-    if !use_wide_layout {
+    if use_compact_layout {
+        render_compact_status_line(app, frame, main_layout[2]);
+    } else if !use_wide_layout {
         let status_area = main_layout[2];
         render_status_area(app, frame, status_area);
     }
 
+    render_connection_indicator(app, frame);
+
     // Cursor handling is now within specific input mode rendering logic above for text input
     // or handled by ListState for selection.
 
@@ -203,6 +290,11 @@ pub fn render_ui(app: &mut App, frame: &mut Frame) {
         render_status_log_popup(app, frame);
     }
 
+    // If ShowBoardInfo mode is active, render the board info popup on top of everything else
+    if app.input_mode == InputMode::ShowBoardInfo {
+        render_board_info_popup(app, frame);
+    }
+
     // If ArtPreview mode is active, render the art preview popup on top of everything else
     if app.input_mode == InputMode::ArtPreview {
         render_art_preview_ui(app, frame, frame.size());
@@ -219,6 +311,30 @@ pub fn render_ui(app: &mut App, frame: &mut Frame) {
     if app.input_mode == InputMode::ArtDeleteConfirmation {
         render_delete_confirmation_dialog(app, frame);
     }
+
+    if app.input_mode == InputMode::ArtEditorExitConfirm {
+        render_editor_exit_confirmation_dialog(app, frame);
+    }
+
+    if app.input_mode == InputMode::ArtOverwriteConfirm {
+        render_art_overwrite_confirmation_dialog(app, frame);
+    }
+
+    if app.input_mode == InputMode::ArtRestoreConfirm {
+        render_art_restore_confirmation_dialog(app, frame);
+    }
+
+    if app.input_mode == InputMode::QuitConfirm {
+        render_quit_confirmation_dialog(app, frame);
+    }
+
+    if app.input_mode == InputMode::LogoutConfirm {
+        render_logout_confirmation_dialog(app, frame);
+    }
+
+    if app.input_mode == InputMode::ResumePositioning {
+        render_resume_positioning_dialog(app, frame);
+    }
 }
 
 fn render_board_display(app: &mut App, frame: &mut Frame, area: Rect) {
@@ -261,23 +377,35 @@ fn render_board_display(app: &mut App, frame: &mut Frame, area: Rect) {
     };
 
     // Store the centered board area bounds for mouse coordinate conversion
-    app.board_area_bounds = Some((
-        drawable_board_area.x,
-        drawable_board_area.y,
-        drawable_board_area.width,
-        drawable_board_area.height,
-    ));
+    // (left as None while there's no board to click on)
+    if !drawable_board_area.is_empty() {
+        app.board_area_bounds = Some((
+            drawable_board_area.x,
+            drawable_board_area.y,
+            drawable_board_area.width,
+            drawable_board_area.height,
+        ));
+    }
 
     let board_title = if app.board_loading {
         let elapsed = app
             .board_load_start
             .map(|start| start.elapsed().as_secs())
             .unwrap_or(0);
-        format!("Board Display - Loading... ({}s)", elapsed)
+        format!(
+            "Board Display - {} Loading... ({}s)",
+            spinner_char(app),
+            elapsed
+        )
     } else {
         format!(
-            "Board Display (Viewport @ {},{} - Size {}x{})",
-            app.board_viewport_x, app.board_viewport_y, board_pixel_width, board_pixel_height
+            "Board Display (Viewport @ {},{} - Size {}x{}){}{}",
+            app.board_viewport_x,
+            app.board_viewport_y,
+            board_pixel_width,
+            board_pixel_height,
+            session_title_suffix(app),
+            queue_spinner_suffix(app)
         )
     };
 
@@ -326,7 +454,7 @@ fn render_board_display(app: &mut App, frame: &mut Frame, area: Rect) {
                                 get_ratatui_color(app, p.c, default_board_rgb)
                             })
                     } else {
-                        default_board_rgb // Bottom half is out of bounds
+                        default_board_rgb // Bottom half is out of bounds (last row of an odd-height board)
                     };
 
                     let cell_char = '▀';
@@ -345,15 +473,23 @@ fn render_board_display(app: &mut App, frame: &mut Frame, area: Rect) {
         }
     }
 
-    // Overlay loaded_art if present - use drawable_board_area instead of inner_board_area
+    // Overlay loaded_art if present
     if let Some(art) = &app.loaded_art {
-        render_loaded_art_overlay(app, frame, &drawable_board_area, art);
+        render_loaded_art_overlay(app, frame, art);
     }
 
     // Overlay queue previews with progress-aware visual feedback
     if !app.art_queue.is_empty() {
-        render_queue_overlay(app, frame, &drawable_board_area);
+        render_queue_overlay(app, frame);
+    }
+
+    // Mark watched pixels
+    if !app.watched_pixels.is_empty() {
+        render_watch_overlay(app, frame);
     }
+    render_color_search_overlay(app, frame);
+    render_snapshot_diff_overlay(app, frame);
+    render_placement_flash(app, frame);
 
     // Render event timer overlay if waiting for event
     if app.waiting_for_event {
@@ -374,8 +510,18 @@ fn render_wide_layout(app: &mut App, frame: &mut Frame, content_area: Rect, _sta
     let board_area = wide_layout[0];
     let log_area = wide_layout[1];
 
-    // Render board on the left (left-aligned instead of centered)
-    render_board_display_left_aligned(app, frame, board_area);
+    if let Some(session) = &app.secondary_session {
+        // Two sessions: active board on the left, background session next to it
+        let session_layout = Layout::default()
+            .direction(Direction::Horizontal)
+            .constraints([Constraint::Percentage(50), Constraint::Percentage(50)])
+            .split(board_area);
+        render_background_session(session, frame, session_layout[1]);
+        render_board_display_left_aligned(app, frame, session_layout[0]);
+    } else {
+        // Render board on the left (left-aligned instead of centered)
+        render_board_display_left_aligned(app, frame, board_area);
+    }
 
     // Render log history on the right
     render_log_history_panel(app, frame, log_area);
@@ -417,23 +563,35 @@ fn render_board_display_left_aligned(app: &mut App, frame: &mut Frame, area: Rec
     };
 
     // Store the board area bounds for mouse coordinate conversion
-    app.board_area_bounds = Some((
-        drawable_board_area.x,
-        drawable_board_area.y,
-        drawable_board_area.width,
-        drawable_board_area.height,
-    ));
+    // (left as None while there's no board to click on)
+    if !drawable_board_area.is_empty() {
+        app.board_area_bounds = Some((
+            drawable_board_area.x,
+            drawable_board_area.y,
+            drawable_board_area.width,
+            drawable_board_area.height,
+        ));
+    }
 
     let board_title = if app.board_loading {
         let elapsed = app
             .board_load_start
             .map(|start| start.elapsed().as_secs())
             .unwrap_or(0);
-        format!("Board Display - Loading... ({}s)", elapsed)
+        format!(
+            "Board Display - {} Loading... ({}s)",
+            spinner_char(app),
+            elapsed
+        )
     } else {
         format!(
-            "Board Display (Viewport @ {},{} - Size {}x{})",
-            app.board_viewport_x, app.board_viewport_y, board_pixel_width, board_pixel_height
+            "Board Display (Viewport @ {},{} - Size {}x{}){}{}",
+            app.board_viewport_x,
+            app.board_viewport_y,
+            board_pixel_width,
+            board_pixel_height,
+            session_title_suffix(app),
+            queue_spinner_suffix(app)
         )
     };
 
@@ -482,7 +640,7 @@ fn render_board_display_left_aligned(app: &mut App, frame: &mut Frame, area: Rec
                                 get_ratatui_color(app, p.c, default_board_rgb)
                             })
                     } else {
-                        default_board_rgb // Bottom half is out of bounds
+                        default_board_rgb // Bottom half is out of bounds (last row of an odd-height board)
                     };
 
                     let cell_char = '▀';
@@ -503,27 +661,132 @@ fn render_board_display_left_aligned(app: &mut App, frame: &mut Frame, area: Rec
 
     // Overlay loaded_art if present
     if let Some(art) = &app.loaded_art {
-        render_loaded_art_overlay(app, frame, &drawable_board_area, art);
+        render_loaded_art_overlay(app, frame, art);
     }
 
     // Overlay queue previews with progress-aware visual feedback
     if !app.art_queue.is_empty() {
-        render_queue_overlay(app, frame, &drawable_board_area);
+        render_queue_overlay(app, frame);
     }
 
+    // Mark watched pixels
+    if !app.watched_pixels.is_empty() {
+        render_watch_overlay(app, frame);
+    }
+    render_color_search_overlay(app, frame);
+    render_snapshot_diff_overlay(app, frame);
+    render_placement_flash(app, frame);
+
     // Render event timer overlay if waiting for event
     if app.waiting_for_event {
         render_event_timer_overlay(app, frame, &drawable_board_area);
     }
 }
 
+fn render_background_session(session: &BoardSession, frame: &mut Frame, area: Rect) {
+    // Read-only view of the inactive session: no overlays, no mouse bounds
+    let inner_board_area = area.inner(Margin {
+        vertical: 1,
+        horizontal: 1,
+    });
+
+    let board_pixel_width = session.board.len();
+    let board_pixel_height = session.board.first().map_or(0, |column| column.len());
+
+    let board_title = format!(
+        "{} (background - Tab to switch)",
+        session.api_client.get_base_url()
+    );
+    frame.render_widget(
+        Block::default()
+            .borders(Borders::ALL)
+            .title(board_title)
+            .border_style(Style::default().fg(Color::DarkGray)),
+        area,
+    );
+
+    if session.board.is_empty() || session.colors.is_empty() {
+        return;
+    }
+
+    let color_for = |color_id: i32, fallback: Color| {
+        session
+            .colors
+            .iter()
+            .find(|c| c.id == color_id)
+            .map_or(fallback, |ci| Color::Rgb(ci.red, ci.green, ci.blue))
+    };
+    let default_board_rgb = color_for(1, Color::Black);
+
+    for y_screen_cell in 0..inner_board_area.height {
+        for x_screen_cell in 0..inner_board_area.width {
+            let board_px_x = session.board_viewport_x as usize + x_screen_cell as usize;
+            let board_px_y_top = session.board_viewport_y as usize + (y_screen_cell * 2) as usize;
+            let board_px_y_bottom = board_px_y_top + 1;
+
+            if board_px_x >= board_pixel_width || board_px_y_top >= board_pixel_height {
+                continue;
+            }
+
+            let pixel_color = |board_px_y: usize| {
+                if board_px_y < board_pixel_height {
+                    session.board[board_px_x][board_px_y]
+                        .as_ref()
+                        .map_or(default_board_rgb, |p| color_for(p.c, default_board_rgb))
+                } else {
+                    default_board_rgb
+                }
+            };
+
+            frame
+                .buffer_mut()
+                .get_mut(
+                    inner_board_area.x + x_screen_cell,
+                    inner_board_area.y + y_screen_cell,
+                )
+                .set_char('▀')
+                .set_style(
+                    Style::default()
+                        .fg(pixel_color(board_px_y_top))
+                        .bg(pixel_color(board_px_y_bottom)),
+                );
+        }
+    }
+}
+
+/// Names the background session in the board title when two sessions are open,
+/// and flags spectator mode and disabled certificate checks
+fn session_title_suffix(app: &App) -> String {
+    let mut suffix = app
+        .secondary_session
+        .as_ref()
+        .map(|session| format!(" [Tab: {}]", session.api_client.get_base_url()))
+        .unwrap_or_default();
+    if app.spectator_mode {
+        suffix.push_str(" [👁️ SPECTATOR - read only]");
+    }
+    if crate::api_client::tls_checks_disabled() {
+        suffix.push_str(" [⚠️ INSECURE TLS]");
+    }
+    suffix
+}
+
+/// Spinner shown in board titles while the queue runs
+fn queue_spinner_suffix(app: &App) -> String {
+    if app.queue_processing {
+        format!(" {} Queue running", spinner_char(app))
+    } else {
+        String::new()
+    }
+}
+
 fn render_log_history_panel(app: &App, frame: &mut Frame, area: Rect) {
     // Build log history content similar to render_status_area but focused on history
     let mut log_lines = Vec::new();
     let max_lines = (area.height.saturating_sub(2)) as usize; // Account for borders
 
     // Add timer status as sticky header - now handles multi-line timeline
-    let timer_status = app.get_formatted_timer_status();
+    let timer_status = status_display(app, &app.get_formatted_timer_status()).into_owned();
 
     // Check if this is the new timeline format (contains newlines)
     if timer_status.contains('\n') {
@@ -559,7 +822,7 @@ fn render_log_history_panel(app: &App, frame: &mut Frame, area: Rect) {
     // Add current status message if not empty
     if !app.status_message.is_empty() {
         log_lines.push(Line::from(Span::styled(
-            format!("📢 {}", app.status_message),
+            status_display(app, &format!("📢 {}", app.status_message)).into_owned(),
             Style::default()
                 .add_modifier(Modifier::BOLD)
                 .fg(Color::Green),
@@ -601,7 +864,10 @@ fn render_log_history_panel(app: &App, frame: &mut Frame, area: Rect) {
                     .fg(Color::Gray)
                     .add_modifier(Modifier::ITALIC),
             ),
-            Span::styled(message, Style::default().fg(Color::White)),
+            Span::styled(
+                status_display(app, message),
+                Style::default().fg(Color::White),
+            ),
         ]));
 
         message_count += 1;
@@ -628,48 +894,120 @@ fn render_log_history_panel(app: &App, frame: &mut Frame, area: Rect) {
     frame.render_widget(log_paragraph, area);
 }
 
-fn render_loaded_art_overlay(
-    app: &App,
-    frame: &mut Frame,
-    inner_board_area: &Rect,
-    art: &crate::art::PixelArt,
-) {
+fn render_loaded_art_overlay(app: &App, frame: &mut Frame, art: &crate::art::PixelArt) {
     for art_pixel in &art.pattern {
         let art_abs_x = art.board_x + art_pixel.x;
         let art_abs_y = art.board_y + art_pixel.y;
 
         // Is this art pixel visible in the current viewport?
-        if art_abs_x >= app.board_viewport_x as i32
-            && art_abs_x < (app.board_viewport_x + inner_board_area.width) as i32
-            && art_abs_y >= app.board_viewport_y as i32
-            && art_abs_y < (app.board_viewport_y + inner_board_area.height * 2) as i32
-        {
-            let screen_cell_x = (art_abs_x - app.board_viewport_x as i32) as u16;
-            // art_abs_y is the pixel row. The cell row is (art_abs_y - viewport_y) / 2
-            let screen_cell_y = ((art_abs_y - app.board_viewport_y as i32) / 2) as u16;
-
-            let target_abs_screen_x = inner_board_area.x + screen_cell_x;
-            let target_abs_screen_y = inner_board_area.y + screen_cell_y;
-
-            // Ensure the target cell is within the drawable inner_board_area bounds
-            if screen_cell_x < inner_board_area.width && screen_cell_y < inner_board_area.height {
-                let art_color = get_ratatui_color(app, art_pixel.color, Color::Magenta);
-                let cell = frame
-                    .buffer_mut()
-                    .get_mut(target_abs_screen_x, target_abs_screen_y);
-
-                cell.set_char('▀');
-                if (art_abs_y - app.board_viewport_y as i32) % 2 == 0 {
-                    cell.set_fg(art_color);
-                } else {
-                    cell.set_bg(art_color);
-                }
+        if let Some((screen_x, screen_y)) = app.board_to_screen(art_abs_x, art_abs_y) {
+            let art_color = get_ratatui_color(app, art_pixel.color, Color::Magenta);
+            let cell = frame.buffer_mut().get_mut(screen_x, screen_y);
+
+            cell.set_char('▀');
+            if (art_abs_y - app.board_viewport_y as i32) % 2 == 0 {
+                cell.set_fg(art_color);
+            } else {
+                cell.set_bg(art_color);
             }
         }
     }
 }
 
-fn render_queue_overlay(app: &App, frame: &mut Frame, inner_board_area: &Rect) {
+/// Draw a marker on each screen cell holding a watched pixel
+fn render_watch_overlay(app: &App, frame: &mut Frame) {
+    for watched in &app.watched_pixels {
+        if let Some((screen_x, screen_y)) = app.board_to_screen(watched.x, watched.y) {
+            frame
+                .buffer_mut()
+                .get_mut(screen_x, screen_y)
+                .set_char('◆')
+                .set_fg(Color::Magenta);
+        }
+    }
+}
+
+/// Mark board search matches (templates at their top-left corner), the current one in a distinct style
+fn render_color_search_overlay(app: &App, frame: &mut Frame) {
+    let Some(search) = &app.color_search else {
+        return;
+    };
+    let current = search.matches.get(search.current).copied();
+
+    // Draw the current match last so a neighbour in the same cell can't hide it
+    let others = search
+        .matches
+        .iter()
+        .copied()
+        .filter(|&m| Some(m) != current);
+    for ((x, y), is_current) in others.map(|m| (m, false)).chain(current.map(|m| (m, true))) {
+        let Some((screen_x, screen_y)) = app.board_to_screen(x, y) else {
+            continue;
+        };
+
+        let cell = frame.buffer_mut().get_mut(screen_x, screen_y);
+        if is_current {
+            cell.set_char('◉')
+                .set_fg(Color::Black)
+                .set_bg(Color::Yellow);
+        } else {
+            cell.set_char('●').set_fg(Color::Yellow);
+        }
+    }
+}
+
+/// Mark cells that changed between two snapshots: green added, red removed, yellow recolored
+fn render_snapshot_diff_overlay(app: &App, frame: &mut Frame) {
+    let Some(changes) = &app.snapshot_diff else {
+        return;
+    };
+
+    for &(x, y, change) in changes {
+        let Some((screen_x, screen_y)) = app.board_to_screen(x, y) else {
+            continue;
+        };
+
+        let (symbol, color) = match change {
+            PixelChange::Added => ('+', Color::Green),
+            PixelChange::Removed => ('-', Color::Red),
+            PixelChange::Recolored => ('~', Color::Yellow),
+        };
+        frame
+            .buffer_mut()
+            .get_mut(screen_x, screen_y)
+            .set_char(symbol)
+            .set_fg(color);
+    }
+}
+
+/// Briefly light up the cell a single-art placement just set, fading out
+fn render_placement_flash(app: &App, frame: &mut Frame) {
+    let Some(((x, y), placed_at)) = app.placement_flash else {
+        return;
+    };
+    let elapsed = placed_at.elapsed();
+    if elapsed >= PLACEMENT_FLASH_DURATION {
+        return;
+    }
+    let Some((screen_x, screen_y)) = app.board_to_screen(x, y) else {
+        return;
+    };
+
+    let color = if elapsed < PLACEMENT_FLASH_DURATION / 3 {
+        Color::White
+    } else if elapsed < PLACEMENT_FLASH_DURATION * 2 / 3 {
+        Color::Gray
+    } else {
+        Color::DarkGray
+    };
+    frame
+        .buffer_mut()
+        .get_mut(screen_x, screen_y)
+        .set_char('✦')
+        .set_fg(color);
+}
+
+fn render_queue_overlay(app: &App, frame: &mut Frame) {
     for queue_item in &app.art_queue {
         // Show all queue items (pending, in progress, complete)
         if queue_item.status == crate::app_state::QueueStatus::Failed
@@ -678,102 +1016,125 @@ fn render_queue_overlay(app: &App, frame: &mut Frame, inner_board_area: &Rect) {
             continue; // Don't show failed/skipped items
         }
 
+        if queue_item.status == crate::app_state::QueueStatus::Complete {
+            match app.completed_overlay {
+                CompletedOverlay::Full => {}
+                CompletedOverlay::Outline => {
+                    render_art_outline(app, frame, &queue_item.art);
+                    continue;
+                }
+                CompletedOverlay::Hidden => continue,
+            }
+        }
+
         // Filter meaningful pixels for this queue item (same logic as queue processing)
         let meaningful_pixels =
-            filter_meaningful_pixels_for_rendering(&queue_item.art, &app.colors);
+            filter_meaningful_pixels_for_rendering(&queue_item.art, &app.colors, &app.skip_rules);
 
         for (pixel_index, art_pixel) in meaningful_pixels.iter().enumerate() {
             let art_abs_x = queue_item.art.board_x + art_pixel.x;
             let art_abs_y = queue_item.art.board_y + art_pixel.y;
 
             // Is this art pixel visible in the current viewport?
-            if art_abs_x >= app.board_viewport_x as i32
-                && art_abs_x < (app.board_viewport_x + inner_board_area.width) as i32
-                && art_abs_y >= app.board_viewport_y as i32
-                && art_abs_y < (app.board_viewport_y + inner_board_area.height * 2) as i32
-            {
-                let screen_cell_x = (art_abs_x - app.board_viewport_x as i32) as u16;
-                let screen_cell_y = ((art_abs_y - app.board_viewport_y as i32) / 2) as u16;
-
-                let target_abs_screen_x = inner_board_area.x + screen_cell_x;
-                let target_abs_screen_y = inner_board_area.y + screen_cell_y;
-
-                // Ensure the target cell is within bounds
-                if screen_cell_x < inner_board_area.width && screen_cell_y < inner_board_area.height
-                {
-                    let cell = frame
-                        .buffer_mut()
-                        .get_mut(target_abs_screen_x, target_abs_screen_y);
-
-                    // Check if this pixel is already correct on the board
-                    let is_already_correct = is_pixel_already_correct_ui(
-                        &app.board,
-                        art_abs_x,
-                        art_abs_y,
-                        art_pixel.color,
-                    );
-
-                    // Check if this pixel is actually correct on the backend board
-                    // Only show as "placed" if it's actually the correct color on the board
-                    let is_actually_placed = is_already_correct;
-
-                    // Determine pixel state: placed, current, or pending
-                    let is_placed = pixel_index < queue_item.pixels_placed && is_actually_placed;
-                    let is_current = pixel_index == queue_item.pixels_placed
-                        && queue_item.status == crate::app_state::QueueStatus::InProgress;
-                    let is_pending = (pixel_index >= queue_item.pixels_placed
-                        || !is_actually_placed)
-                        && queue_item.status == crate::app_state::QueueStatus::Pending;
-
-                    // Get the target color for this pixel
-                    let target_color = get_ratatui_color(app, art_pixel.color, Color::White);
-
-                    if is_placed {
-                        // Show pixels that were actually placed by queue processing AND are correct on board
+            if let Some((screen_x, screen_y)) = app.board_to_screen(art_abs_x, art_abs_y) {
+                let cell = frame.buffer_mut().get_mut(screen_x, screen_y);
+
+                // Check if this pixel is already correct on the board
+                let is_already_correct =
+                    is_pixel_already_correct_ui(&app.board, art_abs_x, art_abs_y, art_pixel.color);
+
+                // Check if this pixel is actually correct on the backend board
+                // Only show as "placed" if it's actually the correct color on the board
+                let is_actually_placed = is_already_correct;
+
+                // Determine pixel state: placed, current, or pending
+                let is_placed = pixel_index < queue_item.pixels_placed && is_actually_placed;
+                let is_current = pixel_index == queue_item.pixels_placed
+                    && queue_item.status == crate::app_state::QueueStatus::InProgress;
+                let is_pending = (pixel_index >= queue_item.pixels_placed || !is_actually_placed)
+                    && queue_item.status == crate::app_state::QueueStatus::Pending;
+
+                // Get the target color for this pixel
+                let target_color = get_ratatui_color(app, art_pixel.color, Color::White);
+
+                if is_placed {
+                    // Show pixels that were actually placed by queue processing AND are correct on board
+                    cell.set_char('▀');
+                    if (art_abs_y - app.board_viewport_y as i32) % 2 == 0 {
+                        cell.set_fg(target_color);
+                    } else {
+                        cell.set_bg(target_color);
+                    }
+                } else if is_current {
+                    // Show current pixel being processed with bright white
+                    cell.set_char('▀');
+                    if (art_abs_y - app.board_viewport_y as i32) % 2 == 0 {
+                        cell.set_fg(Color::White);
+                    } else {
+                        cell.set_bg(Color::White);
+                    }
+                } else if is_pending && !is_already_correct {
+                    // Show pending pixels that need to be changed with blinking effect
+                    // Blink between current board color and target color
+                    if app.queue_blink_state {
+                        // Show target color when blinking on
                         cell.set_char('▀');
                         if (art_abs_y - app.board_viewport_y as i32) % 2 == 0 {
                             cell.set_fg(target_color);
                         } else {
                             cell.set_bg(target_color);
                         }
-                    } else if is_current {
-                        // Show current pixel being processed with bright white
+                    } else {
+                        // Show current board color when blinking off
+                        let current_board_color = get_current_board_color_ui(
+                            &app.board,
+                            &app.colors,
+                            art_abs_x,
+                            art_abs_y,
+                        );
+
                         cell.set_char('▀');
                         if (art_abs_y - app.board_viewport_y as i32) % 2 == 0 {
-                            cell.set_fg(Color::White);
+                            cell.set_fg(current_board_color);
                         } else {
-                            cell.set_bg(Color::White);
-                        }
-                    } else if is_pending && !is_already_correct {
-                        // Show pending pixels that need to be changed with blinking effect
-                        // Blink between current board color and target color
-                        if app.queue_blink_state {
-                            // Show target color when blinking on
-                            cell.set_char('▀');
-                            if (art_abs_y - app.board_viewport_y as i32) % 2 == 0 {
-                                cell.set_fg(target_color);
-                            } else {
-                                cell.set_bg(target_color);
-                            }
-                        } else {
-                            // Show current board color when blinking off
-                            let current_board_color = get_current_board_color_ui(
-                                &app.board,
-                                &app.colors,
-                                art_abs_x,
-                                art_abs_y,
-                            );
-
-                            cell.set_char('▀');
-                            if (art_abs_y - app.board_viewport_y as i32) % 2 == 0 {
-                                cell.set_fg(current_board_color);
-                            } else {
-                                cell.set_bg(current_board_color);
-                            }
+                            cell.set_bg(current_board_color);
                         }
                     }
-                    // If pixel is pending but already correct, we don't show any overlay
                 }
+                // If pixel is pending but already correct, we don't show any overlay
+            }
+        }
+    }
+}
+
+/// Draw a faint bounding-box outline around an art (used for completed queue items)
+fn render_art_outline(app: &App, frame: &mut Frame, art: &crate::art::PixelArt) {
+    let (width, height) = crate::art::get_art_dimensions(art);
+    if width <= 0 || height <= 0 {
+        return;
+    }
+
+    let left = art.board_x;
+    let top = art.board_y;
+    let right = art.board_x + width - 1;
+    let bottom = art.board_y + height - 1;
+
+    for abs_y in top..=bottom {
+        for abs_x in left..=right {
+            let on_border = abs_x == left || abs_x == right || abs_y == top || abs_y == bottom;
+            if !on_border {
+                continue;
+            }
+            let Some((screen_x, screen_y)) = app.board_to_screen(abs_x, abs_y) else {
+                continue;
+            };
+
+            let cell = frame.buffer_mut().get_mut(screen_x, screen_y);
+            cell.set_char('▀');
+            if (abs_y - app.board_viewport_y as i32) % 2 == 0 {
+                cell.set_fg(Color::DarkGray);
+            } else {
+                cell.set_bg(Color::DarkGray);
             }
         }
     }
@@ -812,6 +1173,14 @@ fn render_status_area(app: &App, frame: &mut Frame, area: Rect) {
         }
     }
 
+    // Glanceable queue progress so the board view can stay open during a run
+    if let Some(summary) = app.queue_summary_line() {
+        status_lines.push(format!("📋 {}", summary));
+    }
+    if let Some(countdown) = app.queue_cooldown_countdown() {
+        status_lines.push(format!("⏳ {}", countdown));
+    }
+
     // Add recent status messages (newest first, limit to remaining space)
     let remaining_lines = max_lines.saturating_sub(status_lines.len());
     if remaining_lines > 0 {
@@ -828,34 +1197,109 @@ fn render_status_area(app: &App, frame: &mut Frame, area: Rect) {
         }
     }
 
-    let status_text = status_lines.join("\n");
+    let status_text = status_lines
+        .iter()
+        .map(|line| status_display(app, line))
+        .collect::<Vec<_>>()
+        .join("\n");
     let status_widget = Paragraph::new(status_text)
         .wrap(Wrap { trim: true })
         .block(Block::default().borders(Borders::ALL).title("Status"));
     frame.render_widget(status_widget, area);
 }
 
+/// Colored dot + label in the top-right corner: green connected, yellow no auth, red offline
+fn render_connection_indicator(app: &App, frame: &mut Frame) {
+    let has_token = app.api_client.get_auth_cookie_preview().is_some();
+    let (label, color) = match app.connection_state {
+        ConnectionState::Connected if has_token => ("Connected", Color::Green),
+        ConnectionState::Connected | ConnectionState::Unauthorized => {
+            ("Not authenticated", Color::Yellow)
+        }
+        ConnectionState::Disconnected => ("Disconnected", Color::Red),
+        ConnectionState::Unknown if has_token => ("Connecting...", Color::DarkGray),
+        ConnectionState::Unknown => ("No token", Color::Yellow),
+    };
+
+    let text = Line::from(vec![
+        Span::styled(" ● ", Style::default().fg(color)),
+        Span::raw(format!("{} ", label)),
+    ]);
+    let width = (text.width() as u16).min(frame.size().width);
+    let area = Rect::new(frame.size().width - width, 0, width, 1);
+    frame.render_widget(Clear, area);
+    frame.render_widget(Paragraph::new(text), area);
+}
+
+fn render_compact_status_line(app: &App, frame: &mut Frame, area: Rect) {
+    let token_state = if app.api_client.get_auth_cookie_preview().is_some() {
+        "token set"
+    } else {
+        "no token"
+    };
+
+    let mut spans = vec![Span::styled(
+        format!(
+            " {} [{}] ",
+            app.api_client.get_base_url_config_display(),
+            token_state
+        ),
+        Style::default().fg(Color::Black).bg(Color::Cyan),
+    )];
+
+    if app.spectator_mode {
+        spans.push(Span::styled(
+            " 👁️ SPECTATOR ",
+            Style::default().fg(Color::Black).bg(Color::Magenta),
+        ));
+    }
+
+    if !app.cooldown_status.is_empty() && app.cooldown_status != "Ready to place pixels" {
+        spans.push(Span::styled(
+            format!(" 🕐 {} ", app.cooldown_status),
+            Style::default().fg(Color::Yellow),
+        ));
+    }
+
+    if let Some(summary) = app.queue_summary_line() {
+        spans.push(Span::styled(
+            format!(" 📋 {} ", summary),
+            Style::default().fg(Color::Green),
+        ));
+    }
+
+    if let Some(countdown) = app.queue_cooldown_countdown() {
+        spans.push(Span::styled(
+            format!(" ⏳ {} ", countdown),
+            Style::default().fg(Color::Yellow),
+        ));
+    }
+
+    spans.push(Span::raw(format!(" {} ", app.status_message)));
+    spans.push(Span::styled(
+        "| ?: Help  m: Layout",
+        Style::default().fg(Color::Gray),
+    ));
+    for span in &mut spans {
+        if let Cow::Owned(display) = status_display(app, &span.content) {
+            span.content = display.into();
+        }
+    }
+
+    frame.render_widget(Paragraph::new(Line::from(spans)), area);
+}
+
 /// Filter meaningful pixels for rendering (same logic as queue processing)
 fn filter_meaningful_pixels_for_rendering(
     art: &crate::art::PixelArt,
     colors: &[crate::api_client::ColorInfo],
+    rules: &crate::app_state::SkipRules,
 ) -> Vec<crate::art::ArtPixel> {
     let mut meaningful_pixels = Vec::new();
     let mut seen_positions = std::collections::HashSet::new();
 
     // Define background color IDs that should not be placed
-    let mut background_color_ids = std::collections::HashSet::new();
-    for color in colors {
-        let name_lower = color.name.to_lowercase();
-        if name_lower.contains("transparent")
-            || name_lower.contains("background")
-            || name_lower.contains("empty")
-            || name_lower == "none"
-            || name_lower.contains("alpha")
-        {
-            background_color_ids.insert(color.id);
-        }
-    }
+    let background_color_ids = rules.background_color_ids(colors);
 
     for pixel in &art.pattern {
         // Skip if this position was already processed (remove duplicates)
@@ -922,6 +1366,163 @@ fn render_delete_confirmation_dialog(app: &App, frame: &mut Frame) {
     frame.render_widget(dialog, popup_area);
 }
 
+fn render_editor_exit_confirmation_dialog(app: &App, frame: &mut Frame) {
+    let popup_area = centered_rect(50, 20, frame.size());
+    frame.render_widget(Clear, popup_area);
+
+    let art_name = app
+        .current_editing_art
+        .as_ref()
+        .map_or("Unknown", |art| art.name.as_str());
+    let dialog_text = format!(
+        "'{}' has unsaved changes.\n\ny: Discard   s: Save and exit   n: Keep editing",
+        art_name
+    );
+
+    let dialog = Paragraph::new(dialog_text)
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .title("Discard Unsaved Changes?")
+                .border_style(Style::default().fg(Color::Yellow)),
+        )
+        .alignment(Alignment::Center)
+        .wrap(Wrap { trim: true });
+
+    frame.render_widget(dialog, popup_area);
+}
+
+fn render_art_overwrite_confirmation_dialog(app: &App, frame: &mut Frame) {
+    let popup_area = centered_rect(50, 20, frame.size());
+    frame.render_widget(Clear, popup_area);
+
+    let art_name = app
+        .current_editing_art
+        .as_ref()
+        .map_or("Unknown", |art| art.name.as_str());
+    let dialog_text = format!(
+        "Save over '{}'?\n\nThe saved art will be replaced by your edits.\n\ny: Overwrite   n: Keep editing",
+        art_name
+    );
+
+    let dialog = Paragraph::new(dialog_text)
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .title("Overwrite Saved Art?")
+                .border_style(Style::default().fg(Color::Yellow)),
+        )
+        .alignment(Alignment::Center)
+        .wrap(Wrap { trim: true });
+
+    frame.render_widget(dialog, popup_area);
+}
+
+fn render_art_restore_confirmation_dialog(app: &App, frame: &mut Frame) {
+    let popup_area = centered_rect(50, 20, frame.size());
+    frame.render_widget(Clear, popup_area);
+
+    let art_name = app
+        .current_editing_art
+        .as_ref()
+        .map_or("Unknown", |art| art.name.as_str());
+    let dialog_text = format!(
+        "'{}' has unsaved changes.\n\nThe editor art will be replaced by the autosave.\n\ny: Restore   n: Keep editing",
+        art_name
+    );
+
+    let dialog = Paragraph::new(dialog_text)
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .title("Restore Autosave?")
+                .border_style(Style::default().fg(Color::Yellow)),
+        )
+        .alignment(Alignment::Center)
+        .wrap(Wrap { trim: true });
+
+    frame.render_widget(dialog, popup_area);
+}
+
+fn render_quit_confirmation_dialog(app: &App, frame: &mut Frame) {
+    let popup_area = centered_rect(50, 20, frame.size());
+    frame.render_widget(Clear, popup_area);
+
+    let remaining = app
+        .art_queue
+        .iter()
+        .filter(|item| {
+            matches!(
+                item.status,
+                crate::app_state::QueueStatus::Pending | crate::app_state::QueueStatus::InProgress
+            )
+        })
+        .count();
+    let dialog_text = format!(
+        "The queue is running ({} items left).\nQuitting cancels it; the queue is saved and resumes on the next start.\n\ny: Quit anyway   n: Keep running",
+        remaining
+    );
+
+    let dialog = Paragraph::new(dialog_text)
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .title("Quit While Queue Is Running?")
+                .border_style(Style::default().fg(Color::Red)),
+        )
+        .alignment(Alignment::Center)
+        .wrap(Wrap { trim: true });
+
+    frame.render_widget(dialog, popup_area);
+}
+
+fn render_logout_confirmation_dialog(app: &App, frame: &mut Frame) {
+    let popup_area = centered_rect(50, 20, frame.size());
+    frame.render_widget(Clear, popup_area);
+
+    let dialog_text = format!(
+        "Log out of {}?\nThe access and refresh tokens are removed from memory and from disk; you will need new ones to continue.\n\ny: Log out   n: Stay logged in",
+        app.api_client.get_base_url()
+    );
+
+    let dialog = Paragraph::new(dialog_text)
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .title("Log Out?")
+                .border_style(Style::default().fg(Color::Red)),
+        )
+        .alignment(Alignment::Center)
+        .wrap(Wrap { trim: true });
+
+    frame.render_widget(dialog, popup_area);
+}
+
+fn render_resume_positioning_dialog(app: &App, frame: &mut Frame) {
+    let Some(art) = &app.resume_art else {
+        return;
+    };
+    let popup_area = centered_rect(50, 20, frame.size());
+    frame.render_widget(Clear, popup_area);
+
+    let dialog_text = format!(
+        "'{}' was being positioned at ({}, {}) when the app closed.\n\ny: Resume positioning   n: Discard",
+        art.name, art.board_x, art.board_y
+    );
+
+    let dialog = Paragraph::new(dialog_text)
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .title("Resume Positioning?")
+                .border_style(Style::default().fg(Color::Yellow)),
+        )
+        .alignment(Alignment::Center)
+        .wrap(Wrap { trim: true });
+
+    frame.render_widget(dialog, popup_area);
+}
+
 /// Helper function to create a centered rectangle
 fn centered_rect(percent_x: u16, percent_y: u16, r: Rect) -> Rect {
     let popup_layout = Layout::default()